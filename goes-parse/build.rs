@@ -0,0 +1,22 @@
+//! Copies the station list embedded by `stations` into the build directory, taken from the file
+//! named by `GOES_PARSE_NSD_CCCC` so builds can embed a newer NWS `nsd_cccc.txt` than the one
+//! shipped with the crate
+
+use std::{env, fs, path::PathBuf};
+
+const BUNDLED: &str = "src/stations/nsd_cccc.txt";
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GOES_PARSE_NSD_CCCC");
+    println!("cargo:rerun-if-changed={BUNDLED}");
+
+    let source = match env::var_os("GOES_PARSE_NSD_CCCC") {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(BUNDLED),
+    };
+    println!("cargo:rerun-if-changed={}", source.display());
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::copy(&source, out.join("nsd_cccc.txt"))
+        .unwrap_or_else(|e| panic!("Failed to copy station list {}: {e}", source.display()));
+}
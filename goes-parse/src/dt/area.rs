@@ -1,10 +1,16 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
 /// From WMO No. 386 P. 88
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct AreaCode(char, char);
 
 /// A hemisphere for use in a [GeographicalAreaDesignator]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GeographicalAreaDesignatorHemisphere {
     NorthernHemisphere,
@@ -14,6 +20,7 @@ pub enum GeographicalAreaDesignatorHemisphere {
 
 /// From WMO No. 386 P. 92
 /// Area code for D, G, H, O, P, Q, T, X or Y or A2 for I and J
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GeographicalAreaDesignator {
     ZeroToNinetyWest(GeographicalAreaDesignatorHemisphere),
@@ -25,78 +32,189 @@ pub enum GeographicalAreaDesignator {
     Global,
 }
 
-/// Reference times to be used for forecasts
+/// Reference times to be used for forecasts, along with the A2 term they were given by
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct ReferenceTimeDesignator(u16);
+pub struct ReferenceTimeDesignator {
+    hours: u16,
+    code: char,
+}
 
 impl ReferenceTimeDesignator {
     /// Get the number of hours in this designator
     pub const fn hours(&self) -> u16 {
-        self.0
-    }
-
-    /// Create a new `ReferenceTimeDesignator` from a given number of hours
-    pub const fn from_hours(hours: u16) -> Self {
-        Self(hours)
+        self.hours
     }
 
-    /// Create a new `ReferenceTimeDesignator` from a given number of days
-    pub const fn from_days(days: u16) -> Self {
-        Self(days * 24)
+    /// Get the A2 term this designator was parsed from
+    pub const fn code(&self) -> char {
+        self.code
     }
 
     /// Parse a time designator for the A2 term of a D, G, H, J, O, or P product
     pub const fn parse_for_dghjopt(val: char) -> Result<Self, ReferenceTimeDesignatorParseError> {
-        Ok(match val {
-            'A' => Self::from_hours(0),
-            'B' => Self::from_hours(6),
-            'C' => Self::from_hours(12),
-            'D' => Self::from_hours(18),
-            'E' => Self::from_hours(24),
-            'F' => Self::from_hours(30),
-            'G' => Self::from_hours(36),
-            'H' => Self::from_hours(42),
-            'I' => Self::from_hours(48),
-            'J' => Self::from_hours(60),
-            'K' => Self::from_hours(72),
-            'L' => Self::from_hours(84),
-            'M' => Self::from_hours(96),
-            'N' => Self::from_hours(108),
-            'O' => Self::from_days(5),
-            'P' => Self::from_hours(132),
-            'Q' => Self::from_hours(144),
-            'R' => Self::from_days(7),
-            'S' => Self::from_hours(168),
-            'T' => Self::from_days(10),
-            'U' => Self::from_days(15),
-            'V' => Self::from_days(30),
-            'W' | 'X' | 'Y' | 'Z' => Self::from_hours(0),
+        let hours = match val {
+            'A' => 0,
+            'B' => 6,
+            'C' => 12,
+            'D' => 18,
+            'E' => 24,
+            'F' => 30,
+            'G' => 36,
+            'H' => 42,
+            'I' => 48,
+            'J' => 60,
+            'K' => 72,
+            'L' => 84,
+            'M' => 96,
+            'N' => 108,
+            'O' => 5 * 24,
+            'P' => 132,
+            'Q' => 144,
+            'R' => 7 * 24,
+            'S' => 168,
+            'T' => 10 * 24,
+            'U' => 15 * 24,
+            'V' => 30 * 24,
+            'W' | 'X' | 'Y' | 'Z' => 0,
             other => return Err(ReferenceTimeDesignatorParseError(other)),
-        })
+        };
+
+        Ok(Self { hours, code: val })
     }
 
     /// Parse the reference time designator term A2 for proucts Q, X, and Y
     pub const fn parse_for_qxy(val: char) -> Result<Self, ReferenceTimeDesignatorParseError> {
-        Ok(Self::from_hours(match val {
-            'A' => 0,
-            'B' => 3,
-            'C' => 6,
-            'D' => 9,
-            'E' => 12,
-            'F' => 15,
-            'G' => 18,
-            'H' => 21,
-            'I' => 24,
-            'J' => 27,
-            'K' => 30,
-            'L' => 33,
-            'M' => 36,
-            'N' => 39,
-            'O' => 42,
-            'P' => 45,
-            'Q' => 48,
-            other => return Err(ReferenceTimeDesignatorParseError(other)),
-        }))
+        match val {
+            'A'..='Q' => Ok(Self {
+                hours: (val as u16 - 'A' as u16) * 3,
+                code: val,
+            }),
+            other => Err(ReferenceTimeDesignatorParseError(other)),
+        }
+    }
+
+    /// Get a description of the reference time, i.e. `24 hour forecast`
+    pub fn description(&self) -> String {
+        match self.hours {
+            0 => "analysis".to_owned(),
+            hours if hours >= 120 && hours % 24 == 0 => format!("{} day forecast", hours / 24),
+            hours => format!("{} hour forecast", hours),
+        }
+    }
+}
+
+impl AreaCode {
+    /// Get the name of the country, territory, or region given by this code in tables C1 and C2
+    /// of WMO No. 386
+    pub const fn description(&self) -> Option<&'static str> {
+        Some(match (self.0, self.1) {
+            ('A', 'A') => "Antarctic",
+            ('A', 'C') => "Arctic",
+            ('A', 'E') => "South-East Asia",
+            ('A', 'F') => "Africa",
+            ('A', 'G') => "Argentina",
+            ('A', 'K') => "Alaska",
+            ('A', 'S') => "Asia",
+            ('A', 'U') => "Australia",
+            ('B', 'A') => "Bahamas",
+            ('B', 'Z') => "Brazil",
+            ('C', 'A') => "Caribbean and Central America",
+            ('C', 'I') => "China",
+            ('C', 'N') => "Canada",
+            ('C', 'U') => "Cuba",
+            ('D', 'L') => "Germany",
+            ('E', 'A') => "Eastern Africa",
+            ('E', 'E') => "Eastern Europe",
+            ('E', 'G') => "Egypt",
+            ('E', 'U') => "Europe",
+            ('F', 'E') => "Far East",
+            ('F', 'I') => "Finland",
+            ('F', 'R') => "France",
+            ('G', 'L') => "Greenland",
+            ('G', 'R') => "Greece",
+            ('G', 'X') => "Gulf of Mexico",
+            ('H', 'W') => "Hawaiian Islands",
+            ('I', 'L') => "Iceland",
+            ('I', 'N') => "India",
+            ('I', 'O') => "Indian Ocean",
+            ('I', 'Y') => "Italy",
+            ('J', 'P') => "Japan",
+            ('K', 'O') => "Republic of Korea",
+            ('M', 'E') => "Eastern Mediterranean",
+            ('M', 'M') => "Mediterranean",
+            ('M', 'X') => "Mexico",
+            ('N', 'A') => "North America",
+            ('N', 'L') => "Netherlands",
+            ('N', 'O') => "Norway",
+            ('N', 'T') => "North Atlantic",
+            ('N', 'Z') => "New Zealand",
+            ('O', 'C') => "Oceania",
+            ('P', 'A') => "Pacific Ocean",
+            ('P', 'H') => "Philippines",
+            ('P', 'N') => "North Pacific",
+            ('P', 'Q') => "Western North Pacific",
+            ('P', 'R') => "Puerto Rico",
+            ('P', 'S') => "South Pacific",
+            ('P', 'W') => "Western Pacific",
+            ('R', 'A') => "Russian Federation",
+            ('S', 'A') => "South America",
+            ('S', 'N') => "Sweden",
+            ('S', 'P') => "Spain",
+            ('T', 'U') => "Turkey",
+            ('U', 'K') => "United Kingdom",
+            ('U', 'S') => "United States",
+            ('X', 'N') => "Northern Hemisphere",
+            ('X', 'S') => "Southern Hemisphere",
+            ('X', 'T') => "Tropical belt",
+            ('X', 'X') => "Unspecified area",
+            _ => return None,
+        })
+    }
+}
+
+impl GeographicalAreaDesignator {
+    /// Get a description of the area covered, i.e. `0-90W northern hemisphere`
+    pub const fn description(&self) -> &'static str {
+        use GeographicalAreaDesignatorHemisphere::*;
+
+        match self {
+            Self::ZeroToNinetyWest(NorthernHemisphere) => "0-90W northern hemisphere",
+            Self::NinetyToOneEightyWest(NorthernHemisphere) => "90W-180 northern hemisphere",
+            Self::OneEightytoNinetyEast(NorthernHemisphere) => "180-90E northern hemisphere",
+            Self::NinetyToZeroEast(NorthernHemisphere) => "90E-0 northern hemisphere",
+            Self::ZeroToNinetyWest(TropicalBelt) => "0-90W tropical belt",
+            Self::NinetyToOneEightyWest(TropicalBelt) => "90W-180 tropical belt",
+            Self::OneEightytoNinetyEast(TropicalBelt) => "180-90E tropical belt",
+            Self::NinetyToZeroEast(TropicalBelt) => "90E-0 tropical belt",
+            Self::ZeroToNinetyWest(SouthernHemisphere) => "0-90W southern hemisphere",
+            Self::NinetyToOneEightyWest(SouthernHemisphere) => "90W-180 southern hemisphere",
+            Self::OneEightytoNinetyEast(SouthernHemisphere) => "180-90E southern hemisphere",
+            Self::NinetyToZeroEast(SouthernHemisphere) => "90E-0 southern hemisphere",
+            Self::Hemisphere(NorthernHemisphere) => "Northern hemisphere",
+            Self::Hemisphere(TropicalBelt) => "Tropical belt",
+            Self::Hemisphere(SouthernHemisphere) => "Southern hemisphere",
+            Self::FortyFiveToOneEightyWestNorthernHemisphere => "45W-180 northern hemisphere",
+            Self::Global => "Global area",
+        }
+    }
+}
+
+impl fmt::Display for AreaCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.0, self.1)
+    }
+}
+
+impl fmt::Display for GeographicalAreaDesignator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+impl fmt::Display for ReferenceTimeDesignator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code)
     }
 }
 
@@ -117,6 +235,25 @@ impl FromStr for AreaCode {
     }
 }
 
+impl From<AreaCode> for String {
+    fn from(code: AreaCode) -> Self {
+        [code.0, code.1].iter().collect()
+    }
+}
+
+impl From<AreaCode> for (char, char) {
+    fn from(code: AreaCode) -> Self {
+        (code.0, code.1)
+    }
+}
+
+impl TryFrom<String> for AreaCode {
+    type Error = AreaCodeParseError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl TryFrom<char> for GeographicalAreaDesignator {
     type Error = GeographicalAreaDesignatorParseError;
     fn try_from(value: char) -> Result<Self, Self::Error> {
@@ -150,6 +287,33 @@ impl TryFrom<char> for GeographicalAreaDesignator {
     }
 }
 
+impl From<GeographicalAreaDesignator> for char {
+    fn from(value: GeographicalAreaDesignator) -> Self {
+        use GeographicalAreaDesignatorHemisphere::*;
+
+        match value {
+            GeographicalAreaDesignator::ZeroToNinetyWest(NorthernHemisphere) => 'A',
+            GeographicalAreaDesignator::NinetyToOneEightyWest(NorthernHemisphere) => 'B',
+            GeographicalAreaDesignator::OneEightytoNinetyEast(NorthernHemisphere) => 'C',
+            GeographicalAreaDesignator::NinetyToZeroEast(NorthernHemisphere) => 'D',
+            GeographicalAreaDesignator::ZeroToNinetyWest(TropicalBelt) => 'E',
+            GeographicalAreaDesignator::NinetyToOneEightyWest(TropicalBelt) => 'F',
+            GeographicalAreaDesignator::OneEightytoNinetyEast(TropicalBelt) => 'G',
+            GeographicalAreaDesignator::NinetyToZeroEast(TropicalBelt) => 'H',
+            GeographicalAreaDesignator::ZeroToNinetyWest(SouthernHemisphere) => 'I',
+            GeographicalAreaDesignator::NinetyToOneEightyWest(SouthernHemisphere) => 'J',
+            GeographicalAreaDesignator::OneEightytoNinetyEast(SouthernHemisphere) => 'K',
+            GeographicalAreaDesignator::NinetyToZeroEast(SouthernHemisphere) => 'L',
+            GeographicalAreaDesignator::Hemisphere(NorthernHemisphere) => 'N',
+            GeographicalAreaDesignator::Hemisphere(SouthernHemisphere) => 'S',
+            GeographicalAreaDesignator::FortyFiveToOneEightyWestNorthernHemisphere => 'T',
+            //There is no designator for the tropical belt as a whole
+            GeographicalAreaDesignator::Hemisphere(TropicalBelt)
+            | GeographicalAreaDesignator::Global => 'X',
+        }
+    }
+}
+
 #[derive(Clone, Debug, thiserror::Error)]
 #[error("Unrecognized geographical area designator {0}")]
 pub struct GeographicalAreaDesignatorParseError(char);
@@ -1,4 +1,5 @@
 /// Identifier for a code form that a document is formatted in
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u16)]
 pub enum CodeForm {
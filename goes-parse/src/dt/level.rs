@@ -1,6 +1,8 @@
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct AirLevelDesignator(u8);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SeaLevelDesignator(u8);
 
@@ -18,6 +20,18 @@ impl TryFrom<u8> for SeaLevelDesignator {
     }
 }
 
+impl From<AirLevelDesignator> for u8 {
+    fn from(value: AirLevelDesignator) -> Self {
+        value.0
+    }
+}
+
+impl From<SeaLevelDesignator> for u8 {
+    fn from(value: SeaLevelDesignator) -> Self {
+        value.0
+    }
+}
+
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum InvalidAirLevelDesignator {
     #[error("Invalid air level designator {0}")]
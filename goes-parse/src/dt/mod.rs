@@ -3,7 +3,8 @@ use self::{
     addressedmsg::AddressedMessage,
     analysis::Analysis,
     area::{
-        AreaCodeParseError, GeographicalAreaDesignatorParseError, ReferenceTimeDesignatorParseError,
+        AreaCode, AreaCodeParseError, GeographicalAreaDesignatorParseError,
+        ReferenceTimeDesignatorParseError,
     },
     aviationxml::AviationInformationXML,
     bufr::{forecast::ForecastDataBinary, observational::ObservationalDataBinary},
@@ -22,7 +23,7 @@ use self::{
     upperair::UpperAirData,
     warning::Warning,
 };
-use std::{num::ParseIntError, str::FromStr};
+use std::{fmt, num::ParseIntError, str::FromStr};
 
 pub mod product;
 
@@ -43,6 +44,7 @@ pub struct UnparsedProductIdentifier {
 }
 
 /// A data type designator consisting of two alphanumeric characters
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DataTypeDesignator {
     /// A
@@ -161,4 +163,136 @@ pub enum DataTypeDesignatorParseError {
     InvalidSeaLevel(#[from] InvalidSeaLevelDesignator),
 }
 
-impl DataTypeDesignator {}
+impl DataTypeDesignator {
+    /// Get a human-readable description of the product type and the area it covers, i.e.
+    /// `Aerodrome forecast (VT < 12h), United States` for `FCUS80`
+    pub fn description(&self) -> String {
+        match self {
+            Self::Analysis(v) => format!("{}, {}", v.subtype.description(), area_name(&v.area)),
+            Self::AddressedMessage(v) => match v.binary {
+                true => format!("{} (binary)", v.kind.description()),
+                false => v.kind.description().to_owned(),
+            },
+            Self::ClimaticData(v) => {
+                format!("{}, {}", v.subtype.description(), area_name(&v.area))
+            }
+            Self::GridPointInformation(v) => {
+                format!(
+                    "{}, {}, {}",
+                    v.subtype.description(),
+                    v.area.description(),
+                    v.time.description()
+                )
+            }
+            Self::SatelliteImagery(v) => {
+                format!("{}, {}", v.subtype.description(), area_name(&v.area))
+            }
+            Self::Forecast(v) => format!("{}, {}", v.subtype.description(), area_name(&v.area)),
+            Self::ObservationalDataBinaryBUFR(v) => {
+                format!("{}, {}", v.subtype.description(), v.area.description())
+            }
+            Self::ForecastBinaryBUFR(v) => {
+                format!("{}, {}", v.subtype.description(), v.time.description())
+            }
+            Self::CREX(v) => format!("{}, {}", v.subtype.description(), v.area.description()),
+            Self::AviationInformationXML(v) => {
+                format!("{}, {}", v.subtype.description(), area_name(&v.area))
+            }
+            Self::Notice(v) => format!("{}, {}", v.subtype.description(), area_name(&v.area)),
+            Self::OceanographicInformation(v) => {
+                format!(
+                    "{}, {}, {}",
+                    v.subtype.description(),
+                    v.area.description(),
+                    v.time.description()
+                )
+            }
+            Self::PictoralInformationBinary(v) => {
+                format!(
+                    "{}, {}, {}",
+                    v.subtype.description(),
+                    v.area.description(),
+                    v.time.description()
+                )
+            }
+            Self::PictoralInformationRegionalBinary(v) => {
+                format!(
+                    "{}, {}, {}",
+                    v.subtype.description(),
+                    v.area.description(),
+                    v.time.description()
+                )
+            }
+            Self::SurfaceData(v) => {
+                format!("{}, {}", v.subtype.description(), area_name(&v.area))
+            }
+            Self::SatelliteData(v) => {
+                format!(
+                    "{}, {}, {}",
+                    v.subtype.description(),
+                    v.area.description(),
+                    v.time.description()
+                )
+            }
+            Self::UpperAirData(v) => {
+                format!("{}, {}", v.subtype.description(), area_name(&v.area))
+            }
+            Self::National(v) => format!("National data, {}", area_name(&v.area)),
+            Self::Warning(v) => format!("{}, {}", v.subtype.description(), area_name(&v.area)),
+            Self::CommonAlertProtocolMessage(v) => {
+                format!("Common Alert Protocol message, {}", v.area.description())
+            }
+        }
+    }
+}
+
+/// Get the name of an area code, falling back to the code itself for areas not in the table
+fn area_name(area: &AreaCode) -> String {
+    area.description()
+        .map(str::to_owned)
+        .unwrap_or_else(|| area.to_string())
+}
+
+impl From<DataTypeDesignator> for UnparsedProductIdentifier {
+    fn from(value: DataTypeDesignator) -> Self {
+        match value {
+            DataTypeDesignator::Analysis(v) => v.into(),
+            DataTypeDesignator::AddressedMessage(v) => v.into(),
+            DataTypeDesignator::ClimaticData(v) => v.into(),
+            DataTypeDesignator::GridPointInformation(v) => v.into(),
+            DataTypeDesignator::SatelliteImagery(v) => v.into(),
+            DataTypeDesignator::Forecast(v) => v.into(),
+            DataTypeDesignator::ObservationalDataBinaryBUFR(v) => v.into(),
+            DataTypeDesignator::ForecastBinaryBUFR(v) => v.into(),
+            DataTypeDesignator::CREX(v) => v.into(),
+            DataTypeDesignator::AviationInformationXML(v) => v.into(),
+            DataTypeDesignator::Notice(v) => v.into(),
+            DataTypeDesignator::OceanographicInformation(v) => v.into(),
+            DataTypeDesignator::PictoralInformationBinary(v) => v.into(),
+            DataTypeDesignator::PictoralInformationRegionalBinary(v) => v.into(),
+            DataTypeDesignator::SurfaceData(v) => v.into(),
+            DataTypeDesignator::SatelliteData(v) => v.into(),
+            DataTypeDesignator::UpperAirData(v) => v.into(),
+            DataTypeDesignator::National(v) => v.into(),
+            DataTypeDesignator::Warning(v) => v.into(),
+            DataTypeDesignator::CommonAlertProtocolMessage(v) => v.into(),
+        }
+    }
+}
+
+impl fmt::Display for UnparsedProductIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{:02}",
+            self.t1, self.t2, self.a1, self.a2, self.ii
+        )
+    }
+}
+
+/// Writes the `T1T2A1A2ii` code this designator was parsed from
+impl fmt::Display for DataTypeDesignator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        UnparsedProductIdentifier::from(*self).fmt(f)
+    }
+}
@@ -3,15 +3,19 @@ use crate::dt::{DataTypeDesignatorParseError, UnparsedProductIdentifier};
 /// format @ WMO-No. 386 p.103 attachment II-6
 ///
 /// B
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct AddressedMessage {
     /// T2
     pub binary: bool,
     /// A1A2
     pub kind: AddressedMessageType,
+    /// ii
+    pub enumerator: u8,
 }
 
 /// The type of message an [AddressedMessage] is
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AddressedMessageType {
     Administrative,
@@ -42,6 +46,46 @@ impl TryFrom<UnparsedProductIdentifier> for AddressedMessage {
                     ))
                 }
             },
+            enumerator: value.ii,
         })
     }
 }
+
+impl AddressedMessageType {
+    /// Get the A1A2 terms encoding this message type
+    pub const fn a1a2(&self) -> (char, char) {
+        match self {
+            Self::Administrative => ('A', 'A'),
+            Self::Service => ('B', 'B'),
+            Self::GTSRequest => ('R', 'R'),
+            Self::RequestToDB => ('R', 'Q'),
+            Self::GTSOrDBResponse => ('D', 'A'),
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Administrative => "Administrative message",
+            Self::Service => "Service message",
+            Self::GTSRequest => "Request for GTS bulletins",
+            Self::RequestToDB => "Request to a database",
+            Self::GTSOrDBResponse => "Response to a GTS or database request",
+        }
+    }
+}
+
+impl From<AddressedMessage> for UnparsedProductIdentifier {
+    fn from(value: AddressedMessage) -> Self {
+        let (a1, a2) = value.kind.a1a2();
+        Self {
+            t1: 'B',
+            t2: match value.binary {
+                true => 'I',
+                false => 'M',
+            },
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::dt::{area::AreaCode, DataTypeDesignatorParseError, UnparsedProductIdentifier};
 
 /// A
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Analysis {
     /// T2
@@ -12,6 +13,7 @@ pub struct Analysis {
 }
 
 /// Term T2 definition when T1=Analysis
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AnalysisSubType {
     Cyclone,
@@ -48,3 +50,49 @@ impl TryFrom<UnparsedProductIdentifier> for Analysis {
         })
     }
 }
+
+impl AnalysisSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::Cyclone => 'C',
+            Self::Hydrological => 'G',
+            Self::Thickness => 'H',
+            Self::Ice => 'I',
+            Self::Ozone => 'O',
+            Self::Radar => 'R',
+            Self::Surface => 'S',
+            Self::UpperAir => 'U',
+            Self::WeatherSummary => 'W',
+            Self::Misc => 'X',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Cyclone => "Cyclone analysis",
+            Self::Hydrological => "Hydrological/marine analysis",
+            Self::Thickness => "Thickness analysis",
+            Self::Ice => "Ice analysis",
+            Self::Ozone => "Ozone layer analysis",
+            Self::Radar => "Radar analysis",
+            Self::Surface => "Surface analysis",
+            Self::UpperAir => "Upper air analysis",
+            Self::WeatherSummary => "Weather summary",
+            Self::Misc => "Miscellaneous analysis",
+        }
+    }
+}
+
+impl From<Analysis> for UnparsedProductIdentifier {
+    fn from(value: Analysis) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'A',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::dt::{area::AreaCode, DataTypeDesignatorParseError, UnparsedProductIdentifier};
 
 /// L
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct AviationInformationXML {
     /// T2
@@ -12,6 +13,7 @@ pub struct AviationInformationXML {
 }
 
 /// Term T2 definitions when T1=AviationInformationXML
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AviationInformationXMLSubType {
     AviationRoutineReportMETAR,
@@ -54,3 +56,53 @@ impl TryFrom<UnparsedProductIdentifier> for AviationInformationXML {
         })
     }
 }
+
+impl AviationInformationXMLSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::AviationRoutineReportMETAR => 'A',
+            Self::AerodomeForecastTAFVTLT12 => 'C',
+            Self::TropicalCycloneAdvisory => 'K',
+            Self::SpaceWeatherAdvisory => 'N',
+            Self::SpecialAviationWeatherReportSPECI => 'P',
+            Self::AviationGeneralWarningSIGMET => 'S',
+            Self::AerodomeForecastTAFVTGE12 => 'T',
+            Self::VolcanicAshAdvisory => 'U',
+            Self::AviationVolcanicAshWarningSIGMET => 'V',
+            Self::AIRMET => 'W',
+            Self::AviationTropicalCycloneWarningSIGMET => 'Y',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::AviationRoutineReportMETAR => "Aviation routine report (IWXXM METAR)",
+            Self::AerodomeForecastTAFVTLT12 => "Aerodrome forecast (IWXXM TAF, VT < 12h)",
+            Self::TropicalCycloneAdvisory => "Tropical cyclone advisory (IWXXM)",
+            Self::SpaceWeatherAdvisory => "Space weather advisory (IWXXM)",
+            Self::SpecialAviationWeatherReportSPECI => {
+                "Special aviation weather report (IWXXM SPECI)"
+            }
+            Self::AviationGeneralWarningSIGMET => "SIGMET (IWXXM)",
+            Self::AerodomeForecastTAFVTGE12 => "Aerodrome forecast (IWXXM TAF, VT >= 12h)",
+            Self::VolcanicAshAdvisory => "Volcanic ash advisory (IWXXM)",
+            Self::AviationVolcanicAshWarningSIGMET => "Volcanic ash SIGMET (IWXXM)",
+            Self::AIRMET => "AIRMET (IWXXM)",
+            Self::AviationTropicalCycloneWarningSIGMET => "Tropical cyclone SIGMET (IWXXM)",
+        }
+    }
+}
+
+impl From<AviationInformationXML> for UnparsedProductIdentifier {
+    fn from(value: AviationInformationXML) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'L',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
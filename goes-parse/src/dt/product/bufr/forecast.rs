@@ -3,6 +3,7 @@ use crate::dt::{
     UnparsedProductIdentifier,
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ForecastBUFROceanographicData {
     /// I
@@ -17,6 +18,7 @@ pub enum ForecastBUFROceanographicData {
     OtherSeaEnvironmentalData,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ForecastBUFRSurfaceData {
     /// A
@@ -39,6 +41,7 @@ pub enum ForecastBUFRSurfaceData {
     OtherSurface,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ForecastBUFRTextData {
     /// E
@@ -53,6 +56,7 @@ pub enum ForecastBUFRTextData {
     OtherWarning,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ForecastBUFRUpperAirData {
     /// A
@@ -80,25 +84,27 @@ pub enum ForecastBUFRUpperAirData {
 }
 
 /// Term T2 definitions when T1=ObservationalDataBinaryBUFR or ForecastBinaryBUFR
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ForecastDataBinaryBUFRSubType {
-    /// N
-    SatelliteData,
+    /// N, holding the raw A1 term
+    SatelliteData(char),
     /// O
     OceanographicLimnographic(ForecastBUFROceanographicData),
-    /// P
-    Pictorial,
+    /// P, holding the raw A1 term
+    Pictorial(char),
     /// S
     SurfaceSeaLevel(ForecastBUFRSurfaceData),
     /// T
     Text(ForecastBUFRTextData),
     /// U
     UpperAir(ForecastBUFRUpperAirData),
-    /// X
-    Other,
+    /// X, holding the raw A1 term
+    Other(char),
 }
 
 /// J
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ForecastDataBinary {
     /// T2
@@ -114,7 +120,7 @@ impl TryFrom<UnparsedProductIdentifier> for ForecastDataBinary {
     fn try_from(value: UnparsedProductIdentifier) -> Result<Self, Self::Error> {
         Ok(Self {
             subtype: match value.t2 {
-                'N' => ForecastDataBinaryBUFRSubType::SatelliteData,
+                'N' => ForecastDataBinaryBUFRSubType::SatelliteData(value.a1),
                 'O' => ForecastDataBinaryBUFRSubType::OceanographicLimnographic(match value.a1 {
                     'I' => ForecastBUFROceanographicData::SeaIce,
                     'S' => ForecastBUFROceanographicData::SeaSurfaceAndBelow,
@@ -127,7 +133,7 @@ impl TryFrom<UnparsedProductIdentifier> for ForecastDataBinary {
                         ))
                     }
                 }),
-                'P' => ForecastDataBinaryBUFRSubType::Pictorial,
+                'P' => ForecastDataBinaryBUFRSubType::Pictorial(value.a1),
                 'S' => ForecastDataBinaryBUFRSubType::SurfaceSeaLevel(
                     ForecastBUFRSurfaceData::try_from(value)?,
                 ),
@@ -150,7 +156,7 @@ impl TryFrom<UnparsedProductIdentifier> for ForecastDataBinary {
                         ))
                     }
                 }),
-                'X' => ForecastDataBinaryBUFRSubType::Other,
+                'X' => ForecastDataBinaryBUFRSubType::Other(value.a1),
                 other => {
                     return Err(DataTypeDesignatorParseError::UnrecognizedT2(
                         value.t1, other,
@@ -202,3 +208,115 @@ impl TryFrom<UnparsedProductIdentifier> for ForecastBUFRSurfaceData {
         })
     }
 }
+
+impl ForecastBUFROceanographicData {
+    /// Get the A1 term encoding this data type
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::SeaIce => 'I',
+            Self::SeaSurfaceAndBelow => 'S',
+            Self::SeaSurfaceTemperature => 'T',
+            Self::SeaSurfaceWaves => 'W',
+            Self::OtherSeaEnvironmentalData => 'X',
+        }
+    }
+}
+
+impl ForecastBUFRSurfaceData {
+    /// Get the A1 term encoding this data type
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::SurfaceArea => 'A',
+            Self::Radiological => 'D',
+            Self::Surface => 'M',
+            Self::Maritime => 'O',
+            Self::Amendment => 'P',
+            Self::Hydrologic => 'R',
+            Self::AmendmentTAF => 'S',
+            Self::AerodomeTAF => 'T',
+            Self::OtherSurface => 'X',
+        }
+    }
+}
+
+impl ForecastBUFRTextData {
+    /// Get the A1 term encoding this data type
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::Tsunami => 'E',
+            Self::HurricaneTyphoonStormWarning => 'H',
+            Self::SevereWeatherSIGMET => 'S',
+            Self::TornadoWarning => 'T',
+            Self::OtherWarning => 'X',
+        }
+    }
+}
+
+impl ForecastBUFRUpperAirData {
+    /// Get the A1 term encoding this data type
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::SingleLevel => 'A',
+            Self::SIGWXEmbeddedCumulonimbus => 'B',
+            Self::SIGWXClearAirTurbulence => 'C',
+            Self::SIGWXFront => 'F',
+            Self::SIGWXOtherParameters => 'N',
+            Self::SIGWXTurbulence => 'O',
+            Self::Soundings => 'S',
+            Self::SIGWXIcingTropopause => 'T',
+            Self::SIGWXTropicalStormSandstormVolcano => 'V',
+            Self::SIGWXHighLevelWinds => 'W',
+            Self::OtherUpperAir => 'X',
+        }
+    }
+}
+
+impl ForecastDataBinaryBUFRSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::SatelliteData(_) => 'N',
+            Self::OceanographicLimnographic(_) => 'O',
+            Self::Pictorial(_) => 'P',
+            Self::SurfaceSeaLevel(_) => 'S',
+            Self::Text(_) => 'T',
+            Self::UpperAir(_) => 'U',
+            Self::Other(_) => 'X',
+        }
+    }
+
+    /// Get the A1 term encoding this subtype
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::OceanographicLimnographic(v) => v.a1(),
+            Self::SurfaceSeaLevel(v) => v.a1(),
+            Self::Text(v) => v.a1(),
+            Self::UpperAir(v) => v.a1(),
+            Self::SatelliteData(a1) | Self::Pictorial(a1) | Self::Other(a1) => *a1,
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::SatelliteData(_) => "Forecast satellite data (BUFR)",
+            Self::OceanographicLimnographic(_) => "Forecast oceanographic/limnographic data (BUFR)",
+            Self::Pictorial(_) => "Forecast pictorial data (BUFR)",
+            Self::SurfaceSeaLevel(_) => "Forecast surface/sea level data (BUFR)",
+            Self::Text(_) => "Forecast text (BUFR)",
+            Self::UpperAir(_) => "Forecast upper air data (BUFR)",
+            Self::Other(_) => "Other forecast data (BUFR)",
+        }
+    }
+}
+
+impl From<ForecastDataBinary> for UnparsedProductIdentifier {
+    fn from(value: ForecastDataBinary) -> Self {
+        Self {
+            t1: 'J',
+            t2: value.subtype.t2(),
+            a1: value.subtype.a1(),
+            a2: value.time.code(),
+            ii: value.level.into(),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::dt::{
 };
 
 /// I
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ObservationalDataBinary {
     /// T2
@@ -13,6 +14,7 @@ pub struct ObservationalDataBinary {
     pub enumerator: u8,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ObservationalBUFRSatellite {
     AMSUA,
@@ -28,6 +30,7 @@ pub enum ObservationalBUFRSatellite {
     MWTS,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ObservationalBUFROceanic {
     BuoyObservations,
@@ -41,6 +44,7 @@ pub enum ObservationalBUFROceanic {
     DeepOceanTsunameter,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ObservationalBUFRPictoral {
     RadarCompositeImagery,
@@ -49,12 +53,14 @@ pub enum ObservationalBUFRPictoral {
     NotDefined,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LandStation {
     Fixed,
     Mobile,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ObservationalBUFRSurfaceSeaLevel {
     RoutinelyScheduledLandStation,
@@ -80,6 +86,7 @@ pub enum ObservationalBUFRSurfaceSeaLevel {
     OtherSurfaceData,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ObservationalBUFRText {
     AdministrativeMessage,
@@ -88,6 +95,7 @@ pub enum ObservationalBUFRText {
     OtherText,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ObservationalBUFRUpperAir {
     SingleLevelAircraftReportAuto,
@@ -118,6 +126,7 @@ pub enum ObservationalBUFRUpperAir {
 }
 
 /// Term T2 definitions when T1=ObservationalDataBinaryBUFR or ForecastBinaryBUFR
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ObservationalDataBinaryBUFRSubType {
     /// N
@@ -196,7 +205,7 @@ impl TryFrom<UnparsedProductIdentifier> for ObservationalDataBinary {
                     ))
                 }
             },
-            area: GeographicalAreaDesignator::try_from(value.t2)?,
+            area: GeographicalAreaDesignator::try_from(value.a2)?,
             enumerator: value.ii,
         })
     }
@@ -341,3 +350,174 @@ impl TryFrom<UnparsedProductIdentifier> for ObservationalBUFROceanic {
         })
     }
 }
+
+impl ObservationalBUFRSatellite {
+    /// Get the A1 term encoding this instrument
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::AMSUA => 'A',
+            Self::AMSUB => 'B',
+            Self::CrIS => 'C',
+            Self::HIRS => 'H',
+            Self::IRAS => 'I',
+            Self::HIRAS => 'J',
+            Self::MWHS => 'K',
+            Self::MHS => 'M',
+            Self::IASI => 'Q',
+            Self::ATMS => 'S',
+            Self::MWTS => 'T',
+        }
+    }
+}
+
+impl ObservationalBUFROceanic {
+    /// Get the A1 term encoding this data type
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::BuoyObservations => 'B',
+            Self::SeaIce => 'I',
+            Self::SubsurfaceProfilingFloats => 'P',
+            Self::SeasurfaceObservations => 'R',
+            Self::SeasurfaceAndBelowSoundings => 'S',
+            Self::SeasurfaceTemperature => 'T',
+            Self::SeasurfaceWaves => 'W',
+            Self::OtherSeaEnvironmental => 'X',
+            Self::DeepOceanTsunameter => 'Z',
+        }
+    }
+}
+
+impl ObservationalBUFRPictoral {
+    /// Get the A1 term encoding this data type
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::RadarCompositeImagery => 'C',
+            Self::SatelliteImagery => 'I',
+            Self::RadarImagery => 'R',
+            Self::NotDefined => 'X',
+        }
+    }
+}
+
+impl ObservationalBUFRSurfaceSeaLevel {
+    /// Get the A1 term encoding this data type. Some data types are further distinguished by the
+    /// range of ii which is kept separately
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::RoutinelyScheduledLandStation | Self::NMinuteObservationLandStation => 'A',
+            Self::RadarReportAB => 'B',
+            Self::ClimaticObservationLandStation
+            | Self::ClimaticObservationMarine
+            | Self::ClimaticObservationMonthly => 'C',
+            Self::RadiologicalObservation => 'D',
+            Self::SurfaceOzoneMeasurement => 'E',
+            Self::AtmosphericsSource => 'F',
+            Self::IntermediateSynopticObservation(_) => 'I',
+            Self::MainSynopticObservation(_) => 'M',
+            Self::SynopticObservationNonStandardTime(_) => 'N',
+            Self::Hydrologic => 'R',
+            Self::SynopticObservationMarineStation
+            | Self::OneHourObservationMarineStation
+            | Self::NMinuteObservationMarineStation => 'S',
+            Self::TideGaugeObservation | Self::ObservedWaterLevelTimeSeries => 'T',
+            Self::SpecialAeronauticalObservation => 'V',
+            Self::AviationRoutineWeatherObservation => 'W',
+            Self::OtherSurfaceData => 'X',
+        }
+    }
+}
+
+impl ObservationalBUFRText {
+    /// Get the A1 term encoding this data type
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::AdministrativeMessage => 'A',
+            Self::ServiceMessage => 'B',
+            Self::RequestData => 'R',
+            Self::OtherText => 'X',
+        }
+    }
+}
+
+impl ObservationalBUFRUpperAir {
+    /// Get the A1 term encoding this data type. Some data types are further distinguished by the
+    /// range of ii which is kept separately
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::SingleLevelAircraftReportAuto | Self::SingleLevelAircraftReportManual => 'A',
+            Self::SingleLevelBalloonReport => 'B',
+            Self::SingleLevelSatelliteDerivedReport => 'C',
+            Self::Dropsonde => 'D',
+            Self::OzoneVerticalSounding => 'E',
+            Self::DispersalTransportAnalysis => 'I',
+            Self::UpperWindEntireSounding(_) | Self::UpperWindEntireSoundingMarine => 'J',
+            Self::RadioSoundingUpTo100HPA(_) | Self::RadioSoundingUpTo100HPAMarine => 'K',
+            Self::TotalOzone => 'L',
+            Self::ModelDerivedSondes => 'M',
+            Self::Rocketsondes => 'N',
+            Self::AircraftAscendDescendProfile => 'O',
+            Self::Profiler => 'P',
+            Self::RASSTemperatureProfiler => 'Q',
+            Self::Radiance => 'R',
+            Self::RadiosondesSounding(_) | Self::RadiosondesSoundingMarine => 'S',
+            Self::SatelliteDerivedSondes => 'T',
+            Self::MonthlyStatisticsDataMarine => 'U',
+            Self::UpperWindUpTo100HPA(_) | Self::UpperWindMarineUpTo100HPAMarine => 'W',
+            Self::OtherUpperAirReport => 'X',
+        }
+    }
+}
+
+impl ObservationalDataBinaryBUFRSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::SatelliteData(_) => 'N',
+            Self::OceanographicLimnographic(_) => 'O',
+            Self::Pictorial(_) => 'P',
+            Self::SurfaceSeaLevel(_) => 'S',
+            Self::Text(_) => 'T',
+            Self::UpperAir(_) => 'U',
+            Self::Other => 'X',
+        }
+    }
+
+    /// Get the A1 term encoding this subtype
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::SatelliteData(v) => v.a1(),
+            Self::OceanographicLimnographic(v) => v.a1(),
+            Self::Pictorial(v) => v.a1(),
+            Self::SurfaceSeaLevel(v) => v.a1(),
+            Self::Text(v) => v.a1(),
+            Self::UpperAir(v) => v.a1(),
+            Self::Other => 'X',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::SatelliteData(_) => "Observational satellite data (BUFR)",
+            Self::OceanographicLimnographic(_) => {
+                "Observational oceanographic/limnographic data (BUFR)"
+            }
+            Self::Pictorial(_) => "Observational pictorial data (BUFR)",
+            Self::SurfaceSeaLevel(_) => "Observational surface/sea level data (BUFR)",
+            Self::Text(_) => "Observational text (BUFR)",
+            Self::UpperAir(_) => "Observational upper air data (BUFR)",
+            Self::Other => "Other observational data (BUFR)",
+        }
+    }
+}
+
+impl From<ObservationalDataBinary> for UnparsedProductIdentifier {
+    fn from(value: ObservationalDataBinary) -> Self {
+        Self {
+            t1: 'I',
+            t2: value.subtype.t2(),
+            a1: value.subtype.a1(),
+            a2: value.area.into(),
+            ii: value.enumerator,
+        }
+    }
+}
@@ -3,20 +3,39 @@ use crate::dt::{
     DataTypeDesignatorParseError, UnparsedProductIdentifier,
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CommonAlertProtocolMessage {
+    /// T2
+    pub t2: char,
     /// A1
     pub area: GeographicalAreaDesignator,
     /// A2
     pub time: ReferenceTimeDesignator,
+    /// ii
+    pub enumerator: u8,
 }
 
 impl TryFrom<UnparsedProductIdentifier> for CommonAlertProtocolMessage {
     type Error = DataTypeDesignatorParseError;
     fn try_from(value: UnparsedProductIdentifier) -> Result<Self, Self::Error> {
         Ok(Self {
+            t2: value.t2,
             area: GeographicalAreaDesignator::try_from(value.a1)?,
             time: ReferenceTimeDesignator::parse_for_qxy(value.a2)?,
+            enumerator: value.ii,
         })
     }
 }
+
+impl From<CommonAlertProtocolMessage> for UnparsedProductIdentifier {
+    fn from(value: CommonAlertProtocolMessage) -> Self {
+        Self {
+            t1: 'X',
+            t2: value.t2,
+            a1: value.area.into(),
+            a2: value.time.code(),
+            ii: value.enumerator,
+        }
+    }
+}
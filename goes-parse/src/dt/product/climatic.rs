@@ -3,6 +3,7 @@ use crate::dt::{
 };
 
 /// C
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ClimaticData {
     /// T2
@@ -14,12 +15,16 @@ pub struct ClimaticData {
 }
 
 /// Term T2 definition when T1=ClimaticData
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ClimaticDataSubType {
     Anomaly,
+    /// National climate summaries such as the NWS daily climate report (CLI)
+    Summary,
     UpperAirMonthlyMean,
     SurfaceMonthlyMean(CodeForm),
     OceanMonthlyMean,
+    Misc,
 }
 
 impl TryFrom<UnparsedProductIdentifier> for ClimaticData {
@@ -28,10 +33,12 @@ impl TryFrom<UnparsedProductIdentifier> for ClimaticData {
         Ok(Self {
             subtype: match value.t2 {
                 'A' => ClimaticDataSubType::Anomaly,
+                'D' => ClimaticDataSubType::Summary,
                 'E' => ClimaticDataSubType::UpperAirMonthlyMean,
                 'H' => ClimaticDataSubType::SurfaceMonthlyMean(CodeForm::CLIMATSHIP),
                 'O' => ClimaticDataSubType::OceanMonthlyMean,
                 'S' => ClimaticDataSubType::SurfaceMonthlyMean(CodeForm::CLIMAT),
+                'X' => ClimaticDataSubType::Misc,
                 other => return Err(DataTypeDesignatorParseError::UnrecognizedT2('C', other)),
             },
             area: AreaCode::try_from((value.a1, value.a2))?,
@@ -39,3 +46,45 @@ impl TryFrom<UnparsedProductIdentifier> for ClimaticData {
         })
     }
 }
+
+impl ClimaticDataSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::Anomaly => 'A',
+            Self::Summary => 'D',
+            Self::UpperAirMonthlyMean => 'E',
+            Self::SurfaceMonthlyMean(CodeForm::CLIMATSHIP) => 'H',
+            Self::SurfaceMonthlyMean(_) => 'S',
+            Self::OceanMonthlyMean => 'O',
+            Self::Misc => 'X',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Anomaly => "Climatic anomalies",
+            Self::Summary => "Climate summary",
+            Self::UpperAirMonthlyMean => "Monthly means (upper air)",
+            Self::SurfaceMonthlyMean(CodeForm::CLIMATSHIP) => {
+                "Monthly means (surface, CLIMAT SHIP)"
+            }
+            Self::SurfaceMonthlyMean(_) => "Monthly means (surface, CLIMAT)",
+            Self::OceanMonthlyMean => "Monthly means (ocean areas)",
+            Self::Misc => "Miscellaneous climatic data",
+        }
+    }
+}
+
+impl From<ClimaticData> for UnparsedProductIdentifier {
+    fn from(value: ClimaticData) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'C',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
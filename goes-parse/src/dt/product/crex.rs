@@ -8,6 +8,7 @@ use super::{
 };
 
 /// K
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CREX {
     /// T2A1
@@ -18,6 +19,7 @@ pub struct CREX {
     pub enumerator: u8,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CREXPictoral {
     SeaIce,
@@ -28,6 +30,7 @@ pub enum CREXPictoral {
 }
 
 /// Term T2 definitions when T1=ObservationalDataBinaryBUFR or ForecastBinaryBUFR
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CREXSubType {
     /// F
@@ -44,8 +47,8 @@ pub enum CREXSubType {
     UpperAir(ObservationalBUFRUpperAir),
     /// V
     SIGW(ForecastBUFRSurfaceData),
-    /// X
-    Other,
+    /// X, holding the raw A1 term
+    Other(char),
 }
 
 impl TryFrom<UnparsedProductIdentifier> for CREX {
@@ -75,7 +78,7 @@ impl TryFrom<UnparsedProductIdentifier> for CREX {
                 'T' => CREXSubType::Text(ForecastBUFRTextData::try_from(value)?),
                 'U' => CREXSubType::UpperAir(ObservationalBUFRUpperAir::try_from(value)?),
                 'V' => CREXSubType::SIGW(ForecastBUFRSurfaceData::try_from(value)?),
-                'X' => CREXSubType::Other,
+                'X' => CREXSubType::Other(value.a1),
                 other => {
                     return Err(DataTypeDesignatorParseError::UnrecognizedT2(
                         value.t1, other,
@@ -87,3 +90,70 @@ impl TryFrom<UnparsedProductIdentifier> for CREX {
         })
     }
 }
+
+impl CREXPictoral {
+    /// Get the A1 term encoding this data type
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::SeaIce => 'I',
+            Self::SeaSurfaceAndBelowSoundings => 'S',
+            Self::SeaSurfaceTemperature => 'T',
+            Self::SeaSurfaceWaves => 'W',
+            Self::OtherSeaEnvironmental => 'X',
+        }
+    }
+}
+
+impl CREXSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::Forecast(_) => 'F',
+            Self::OceanographicLimnographic(_) => 'O',
+            Self::Pictorial(_) => 'P',
+            Self::SurfaceSeaLevel(_) => 'S',
+            Self::Text(_) => 'T',
+            Self::UpperAir(_) => 'U',
+            Self::SIGW(_) => 'V',
+            Self::Other(_) => 'X',
+        }
+    }
+
+    /// Get the A1 term encoding this subtype
+    pub const fn a1(&self) -> char {
+        match self {
+            Self::Forecast(v) | Self::SIGW(v) => v.a1(),
+            Self::OceanographicLimnographic(v) => v.a1(),
+            Self::Pictorial(v) => v.a1(),
+            Self::SurfaceSeaLevel(v) => v.a1(),
+            Self::Text(v) => v.a1(),
+            Self::UpperAir(v) => v.a1(),
+            Self::Other(a1) => *a1,
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Forecast(_) => "Forecast data (CREX)",
+            Self::OceanographicLimnographic(_) => "Oceanographic/limnographic data (CREX)",
+            Self::Pictorial(_) => "Pictorial data (CREX)",
+            Self::SurfaceSeaLevel(_) => "Surface/sea level data (CREX)",
+            Self::Text(_) => "Text (CREX)",
+            Self::UpperAir(_) => "Upper air data (CREX)",
+            Self::SIGW(_) => "Significant weather (CREX)",
+            Self::Other(_) => "Other data (CREX)",
+        }
+    }
+}
+
+impl From<CREX> for UnparsedProductIdentifier {
+    fn from(value: CREX) -> Self {
+        Self {
+            t1: 'K',
+            t2: value.subtype.t2(),
+            a1: value.subtype.a1(),
+            a2: value.area.into(),
+            ii: value.enumerator,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::dt::{area::AreaCode, DataTypeDesignatorParseError, UnparsedProductIdentifier};
 
 /// F
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Forecast {
     /// T2
@@ -12,6 +13,7 @@ pub struct Forecast {
 }
 
 /// Term T2 definition when T1=Forecast
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ForecastSubType {
     AviationGAMETAdvisories,
@@ -78,3 +80,79 @@ impl TryFrom<UnparsedProductIdentifier> for Forecast {
         })
     }
 }
+
+impl ForecastSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::AviationGAMETAdvisories => 'A',
+            Self::UpperWindsTemps => 'B',
+            Self::AerodomeVTLT12 => 'C',
+            Self::RadiologicalTrajectoryDose => 'D',
+            Self::Extended => 'E',
+            Self::Shipping => 'F',
+            Self::Hydrological => 'G',
+            Self::UpperAirThickness => 'H',
+            Self::Iceberg => 'I',
+            Self::RadioWarningService => 'J',
+            Self::TropicalCycloneAdvisory => 'K',
+            Self::Local => 'L',
+            Self::TemperatureExtreme => 'M',
+            Self::SpaceWeatherAdvisory => 'N',
+            Self::Guidance => 'O',
+            Self::Public => 'P',
+            Self::OtherShipping => 'Q',
+            Self::AviationRoute => 'R',
+            Self::Surface => 'S',
+            Self::AerodomeVTGE12 => 'T',
+            Self::UpperAir => 'U',
+            Self::VolcanicAshAdvisory => 'V',
+            Self::WinterSports => 'W',
+            Self::Misc => 'X',
+            Self::ShippingArea => 'Z',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::AviationGAMETAdvisories => "Aviation area forecast/GAMET/advisories",
+            Self::UpperWindsTemps => "Upper winds and temperatures forecast",
+            Self::AerodomeVTLT12 => "Aerodrome forecast (VT < 12h)",
+            Self::RadiologicalTrajectoryDose => "Radiological trajectory dose forecast",
+            Self::Extended => "Extended forecast",
+            Self::Shipping => "Shipping forecast",
+            Self::Hydrological => "Hydrological forecast",
+            Self::UpperAirThickness => "Upper air thickness forecast",
+            Self::Iceberg => "Iceberg forecast",
+            Self::RadioWarningService => "Radio warning service",
+            Self::TropicalCycloneAdvisory => "Tropical cyclone advisory",
+            Self::Local => "Local/area forecast",
+            Self::TemperatureExtreme => "Temperature extremes forecast",
+            Self::SpaceWeatherAdvisory => "Space weather advisory",
+            Self::Guidance => "Guidance",
+            Self::Public => "Public forecast",
+            Self::OtherShipping => "Other shipping forecast",
+            Self::AviationRoute => "Aviation route forecast",
+            Self::Surface => "Surface forecast",
+            Self::AerodomeVTGE12 => "Aerodrome forecast (VT >= 12h)",
+            Self::UpperAir => "Upper air forecast",
+            Self::VolcanicAshAdvisory => "Volcanic ash advisory",
+            Self::WinterSports => "Winter sports forecast",
+            Self::Misc => "Miscellaneous forecast",
+            Self::ShippingArea => "Shipping area forecast",
+        }
+    }
+}
+
+impl From<Forecast> for UnparsedProductIdentifier {
+    fn from(value: Forecast) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'F',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
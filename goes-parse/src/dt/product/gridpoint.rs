@@ -5,8 +5,11 @@ use crate::dt::{
 };
 
 /// D, G, H, Y
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct GridPointInformation {
+    /// T1, one of D, G, H, or Y
+    pub t1: char,
     /// T2
     pub subtype: GridPointSubType,
     /// A1
@@ -18,6 +21,7 @@ pub struct GridPointInformation {
 }
 
 /// Term T2 definitions when T1=GridPointInformation
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GridPointSubType {
     RadarData,
@@ -36,6 +40,7 @@ impl TryFrom<UnparsedProductIdentifier> for GridPointInformation {
     type Error = DataTypeDesignatorParseError;
     fn try_from(value: UnparsedProductIdentifier) -> Result<Self, Self::Error> {
         Ok(Self {
+            t1: value.t1,
             subtype: match value.t2 {
                 'A' => GridPointSubType::RadarData,
                 'B' => GridPointSubType::Cloud,
@@ -62,3 +67,48 @@ impl TryFrom<UnparsedProductIdentifier> for GridPointInformation {
         })
     }
 }
+
+impl GridPointSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::RadarData => 'A',
+            Self::Cloud => 'B',
+            Self::Vorticity => 'C',
+            Self::Thickness => 'D',
+            Self::Precipitation => 'E',
+            Self::Divergence => 'G',
+            Self::Height => 'H',
+            Self::WaveHeight => 'J',
+            Self::SwellHeight => 'K',
+            Self::NationalUse => 'M',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::RadarData => "Radar data grid",
+            Self::Cloud => "Cloud grid",
+            Self::Vorticity => "Vorticity grid",
+            Self::Thickness => "Thickness (relative topography) grid",
+            Self::Precipitation => "Precipitation grid",
+            Self::Divergence => "Divergence grid",
+            Self::Height => "Height grid",
+            Self::WaveHeight => "Wave height grid",
+            Self::SwellHeight => "Swell height grid",
+            Self::NationalUse => "Grid for national use",
+        }
+    }
+}
+
+impl From<GridPointInformation> for UnparsedProductIdentifier {
+    fn from(value: GridPointInformation) -> Self {
+        Self {
+            t1: value.t1,
+            t2: value.subtype.t2(),
+            a1: value.area.into(),
+            a2: value.time.code(),
+            ii: value.level.into(),
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use crate::dt::{area::AreaCode, DataTypeDesignatorParseError, UnparsedProductIdentifier};
 
 /// V
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct National {
+    /// T2
+    pub t2: char,
     /// A1A2
     pub area: AreaCode,
     /// ii
@@ -13,8 +16,22 @@ impl TryFrom<UnparsedProductIdentifier> for National {
     type Error = DataTypeDesignatorParseError;
     fn try_from(value: UnparsedProductIdentifier) -> Result<Self, Self::Error> {
         Ok(Self {
+            t2: value.t2,
             area: AreaCode::try_from((value.a1, value.a2))?,
             enumerator: value.ii,
         })
     }
 }
+
+impl From<National> for UnparsedProductIdentifier {
+    fn from(value: National) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'V',
+            t2: value.t2,
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
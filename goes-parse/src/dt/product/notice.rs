@@ -1,6 +1,7 @@
 use crate::dt::{area::AreaCode, DataTypeDesignatorParseError, UnparsedProductIdentifier};
 
 /// N
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Notice {
     /// T2
@@ -12,6 +13,7 @@ pub struct Notice {
 }
 
 /// Term T2 definition when T1=Notice
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NoticeSubType {
     Hydrological,
@@ -42,3 +44,43 @@ impl TryFrom<UnparsedProductIdentifier> for Notice {
         })
     }
 }
+
+impl NoticeSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::Hydrological => 'G',
+            Self::Marine => 'H',
+            Self::NuclearEmergencyResponse => 'N',
+            Self::METNOWIFMA => 'O',
+            Self::ProductGenerationDelay => 'P',
+            Self::TESTMSG => 'T',
+            Self::WarningRelatedOrCancellation => 'W',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Hydrological => "Hydrological notice",
+            Self::Marine => "Marine notice",
+            Self::NuclearEmergencyResponse => "Nuclear emergency response notice",
+            Self::METNOWIFMA => "METNO/WIFMA notice",
+            Self::ProductGenerationDelay => "Product generation delay notice",
+            Self::TESTMSG => "Test message",
+            Self::WarningRelatedOrCancellation => "Warning related and/or cancellation notice",
+        }
+    }
+}
+
+impl From<Notice> for UnparsedProductIdentifier {
+    fn from(value: Notice) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'N',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
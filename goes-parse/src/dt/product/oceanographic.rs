@@ -5,6 +5,7 @@ use crate::dt::{
 };
 
 /// O
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct OceanographicInformation {
     /// T2
@@ -18,6 +19,7 @@ pub struct OceanographicInformation {
 }
 
 /// Term T2 definitions when T1=OceanographicInformation
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OceanographicSubType {
     Depth,
@@ -30,7 +32,8 @@ pub enum OceanographicSubType {
     DepthAnomaly,
     Salinity,
     Temperature,
-    CurrentComponent,
+    /// U or V, holding the raw T2 term
+    CurrentComponent(char),
     TemperatureWarming,
     Mixed,
 }
@@ -50,7 +53,7 @@ impl TryFrom<UnparsedProductIdentifier> for OceanographicInformation {
                 'R' => OceanographicSubType::DepthAnomaly,
                 'S' => OceanographicSubType::Salinity,
                 'T' => OceanographicSubType::Temperature,
-                'U' | 'V' => OceanographicSubType::CurrentComponent,
+                t2 @ ('U' | 'V') => OceanographicSubType::CurrentComponent(t2),
                 'W' => OceanographicSubType::TemperatureWarming,
                 'X' => OceanographicSubType::Mixed,
                 other => {
@@ -65,3 +68,54 @@ impl TryFrom<UnparsedProductIdentifier> for OceanographicInformation {
         })
     }
 }
+
+impl OceanographicSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::Depth => 'D',
+            Self::IceConcentration => 'E',
+            Self::IceThickness => 'F',
+            Self::IceDrift => 'G',
+            Self::IceGrowth => 'H',
+            Self::IceConvergenceDivergence => 'I',
+            Self::TemperatureAnomaly => 'Q',
+            Self::DepthAnomaly => 'R',
+            Self::Salinity => 'S',
+            Self::Temperature => 'T',
+            Self::CurrentComponent(t2) => *t2,
+            Self::TemperatureWarming => 'W',
+            Self::Mixed => 'X',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Depth => "Ocean depth",
+            Self::IceConcentration => "Ice concentration",
+            Self::IceThickness => "Ice thickness",
+            Self::IceDrift => "Ice drift",
+            Self::IceGrowth => "Ice growth",
+            Self::IceConvergenceDivergence => "Ice convergence/divergence",
+            Self::TemperatureAnomaly => "Ocean temperature anomaly",
+            Self::DepthAnomaly => "Ocean depth anomaly",
+            Self::Salinity => "Salinity",
+            Self::Temperature => "Ocean temperature",
+            Self::CurrentComponent(_) => "Ocean current component",
+            Self::TemperatureWarming => "Ocean temperature warming",
+            Self::Mixed => "Mixed oceanographic data",
+        }
+    }
+}
+
+impl From<OceanographicInformation> for UnparsedProductIdentifier {
+    fn from(value: OceanographicInformation) -> Self {
+        Self {
+            t1: 'O',
+            t2: value.subtype.t2(),
+            a1: value.area.into(),
+            a2: value.time.code(),
+            ii: value.level.into(),
+        }
+    }
+}
@@ -5,6 +5,7 @@ use crate::dt::{
 };
 
 /// P
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct PictoralInformation {
     /// T2
@@ -18,6 +19,7 @@ pub struct PictoralInformation {
 }
 
 /// Term T2 definitions when T1=PictoralInformationBinary or PictoralInformationRegionalBinary
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PictoralInformationSubType {
     RadarData,
@@ -91,3 +93,78 @@ impl TryFrom<UnparsedProductIdentifier> for PictoralInformation {
         })
     }
 }
+
+impl PictoralInformationSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::RadarData => 'A',
+            Self::Cloud => 'B',
+            Self::ClearAirTurbulence => 'C',
+            Self::Thickness => 'D',
+            Self::Precipitation => 'E',
+            Self::AerologicalDiagrams => 'F',
+            Self::SignificantWeather => 'G',
+            Self::Height => 'H',
+            Self::IceFlow => 'I',
+            Self::WaveHeight => 'J',
+            Self::SwellHeight => 'K',
+            Self::PlainLanguage => 'L',
+            Self::NationalUse => 'M',
+            Self::Radiation => 'N',
+            Self::VerticalVelocity => 'O',
+            Self::Pressure => 'P',
+            Self::WetBulbPotentialTemperature => 'Q',
+            Self::RelativeHumidity => 'R',
+            Self::SnowCover => 'S',
+            Self::Temperature => 'T',
+            Self::EastwardWindComponent => 'U',
+            Self::NorthwardWindComponent => 'V',
+            Self::Wind => 'W',
+            Self::LiftedIndex => 'X',
+            Self::ObservationalPlottedChart => 'Y',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::RadarData => "Radar data chart",
+            Self::Cloud => "Cloud chart",
+            Self::ClearAirTurbulence => "Clear air turbulence chart",
+            Self::Thickness => "Thickness (relative topography) chart",
+            Self::Precipitation => "Precipitation chart",
+            Self::AerologicalDiagrams => "Aerological diagram",
+            Self::SignificantWeather => "Significant weather chart",
+            Self::Height => "Height chart",
+            Self::IceFlow => "Ice flow chart",
+            Self::WaveHeight => "Wave height chart",
+            Self::SwellHeight => "Swell height chart",
+            Self::PlainLanguage => "Plain language chart",
+            Self::NationalUse => "Chart for national use",
+            Self::Radiation => "Radiation chart",
+            Self::VerticalVelocity => "Vertical velocity chart",
+            Self::Pressure => "Pressure chart",
+            Self::WetBulbPotentialTemperature => "Wet bulb potential temperature chart",
+            Self::RelativeHumidity => "Relative humidity chart",
+            Self::SnowCover => "Snow cover chart",
+            Self::Temperature => "Temperature chart",
+            Self::EastwardWindComponent => "Eastward wind component chart",
+            Self::NorthwardWindComponent => "Northward wind component chart",
+            Self::Wind => "Wind chart",
+            Self::LiftedIndex => "Lifted index chart",
+            Self::ObservationalPlottedChart => "Observational plotted chart",
+        }
+    }
+}
+
+impl From<PictoralInformation> for UnparsedProductIdentifier {
+    fn from(value: PictoralInformation) -> Self {
+        Self {
+            t1: 'P',
+            t2: value.subtype.t2(),
+            a1: value.area.into(),
+            a2: value.time.code(),
+            ii: value.level.into(),
+        }
+    }
+}
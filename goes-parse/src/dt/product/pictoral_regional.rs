@@ -7,6 +7,7 @@ use crate::dt::{
 use super::pictoral::PictoralInformationSubType;
 
 /// Q
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct RegionalPictoralInformation {
     /// T2
@@ -30,3 +31,15 @@ impl TryFrom<UnparsedProductIdentifier> for RegionalPictoralInformation {
         })
     }
 }
+
+impl From<RegionalPictoralInformation> for UnparsedProductIdentifier {
+    fn from(value: RegionalPictoralInformation) -> Self {
+        Self {
+            t1: 'Q',
+            t2: value.subtype.t2(),
+            a1: value.area.into(),
+            a2: value.time.code(),
+            ii: value.level.into(),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::dt::{
 };
 
 /// T
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SatelliteData {
     /// T2
@@ -17,6 +18,7 @@ pub struct SatelliteData {
 }
 
 /// Term T2 definition when T1=satellite
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SatelliteDataSubType {
     SatelliteOrbitParameters,
@@ -48,3 +50,42 @@ impl TryFrom<UnparsedProductIdentifier> for SatelliteData {
         })
     }
 }
+
+impl SatelliteDataSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::SatelliteOrbitParameters => 'B',
+            Self::SatelliteCloudInterpretations => 'C',
+            Self::SatelliteRemoteUpperAirSoundings => 'H',
+            Self::ClearRadianceObservations => 'R',
+            Self::SeaSurfaceTemperatures => 'T',
+            Self::WindsAndCloudsTemperatures => 'W',
+            Self::Misc => 'X',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::SatelliteOrbitParameters => "Satellite orbit parameters",
+            Self::SatelliteCloudInterpretations => "Satellite cloud interpretations",
+            Self::SatelliteRemoteUpperAirSoundings => "Satellite remote upper air soundings",
+            Self::ClearRadianceObservations => "Clear radiance observations",
+            Self::SeaSurfaceTemperatures => "Sea surface temperatures",
+            Self::WindsAndCloudsTemperatures => "Winds and cloud temperatures",
+            Self::Misc => "Miscellaneous satellite data",
+        }
+    }
+}
+
+impl From<SatelliteData> for UnparsedProductIdentifier {
+    fn from(value: SatelliteData) -> Self {
+        Self {
+            t1: 'T',
+            t2: value.subtype.t2(),
+            a1: value.area.into(),
+            a2: value.time.code(),
+            ii: value.enumerator,
+        }
+    }
+}
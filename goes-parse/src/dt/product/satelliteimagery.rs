@@ -1,13 +1,17 @@
 use crate::dt::{area::AreaCode, DataTypeDesignatorParseError, UnparsedProductIdentifier};
 
 /// E
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SatelliteImagery {
     pub subtype: SatelliteImagerySubType,
     pub area: AreaCode,
+    /// ii
+    pub enumerator: u8,
 }
 
 /// Term T2 definitions when T1=SatelliteImagery
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SatelliteImagerySubType {
     CloudTopTemperature,
@@ -32,6 +36,41 @@ impl TryFrom<UnparsedProductIdentifier> for SatelliteImagery {
                 }
             },
             area: AreaCode::try_from((value.a1, value.a2))?,
+            enumerator: value.ii,
         })
     }
 }
+
+impl SatelliteImagerySubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::CloudTopTemperature => 'C',
+            Self::Fog => 'F',
+            Self::Infared => 'I',
+            Self::SurfaceTemperature => 'S',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::CloudTopTemperature => "Cloud top temperature satellite imagery",
+            Self::Fog => "Fog satellite imagery",
+            Self::Infared => "Infrared satellite imagery",
+            Self::SurfaceTemperature => "Surface temperature satellite imagery",
+        }
+    }
+}
+
+impl From<SatelliteImagery> for UnparsedProductIdentifier {
+    fn from(value: SatelliteImagery) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'E',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
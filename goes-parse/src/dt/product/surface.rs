@@ -1,6 +1,7 @@
 use crate::dt::{area::AreaCode, DataTypeDesignatorParseError, UnparsedProductIdentifier};
 
 /// S
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SurfaceData {
     /// T2
@@ -12,6 +13,7 @@ pub struct SurfaceData {
 }
 
 /// Term T2 definition when T1=Surface
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SurfaceSubType {
     AviationRoutineReport,
@@ -72,3 +74,73 @@ impl TryFrom<UnparsedProductIdentifier> for SurfaceData {
         })
     }
 }
+
+impl SurfaceSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::AviationRoutineReport => 'A',
+            Self::RadarReportA => 'B',
+            Self::RadarReportB => 'C',
+            Self::RadarReportAB => 'D',
+            Self::Seismic => 'E',
+            Self::AtmosphericReport => 'F',
+            Self::RadiologicalDataReport => 'G',
+            Self::DCPStationReport => 'H',
+            Self::IntermediateSynopticHour => 'I',
+            Self::MainSynopticHour => 'M',
+            Self::NonstandardSynopticHour => 'N',
+            Self::OceanographicData => 'O',
+            Self::SpecialAviationWeatherReport => 'P',
+            Self::HydrologicalRiverReport => 'R',
+            Self::DriftingBuoyReport => 'S',
+            Self::SeaIce => 'T',
+            Self::SnowDepth => 'U',
+            Self::LakeIce => 'V',
+            Self::WaveInformation => 'W',
+            Self::Misc => 'X',
+            Self::SeismicWaveformData => 'Y',
+            Self::SeaLevelDeepOceanTsunamiData => 'Z',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::AviationRoutineReport => "Aviation routine report (METAR)",
+            Self::RadarReportA => "Radar report (part A)",
+            Self::RadarReportB => "Radar report (part B)",
+            Self::RadarReportAB => "Radar report (parts A and B)",
+            Self::Seismic => "Seismic data",
+            Self::AtmosphericReport => "Atmospherics report",
+            Self::RadiologicalDataReport => "Radiological data report",
+            Self::DCPStationReport => "DCP station report",
+            Self::IntermediateSynopticHour => "Intermediate synoptic hour surface observations",
+            Self::MainSynopticHour => "Main synoptic hour surface observations",
+            Self::NonstandardSynopticHour => "Non-standard synoptic hour surface observations",
+            Self::OceanographicData => "Oceanographic data",
+            Self::SpecialAviationWeatherReport => "Special aviation weather report (SPECI)",
+            Self::HydrologicalRiverReport => "Hydrological (river) report",
+            Self::DriftingBuoyReport => "Drifting buoy report",
+            Self::SeaIce => "Sea ice observations",
+            Self::SnowDepth => "Snow depth observations",
+            Self::LakeIce => "Lake ice observations",
+            Self::WaveInformation => "Wave information",
+            Self::Misc => "Miscellaneous surface data",
+            Self::SeismicWaveformData => "Seismic waveform data",
+            Self::SeaLevelDeepOceanTsunamiData => "Sea level and deep ocean tsunami data",
+        }
+    }
+}
+
+impl From<SurfaceData> for UnparsedProductIdentifier {
+    fn from(value: SurfaceData) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'S',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
@@ -5,6 +5,7 @@ use crate::dt::{
 };
 
 /// U
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct UpperAirData {
     /// T2
@@ -17,6 +18,7 @@ pub struct UpperAirData {
 
 /// Subset of [CodeForm] that contains variants that can be parsed to an
 /// [UpperAirDataSubType::AircraftReport]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AircraftReportCodeForm {
     ICAO,
@@ -26,6 +28,7 @@ pub enum AircraftReportCodeForm {
 }
 
 /// Term T2 definitions when T1=UpperAirData
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UpperAirDataSubType {
     AircraftReport(AircraftReportCodeForm),
@@ -104,3 +107,81 @@ impl Deref for AircraftReportCodeForm {
         }
     }
 }
+
+impl UpperAirDataSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::AircraftReport(AircraftReportCodeForm::ICAO) => 'A',
+            Self::AircraftReport(AircraftReportCodeForm::AMDAR) => 'D',
+            Self::UpperLevelPressureTemperatureHumidityWindD => 'E',
+            Self::UpperLevelPressureTemperatureHumidityWindCD => 'F',
+            Self::UpperWindB => 'G',
+            Self::UpperWindC => 'H',
+            Self::UpperWindAB => 'I',
+            Self::UpperLevelPressureTemperatureHumidityWindB => 'K',
+            Self::UpperLevelPressureTemperatureHumidityWindC => 'L',
+            Self::UpperLevelPressureTemperatureHumidityWindAB => 'M',
+            Self::RocketsondeReport => 'N',
+            Self::UpperWindA => 'P',
+            Self::UpperWindD => 'Q',
+            Self::AircraftReport(AircraftReportCodeForm::RECCO) => 'R',
+            Self::UpperLevelPressureTemperatureHumidityWindA => 'S',
+            Self::AircraftReport(AircraftReportCodeForm::CODAR) => 'T',
+            Self::Misc => 'X',
+            Self::UpperWindCD => 'Y',
+            Self::UpperLevelPressureTemperatureHumidityWindABCD => 'Z',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::AircraftReport(AircraftReportCodeForm::ICAO) => "Aircraft report (ICAO)",
+            Self::AircraftReport(AircraftReportCodeForm::AMDAR) => "Aircraft report (AMDAR)",
+            Self::AircraftReport(AircraftReportCodeForm::RECCO) => "Aircraft report (RECCO)",
+            Self::AircraftReport(AircraftReportCodeForm::CODAR) => "Aircraft report (CODAR)",
+            Self::UpperLevelPressureTemperatureHumidityWindA => {
+                "Upper level pressure, temperature, humidity, and wind (part A)"
+            }
+            Self::UpperLevelPressureTemperatureHumidityWindB => {
+                "Upper level pressure, temperature, humidity, and wind (part B)"
+            }
+            Self::UpperLevelPressureTemperatureHumidityWindC => {
+                "Upper level pressure, temperature, humidity, and wind (part C)"
+            }
+            Self::UpperLevelPressureTemperatureHumidityWindD => {
+                "Upper level pressure, temperature, humidity, and wind (part D)"
+            }
+            Self::UpperLevelPressureTemperatureHumidityWindAB => {
+                "Upper level pressure, temperature, humidity, and wind (parts A and B)"
+            }
+            Self::UpperLevelPressureTemperatureHumidityWindCD => {
+                "Upper level pressure, temperature, humidity, and wind (parts C and D)"
+            }
+            Self::UpperLevelPressureTemperatureHumidityWindABCD => {
+                "Upper level pressure, temperature, humidity, and wind (parts A, B, C, and D)"
+            }
+            Self::UpperWindA => "Upper wind (part A)",
+            Self::UpperWindB => "Upper wind (part B)",
+            Self::UpperWindC => "Upper wind (part C)",
+            Self::UpperWindD => "Upper wind (part D)",
+            Self::UpperWindAB => "Upper wind (parts A and B)",
+            Self::UpperWindCD => "Upper wind (parts C and D)",
+            Self::RocketsondeReport => "Rocketsonde report",
+            Self::Misc => "Miscellaneous upper air data",
+        }
+    }
+}
+
+impl From<UpperAirData> for UnparsedProductIdentifier {
+    fn from(value: UpperAirData) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'U',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
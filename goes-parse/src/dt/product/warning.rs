@@ -1,6 +1,7 @@
 use crate::dt::{area::AreaCode, DataTypeDesignatorParseError, UnparsedProductIdentifier};
 
 /// W
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Warning {
     /// T2
@@ -12,6 +13,7 @@ pub struct Warning {
 }
 
 /// Term T2 definitions when T1=Warning
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WarningSubType {
     AIRMET,
@@ -54,3 +56,55 @@ impl TryFrom<UnparsedProductIdentifier> for Warning {
         })
     }
 }
+
+impl WarningSubType {
+    /// Get the T2 term encoding this subtype
+    pub const fn t2(&self) -> char {
+        match self {
+            Self::AIRMET => 'A',
+            Self::TropicalCycloneSIGMET => 'C',
+            Self::Tsunami => 'E',
+            Self::Tornado => 'F',
+            Self::HydrologicalRiverFlood => 'G',
+            Self::MarineCoastalFlood => 'H',
+            Self::Other => 'O',
+            Self::HumanitarianActivities => 'R',
+            Self::SIGMET => 'S',
+            Self::TropicalCycloneTyphoonHurricane => 'T',
+            Self::SevereThunderstorm => 'U',
+            Self::VolcanicAshCloudsSIGMET => 'V',
+            Self::WarningsWeatherSummary => 'W',
+        }
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::AIRMET => "AIRMET",
+            Self::TropicalCycloneSIGMET => "Tropical cyclone SIGMET",
+            Self::Tsunami => "Tsunami warning",
+            Self::Tornado => "Tornado warning",
+            Self::HydrologicalRiverFlood => "Hydrological/river flood warning",
+            Self::MarineCoastalFlood => "Marine/coastal flood warning",
+            Self::Other => "Other warning",
+            Self::HumanitarianActivities => "Humanitarian activities warning",
+            Self::SIGMET => "SIGMET",
+            Self::TropicalCycloneTyphoonHurricane => "Tropical cyclone (typhoon/hurricane) warning",
+            Self::SevereThunderstorm => "Severe thunderstorm warning",
+            Self::VolcanicAshCloudsSIGMET => "Volcanic ash clouds SIGMET",
+            Self::WarningsWeatherSummary => "Warnings and weather summary",
+        }
+    }
+}
+
+impl From<Warning> for UnparsedProductIdentifier {
+    fn from(value: Warning) -> Self {
+        let (a1, a2) = value.area.into();
+        Self {
+            t1: 'W',
+            t2: value.subtype.t2(),
+            a1,
+            a2,
+            ii: value.enumerator,
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use super::*;
+use super::{
+    area::{GeographicalAreaDesignator, GeographicalAreaDesignatorHemisphere},
+    *,
+};
 
 #[test]
 fn test_parse() {
@@ -12,3 +15,91 @@ fn test_parse() {
         })
     ));
 }
+
+#[test]
+fn test_round_trip() {
+    for code in [
+        "FTUS80", "SAUS70", "WWUS81", "LAUS41", "ASMX00", "NOUS41", "CSUS42", "UANT01", "FCUS80",
+        "HHXA50", "PYAA10", "OTIA00", "TBIA01", "ISMA01", "JSMT10", "KFAX01", "YHXC50", "EIUS41",
+        "BMBB91", "DHXS50", "GHXW50", "QGAE85", "XOAA11", "VXUS42", "JNAA00", "KXBD99", "OVDV99",
+    ] {
+        assert!(
+            code.parse::<DataTypeDesignator>().is_ok(),
+            "{code} should parse"
+        );
+    }
+
+    //Every designator that parses must print back exactly as received
+    let letters = || 'A'..='Z';
+    for t1 in letters() {
+        for t2 in letters() {
+            for a1 in letters() {
+                for a2 in letters() {
+                    for ii in [0u8, 1, 13, 50, 99] {
+                        let code = format!("{t1}{t2}{a1}{a2}{ii:02}");
+                        if let Ok(dt) = code.parse::<DataTypeDesignator>() {
+                            assert_eq!(dt.to_string(), code);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_area_time_display() {
+    let DataTypeDesignator::GridPointInformation(grid) = "YHXC50".parse().unwrap() else {
+        panic!("YHXC50 is grid point information");
+    };
+    assert_eq!(grid.t1, 'Y');
+    assert_eq!(grid.area.to_string(), "X");
+    assert_eq!(grid.area.description(), "Global area");
+    assert_eq!(grid.time.to_string(), "C");
+    assert_eq!(grid.time.hours(), 6);
+    assert_eq!(grid.time.description(), "6 hour forecast");
+
+    //Days 5, 7, and 10 are written in days
+    let time = area::ReferenceTimeDesignator::parse_for_dghjopt('R').unwrap();
+    assert_eq!(
+        (time.to_string(), time.description()),
+        ("R".to_owned(), "7 day forecast".to_owned())
+    );
+}
+
+#[test]
+fn test_description() {
+    let describe = |code: &str| code.parse::<DataTypeDesignator>().unwrap().description();
+
+    assert_eq!(
+        describe("FCUS80"),
+        "Aerodrome forecast (VT < 12h), United States"
+    );
+    //Areas not in the table fall back to the code
+    assert_eq!(
+        describe("SAZZ70"),
+        format!(
+            "{}, ZZ",
+            SurfaceSubType::AviationRoutineReport.description()
+        )
+    );
+    assert_eq!(
+        describe("HDAE50"),
+        "Thickness (relative topography) grid, 0-90W northern hemisphere, 24 hour forecast"
+    );
+}
+
+#[test]
+fn test_bufr_observational_area() {
+    //A2 gives the area of BUFR observational data, T2 gives the data type
+    let synop: DataTypeDesignator = "ISMA01".parse().unwrap();
+    let DataTypeDesignator::ObservationalDataBinaryBUFR(synop) = synop else {
+        panic!("ISMA01 is observational BUFR data");
+    };
+    assert_eq!(
+        synop.area,
+        GeographicalAreaDesignator::ZeroToNinetyWest(
+            GeographicalAreaDesignatorHemisphere::NorthernHemisphere
+        )
+    );
+}
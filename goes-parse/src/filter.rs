@@ -0,0 +1,361 @@
+//! Wildcard filters selecting products by WMO heading, AWIPS product identifier, and originating
+//! office, i.e. `FT*`, `W[AS]*`, `pil:AFD*`, or `SA?? cccc:KWBC`
+//!
+//! A filter is a whitespace separated list of terms that must all match. Each term is an optional
+//! `wmo:`, `pil:`, or `cccc:` field prefix followed by a pattern, where `?` matches any single
+//! character, `*` matches any run of characters, and `[...]` matches one of a set of characters or
+//! ranges such as `[A-F]`, negated with a leading `!`. Terms without a prefix match the
+//! `T1T2A1A2ii` data type designator.
+
+use std::{fmt, str::FromStr};
+
+use crate::header::{GoesEmwinFileName, WMOProductIdentifier, CCCC};
+
+/// A compiled product filter expression
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProductFilter {
+    source: String,
+    terms: Vec<Term>,
+}
+
+/// The part of a product identifier a term is matched against
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FilterField {
+    /// `wmo:` The `T1T2A1A2ii` data type designator
+    Wmo,
+    /// `pil:` The AWIPS product identifier
+    Pil,
+    /// `cccc:` The originating office
+    Cccc,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Term {
+    field: FilterField,
+    pattern: Vec<Token>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Literal(char),
+    Any,
+    Many,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ProductFilterParseError {
+    #[error("Product filter contains no terms")]
+    Empty,
+    #[error("Unknown product filter field {0}, expecting wmo, pil, or cccc")]
+    Field(String),
+    #[error("Empty pattern in product filter term {0}")]
+    Pattern(String),
+    #[error("Unclosed character class in product filter term {0}")]
+    UnclosedClass(String),
+    #[error("Invalid character class in product filter term {0}")]
+    InvalidClass(String),
+}
+
+impl ProductFilter {
+    /// Check if a product received as an EMWIN file matches this filter
+    pub fn matches_filename(&self, name: &GoesEmwinFileName) -> bool {
+        self.matches(
+            &name.wmo_product_id.to_string(),
+            &name.country,
+            name.pil.as_deref(),
+        )
+    }
+
+    /// Check if a product with the given WMO header and optional AWIPS product identifier matches
+    /// this filter. Terms matching the product identifier never match if `pil` is `None`
+    pub fn matches_header(&self, header: &WMOProductIdentifier, pil: Option<&str>) -> bool {
+        self.matches(&header.datatype.to_string(), &header.country, pil)
+    }
+
+    /// Match against the `T1T2A1A2ii` heading as it was received, which the data type designator
+    /// writes back term for term
+    fn matches(&self, wmo: &str, cccc: &CCCC, pil: Option<&str>) -> bool {
+        let cccc = cccc.to_string();
+        self.terms.iter().all(|term| {
+            let subject = match term.field {
+                FilterField::Wmo => wmo,
+                FilterField::Cccc => &cccc,
+                FilterField::Pil => match pil {
+                    Some(pil) => pil,
+                    None => return false,
+                },
+            };
+
+            glob(&term.pattern, &subject.chars().collect::<Vec<_>>())
+        })
+    }
+}
+
+/// Match a subject against a pattern, backtracking to the last `*` on a mismatch
+fn glob(pattern: &[Token], subject: &[char]) -> bool {
+    let (mut p, mut s) = (0, 0);
+    let mut backtrack = None;
+
+    while s < subject.len() {
+        match pattern.get(p) {
+            Some(Token::Many) => {
+                backtrack = Some((p, s));
+                p += 1;
+                continue;
+            }
+            Some(token) if token.matches(subject[s]) => {
+                p += 1;
+                s += 1;
+                continue;
+            }
+            _ => (),
+        }
+
+        match backtrack {
+            Some((star, from)) => {
+                p = star + 1;
+                s = from + 1;
+                backtrack = Some((star, from + 1));
+            }
+            None => return false,
+        }
+    }
+
+    pattern[p..].iter().all(|token| *token == Token::Many)
+}
+
+impl Token {
+    fn matches(&self, c: char) -> bool {
+        let c = c.to_ascii_uppercase();
+        match self {
+            Self::Literal(l) => *l == c,
+            Self::Any => true,
+            Self::Many => false,
+            Self::Class { negated, ranges } => {
+                ranges.iter().any(|(lo, hi)| (*lo..=*hi).contains(&c)) != *negated
+            }
+        }
+    }
+}
+
+impl Term {
+    fn parse(term: &str) -> Result<Self, ProductFilterParseError> {
+        let (field, pattern) = match term.split_once(':') {
+            Some((field, pattern)) => (
+                match field.to_ascii_lowercase().as_str() {
+                    "wmo" => FilterField::Wmo,
+                    "pil" => FilterField::Pil,
+                    "cccc" => FilterField::Cccc,
+                    _ => return Err(ProductFilterParseError::Field(field.to_owned())),
+                },
+                pattern,
+            ),
+            None => (FilterField::Wmo, term),
+        };
+
+        if pattern.is_empty() {
+            return Err(ProductFilterParseError::Pattern(term.to_owned()));
+        }
+
+        let mut chars = pattern.chars().map(|c| c.to_ascii_uppercase());
+        let mut tokens = Vec::new();
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '?' => Token::Any,
+                '*' => Token::Many,
+                '[' => {
+                    let mut class = Vec::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(c) => class.push(c),
+                            None => {
+                                return Err(ProductFilterParseError::UnclosedClass(term.to_owned()))
+                            }
+                        }
+                    }
+                    parse_class(&class)
+                        .ok_or_else(|| ProductFilterParseError::InvalidClass(term.to_owned()))?
+                }
+                other => Token::Literal(other),
+            });
+        }
+
+        Ok(Self {
+            field,
+            pattern: tokens,
+        })
+    }
+}
+
+/// Parse the contents of a `[...]` character class
+fn parse_class(class: &[char]) -> Option<Token> {
+    let (negated, class) = match class.first() {
+        Some('!') => (true, &class[1..]),
+        _ => (false, class),
+    };
+
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < class.len() {
+        match class.get(i + 1..i + 3) {
+            Some(['-', hi]) if class[i] <= *hi => {
+                ranges.push((class[i], *hi));
+                i += 3;
+            }
+            Some(['-', _]) => return None,
+            _ => {
+                ranges.push((class[i], class[i]));
+                i += 1;
+            }
+        }
+    }
+
+    (!ranges.is_empty()).then_some(Token::Class { negated, ranges })
+}
+
+impl FromStr for ProductFilter {
+    type Err = ProductFilterParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = s
+            .split_whitespace()
+            .map(Term::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if terms.is_empty() {
+            return Err(ProductFilterParseError::Empty);
+        }
+
+        Ok(Self {
+            source: s.trim().to_owned(),
+            terms,
+        })
+    }
+}
+
+impl fmt::Display for ProductFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl From<ProductFilter> for String {
+    fn from(filter: ProductFilter) -> Self {
+        filter.source
+    }
+}
+
+impl TryFrom<String> for ProductFilter {
+    type Error = ProductFilterParseError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filename: GoesEmwinFileName =
+            "A_FXUS65KABQ121804AAB_C_KWIN_20160112180901_008996-2-AFDABQNM.TXT"
+                .parse()
+                .unwrap();
+        let matches = |filter: &str| {
+            filter
+                .parse::<ProductFilter>()
+                .unwrap()
+                .matches_filename(&filename)
+        };
+
+        assert!(matches("FX*"));
+        assert!(matches("FXUS6?"));
+        assert!(matches("F[TX]*"));
+        assert!(matches("F[!T]US[0-9]5"));
+        assert!(matches("pil:AFD*"));
+        assert!(matches("fx* cccc:K???"));
+        assert!(!matches("FT*"));
+        assert!(!matches("FX"));
+        assert!(!matches("FX* cccc:KWBC"));
+        assert!(!matches("pil:AFD"));
+
+        let (_, header) = WMOProductIdentifier::parse("SAUS70 KWBC 121800\n").unwrap();
+        let filter = "SA??7* cccc:KWBC".parse::<ProductFilter>().unwrap();
+        assert!(filter.matches_header(&header, None));
+        let filter = "SA* pil:MTR*".parse::<ProductFilter>().unwrap();
+        assert!(filter.matches_header(&header, Some("MTRDEN")));
+        assert!(!filter.matches_header(&header, None));
+
+        //Y grids and enumerators are matched as received
+        let (_, grid) = WMOProductIdentifier::parse("YHXC50 KWBC 121800\n").unwrap();
+        let filter = |filter: &str| filter.parse::<ProductFilter>().unwrap();
+        assert!(filter("Y*").matches_header(&grid, None));
+        assert!(filter("YHX[A-C]50").matches_header(&grid, None));
+        assert!(!filter("H*").matches_header(&grid, None));
+
+        let (_, imagery) = WMOProductIdentifier::parse("EIUS41 KWBC 121800\n").unwrap();
+        assert!(filter("EIUS4?").matches_header(&imagery, None));
+        assert!(filter("E???41").matches_header(&imagery, None));
+        assert!(!filter("EIUS00").matches_header(&imagery, None));
+
+        let (_, service) = WMOProductIdentifier::parse("BMBB91 KWBC 121800\n").unwrap();
+        assert!(filter("BMBB9[0-5]").matches_header(&service, None));
+        assert!(!filter("*00").matches_header(&service, None));
+
+        //BUFR forecasts, CREX, and ocean currents keep their A1 and T2 terms
+        let (_, bufr) = WMOProductIdentifier::parse("JNAA00 KWBC 121800\n").unwrap();
+        assert!(filter("JNA*").matches_header(&bufr, None));
+        assert!(!filter("JNX*").matches_header(&bufr, None));
+
+        let (_, crex) = WMOProductIdentifier::parse("KXBD99 KWBC 121800\n").unwrap();
+        assert!(filter("KXB*").matches_header(&crex, None));
+
+        let (_, current) = WMOProductIdentifier::parse("OVDV99 KWBC 121800\n").unwrap();
+        assert!(filter("OV*").matches_header(&current, None));
+        assert!(!filter("OU*").matches_header(&current, None));
+
+        assert_eq!(
+            "".parse::<ProductFilter>(),
+            Err(ProductFilterParseError::Empty)
+        );
+        assert!(matches!(
+            "awips:AFD*".parse::<ProductFilter>(),
+            Err(ProductFilterParseError::Field(_))
+        ));
+        assert!(matches!(
+            "W[AS*".parse::<ProductFilter>(),
+            Err(ProductFilterParseError::UnclosedClass(_))
+        ));
+        assert!(matches!(
+            "W[Z-A]*".parse::<ProductFilter>(),
+            Err(ProductFilterParseError::InvalidClass(_))
+        ));
+        assert!(matches!(
+            "cccc:".parse::<ProductFilter>(),
+            Err(ProductFilterParseError::Pattern(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_filter() {
+        let filters: Vec<ProductFilter> =
+            serde_json::from_str(r#"["W[AS]*", "pil:AFD* cccc:KBOU"]"#).unwrap();
+        assert_eq!(filters[1].to_string(), "pil:AFD* cccc:KBOU");
+        assert_eq!(
+            serde_json::to_string(&filters).unwrap(),
+            r#"["W[AS]*","pil:AFD* cccc:KBOU"]"#
+        );
+        assert!(serde_json::from_str::<ProductFilter>(r#""W[AS""#).is_err());
+    }
+}
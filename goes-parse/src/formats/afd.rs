@@ -0,0 +1,131 @@
+//! Parsing for the named sections of Area Forecast Discussions
+
+use crate::{
+    formats::text_product::{dot_heading, TextProduct},
+    ParseResult,
+};
+
+/// An Area Forecast Discussion split into its sections
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct AreaForecastDiscussion {
+    pub product: TextProduct,
+    /// Identifier of the issuing forecast office, i.e. `BOU`
+    pub office: Option<String>,
+    pub sections: Vec<DiscussionSection>,
+}
+
+/// A section beginning with a heading like `.SHORT TERM /Today through Monday/...`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscussionSection {
+    /// Name of the section, i.e. `SYNOPSIS` or `SHORT TERM`
+    pub name: String,
+    /// Time covered by the section given between slashes after the name
+    pub period: Option<String>,
+    pub text: String,
+}
+
+impl AreaForecastDiscussion {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, product) = TextProduct::parse(input)?;
+        let office = product.office_id().map(str::to_owned);
+
+        let mut sections = vec![];
+        let mut section: Option<(DiscussionSection, Vec<&str>)> = None;
+
+        let lines = product.segments.iter().flat_map(|s| s.body.lines());
+        for line in lines {
+            let heading = dot_heading(line);
+            if heading.is_some() || line.starts_with("&&") {
+                sections.extend(section.take().map(DiscussionSection::finish));
+            }
+
+            if let Some((name, text)) = heading {
+                let (name, period) = match name.split_once(" /") {
+                    Some((name, period)) => (name, Some(period.trim_end_matches('/').to_owned())),
+                    None => (name, None),
+                };
+                section = Some((
+                    DiscussionSection {
+                        name: name.trim().to_owned(),
+                        period,
+                        text: String::new(),
+                    },
+                    vec![text],
+                ));
+            } else if let Some((_, text)) = section.as_mut() {
+                text.push(line);
+            }
+        }
+        sections.extend(section.map(DiscussionSection::finish));
+
+        Ok((
+            input,
+            Self {
+                product,
+                office,
+                sections,
+            },
+        ))
+    }
+
+    /// Get the first section with the given name
+    pub fn section(&self, name: &str) -> Option<&DiscussionSection> {
+        self.sections.iter().find(|s| s.name == name)
+    }
+}
+
+impl DiscussionSection {
+    fn finish((section, text): (Self, Vec<&str>)) -> Self {
+        Self {
+            text: text.join("\n").trim().to_owned(),
+            ..section
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_afd() {
+        let afd = AreaForecastDiscussion::parse(EX_AFD)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(afd.office.as_deref(), Some("BOU"));
+        assert_eq!(
+            afd.sections
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "KEY MESSAGES",
+                "SHORT TERM",
+                "LONG TERM",
+                "AVIATION",
+                "BOU WATCHES/WARNINGS/ADVISORIES"
+            ]
+        );
+
+        let short = afd.section("SHORT TERM").unwrap();
+        assert_eq!(short.period.as_deref(), Some("Today through Monday"));
+        assert!(short.text.starts_with("Issued at 330 AM MDT"));
+        assert!(short.text.ends_with("snow above 9000 feet."));
+
+        let aviation = afd.section("AVIATION").unwrap();
+        assert_eq!(
+            aviation.period.as_deref(),
+            Some("12Z TAFS THROUGH 12Z MONDAY")
+        );
+        assert_eq!(afd.sections[4].period, None);
+        assert_eq!(
+            afd.sections[4].text,
+            "High Wind Warning from noon today to 6 PM MDT this evening for COZ033-034."
+        );
+    }
+
+    const EX_AFD: &str = include_str!("test/afd.txt");
+}
@@ -33,13 +33,15 @@ use crate::{
 use super::{parse_degreesminutes, LatitudeDir, LongitudeDir};
 
 /// A single AMDAR report parsed from FM 42 data
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub struct AmdarReport {
     pub header: WMOProductIdentifier,
     pub items: Vec<AmdarReportItem>,
 }
 
-#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
 pub struct AmdarReportItem {
     pub phase: FlightPhase,
     pub aircraft_identifier: String,
@@ -59,7 +61,8 @@ pub struct AmdarReportItem {
     pub precision: Option<TemperaturePrecision>,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TransmissionSystem {
     ASDAR,
     /// false if ACARS not operative
@@ -69,7 +72,8 @@ pub enum TransmissionSystem {
     ACARSWithASDAR(bool),
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TemperaturePrecision {
     /// +/- 2.0 C
     Low,
@@ -77,13 +81,15 @@ pub enum TemperaturePrecision {
     High,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NavigationSystem {
     Intertial,
     OMEGA,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Turbulence {
     None,
     Light,
@@ -91,7 +97,8 @@ pub enum Turbulence {
     Severe,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum HumidityOrDewPoint {
     /// Ranging from [0., 1.] for relative humidity
     RelativeHumidity(f32),
@@ -99,12 +106,14 @@ pub enum HumidityOrDewPoint {
     DewPoint(ThermodynamicTemperature),
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AircraftReportType {
     Routine,
     MaxWind,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FlightPhase {
     /// ///
@@ -0,0 +1,198 @@
+//! Parsing for FM 18 BUOY reports of observations from fixed and drifting buoys
+
+use nom::{character::complete::multispace1, sequence::terminated};
+use uom::si::{
+    angle::degree,
+    f32::{Angle, Length, Time},
+    length::meter,
+    time::second,
+};
+
+use crate::{
+    formats::{
+        code_field,
+        ship::{quadrant, wind_group, MarineObservation},
+    },
+    header::WMOProductIdentifier,
+    parse::time::DayHourMinute,
+    ParseResult,
+};
+
+/// A bulletin of BUOY reports
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct BuoyReport {
+    pub header: WMOProductIdentifier,
+    pub items: Vec<BuoyReportItem>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuoyReportItem {
+    /// WMO identifier of the buoy A1bwnbnbnb
+    pub identifier: String,
+    pub month: u8,
+    pub time: DayHourMinute,
+    pub lat: Angle,
+    pub lon: Angle,
+    pub observation: MarineObservation,
+}
+
+impl BuoyReport {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let items = input
+            .split('=')
+            .filter_map(|report| {
+                let mut groups = report.split_whitespace().skip_while(|g| *g == "ZZYY");
+                BuoyReportItem::parse(&mut groups)
+            })
+            .collect();
+
+        Ok(("", Self { header, items }))
+    }
+}
+
+impl BuoyReportItem {
+    /// Parse the groups of a single report, returning `None` if the report does not contain a
+    /// valid identification and position
+    fn parse<'a>(groups: &mut impl Iterator<Item = &'a str>) -> Option<Self> {
+        let identifier = groups.next().filter(|id| id.len() == 5)?.to_owned();
+        let date = groups.next()?;
+        let time = groups.next()?;
+        let units = time.get(4..5);
+        let month = code_field(date, 2..4)?;
+        let time = DayHourMinute {
+            day: code_field(date, 0..2)?,
+            hour: code_field(time, 0..2)?,
+            minute: code_field(time, 2..4)?,
+        };
+
+        let lat = groups.next()?;
+        let lon = groups.next()?;
+        let (lat_dir, lon_dir) = quadrant(lat.get(..1)?)?;
+        let lat = Angle::new::<degree>(lat_dir.to_north(code_field::<f32>(lat, 1..6)? / 1000f32));
+        let lon = Angle::new::<degree>(lon_dir.to_east(code_field::<f32>(lon, 0..6)? / 1000f32));
+
+        let mut observation = MarineObservation::default();
+        let mut section = 0;
+        while let Some(group) = groups.next() {
+            match group {
+                g if g.len() == 5 && g.starts_with("111") && section < 1 => {
+                    section = 1;
+                    //The wind group immediately follows the section header
+                    if let Some(wind) = groups.next() {
+                        observation.wind = wind_group(wind, 1, units, groups);
+                    }
+                    continue;
+                }
+                g if g.len() == 5 && g.starts_with("222") && section < 2 => {
+                    section = 2;
+                    continue;
+                }
+                g if g.len() == 5 && g.starts_with("333") => break,
+                _ => (),
+            }
+
+            match section {
+                1 => observation.set_section1(group),
+                2 => match group.get(..2) {
+                    Some("20") => {
+                        if let Some(period) = code_field::<f32>(group, 2..5) {
+                            observation
+                                .waves
+                                .get_or_insert_with(Default::default)
+                                .period = Some(Time::new::<second>(period / 10f32));
+                        }
+                    }
+                    Some("21") => {
+                        if let Some(height) = code_field::<f32>(group, 2..5) {
+                            observation
+                                .waves
+                                .get_or_insert_with(Default::default)
+                                .height = Some(Length::new::<meter>(height / 10f32));
+                        }
+                    }
+                    _ => observation.set_section2(group),
+                },
+                _ => (),
+            }
+        }
+
+        Some(Self {
+            identifier,
+            month,
+            time,
+            lat,
+            lon,
+            observation,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use uom::si::{
+        f32::{Pressure, ThermodynamicTemperature, Velocity},
+        pressure::hectopascal,
+        thermodynamic_temperature::degree_celsius,
+        velocity::knot,
+    };
+
+    use super::*;
+
+    #[test]
+    pub fn test_buoy() {
+        let buoy = BuoyReport::parse(EX_BUOY)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(buoy.items.len(), 2);
+
+        let first = &buoy.items[0];
+        assert_eq!(first.identifier, "41001");
+        assert_eq!(first.month, 10);
+        assert_eq!(
+            first.time,
+            DayHourMinute {
+                day: 18,
+                hour: 12,
+                minute: 0
+            }
+        );
+        assert_eq!(first.lat, Angle::new::<degree>(34.689));
+        assert_eq!(first.lon, Angle::new::<degree>(-72.658));
+
+        let obs = &first.observation;
+        let wind = obs.wind.unwrap();
+        assert_eq!(wind.direction, Angle::new::<degree>(220f32));
+        assert_eq!(wind.speed, Velocity::new::<knot>(12f32));
+        assert_eq!(
+            obs.air_temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(17.4))
+        );
+        assert_eq!(
+            obs.sea_level_pressure,
+            Some(Pressure::new::<hectopascal>(1014.2))
+        );
+        assert_eq!(
+            obs.sea_temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(19.1))
+        );
+        let waves = obs.waves.unwrap();
+        assert_eq!(waves.period, Some(Time::new::<second>(8f32)));
+        assert_eq!(waves.height, Some(Length::new::<meter>(1.5)));
+
+        let last = &buoy.items[1];
+        assert_eq!(last.time.minute, 50);
+        assert_eq!(last.lon, Angle::new::<degree>(-122.398));
+        assert_eq!(last.observation.wind, None);
+        assert_eq!(
+            last.observation.pressure_change,
+            Some(Pressure::new::<hectopascal>(-0.3))
+        );
+    }
+
+    const EX_BUOY: &str = include_str!("test/buoy.txt");
+}
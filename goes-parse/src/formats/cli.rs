@@ -0,0 +1,687 @@
+//! Parsing for NWS daily (CLI) and monthly (CLM) climate reports
+
+use chrono::{Datelike, NaiveDate};
+use uom::si::{
+    angle::degree,
+    f32::{Angle, Velocity},
+    velocity::mile_per_hour,
+};
+
+use crate::{
+    formats::compass16, header::WMOProductIdentifier, parse::time::HourMinute, ParseResult,
+};
+
+use nom::{character::complete::multispace1, sequence::terminated};
+
+/// A climate summary for a single station over one day or month
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ClimateReport {
+    pub header: WMOProductIdentifier,
+    /// Name of the station such as `DENVER CO`
+    pub station: String,
+    pub period: ClimatePeriod,
+    /// Temperatures in degrees Fahrenheit
+    pub temperature: ClimateSection,
+    /// Precipitation in inches
+    pub precipitation: ClimateSection,
+    /// Snowfall and snow depth in inches
+    pub snowfall: ClimateSection,
+    pub heating_degree_days: ClimateSection,
+    pub cooling_degree_days: ClimateSection,
+    pub wind: ClimateWind,
+    pub sky: ClimateSky,
+    /// Relative humidity in percent
+    pub humidity: ClimateSection,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClimatePeriod {
+    Daily(NaiveDate),
+    Monthly { year: i32, month: u32 },
+}
+
+/// Rows of one table section of the report such as `TEMPERATURE (F)`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClimateSection {
+    pub rows: Vec<ClimateRow>,
+}
+
+/// A single row of a climate table, with values in the units of the section it belongs to
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClimateRow {
+    /// Label of the row such as `MAXIMUM` or `MONTH TO DATE`
+    pub label: String,
+    pub observed: Option<ClimateValue>,
+    /// Local standard time that the observed value occurred at
+    pub time: Option<HourMinute>,
+    /// Dates that the observed value occurred on in monthly reports
+    pub dates: Vec<NaiveDate>,
+    pub record: Option<ClimateValue>,
+    pub record_year: Option<u16>,
+    pub normal: Option<ClimateValue>,
+    pub departure: Option<ClimateValue>,
+    pub last_year: Option<ClimateValue>,
+}
+
+/// A reported value, where missing values (`MM`) are represented as `None`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClimateValue {
+    Trace,
+    Value(f32),
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClimateWind {
+    pub highest_speed: Option<Velocity>,
+    pub highest_direction: Option<Angle>,
+    pub highest_gust: Option<Velocity>,
+    pub gust_direction: Option<Angle>,
+    pub average_speed: Option<Velocity>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClimateSky {
+    /// Percent of possible sunshine, ranging from [0., 1.]
+    pub possible_sunshine: Option<f32>,
+    /// Average sky cover, ranging from [0., 1.]
+    pub average_sky_cover: Option<f32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Section {
+    Temperature,
+    Precipitation,
+    Snowfall,
+    DegreeDays,
+    HeatingDegreeDays,
+    CoolingDegreeDays,
+    Wind,
+    Sky,
+    Humidity,
+}
+
+impl ClimateReport {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut summary = None;
+        let mut section = None;
+        let mut prefix = None;
+        let mut report = ClimateSections::default();
+
+        for line in input.lines().map(str::trim_end) {
+            let trimmed = line.trim();
+            if line.starts_with("$$")
+                || trimmed.contains("CLIMATE NORMALS FOR")
+                || trimmed.starts_with("SUNRISE AND SUNSET")
+            {
+                break;
+            }
+
+            let period = match &summary {
+                Some((_, period)) => *period,
+                None => {
+                    summary = parse_summary(trimmed);
+                    continue;
+                }
+            };
+
+            if trimmed.is_empty() || trimmed.starts_with("....") {
+                continue;
+            }
+
+            if !line.starts_with(' ') {
+                prefix = None;
+                let heading = Some(match trimmed {
+                    h if h.starts_with("TEMPERATURE") => Section::Temperature,
+                    h if h.starts_with("PRECIPITATION") => Section::Precipitation,
+                    h if h.starts_with("SNOWFALL") => Section::Snowfall,
+                    h if h.starts_with("DEGREE DAYS") => Section::DegreeDays,
+                    h if h.starts_with("WIND") => Section::Wind,
+                    h if h.starts_with("SKY COVER") => Section::Sky,
+                    h if h.starts_with("RELATIVE HUMIDITY") => Section::Humidity,
+                    "RECORD" => {
+                        prefix = Some("RECORD");
+                        continue;
+                    }
+                    _ => {
+                        //Unindented rows are used in monthly reports
+                        if section.is_some() {
+                            if let Some(row) = ClimateRow::parse(trimmed, None, period) {
+                                report.push(section, row);
+                                continue;
+                            }
+                        }
+                        section = None;
+                        continue;
+                    }
+                });
+                section = heading;
+                continue;
+            }
+
+            match (section, trimmed) {
+                (Some(Section::DegreeDays | Section::HeatingDegreeDays), "HEATING") => {
+                    section = Some(Section::HeatingDegreeDays)
+                }
+                (
+                    Some(
+                        Section::DegreeDays
+                        | Section::HeatingDegreeDays
+                        | Section::CoolingDegreeDays,
+                    ),
+                    "COOLING",
+                ) => section = Some(Section::CoolingDegreeDays),
+                (Some(Section::Wind), line) => report.wind.parse_line(line),
+                (Some(Section::Sky), line) => report.sky.parse_line(line),
+                (Some(_), line) => {
+                    if let Some(row) = ClimateRow::parse(line, prefix, period) {
+                        report.push(section, row);
+                    }
+                }
+                (None, _) => (),
+            }
+        }
+
+        let (station, period) = summary.ok_or_else(|| {
+            nom::Err::Error(nom::error::ParseError::from_error_kind(
+                input,
+                nom::error::ErrorKind::Verify,
+            ))
+        })?;
+
+        Ok((
+            "",
+            Self {
+                header,
+                station,
+                period,
+                temperature: report.temperature,
+                precipitation: report.precipitation,
+                snowfall: report.snowfall,
+                heating_degree_days: report.heating_degree_days,
+                cooling_degree_days: report.cooling_degree_days,
+                wind: report.wind,
+                sky: report.sky,
+                humidity: report.humidity,
+            },
+        ))
+    }
+}
+
+/// Sections of the report collected while parsing
+#[derive(Default)]
+struct ClimateSections {
+    temperature: ClimateSection,
+    precipitation: ClimateSection,
+    snowfall: ClimateSection,
+    heating_degree_days: ClimateSection,
+    cooling_degree_days: ClimateSection,
+    wind: ClimateWind,
+    sky: ClimateSky,
+    humidity: ClimateSection,
+}
+
+impl ClimateSections {
+    fn push(&mut self, section: Option<Section>, row: ClimateRow) {
+        let section = match section {
+            Some(Section::Temperature) => &mut self.temperature,
+            Some(Section::Precipitation) => &mut self.precipitation,
+            Some(Section::Snowfall) => &mut self.snowfall,
+            Some(Section::HeatingDegreeDays) => &mut self.heating_degree_days,
+            Some(Section::CoolingDegreeDays) => &mut self.cooling_degree_days,
+            Some(Section::Humidity) => &mut self.humidity,
+            _ => return,
+        };
+
+        section.rows.push(row);
+    }
+}
+
+/// Parse the summary line like `...THE DENVER CO CLIMATE SUMMARY FOR OCTOBER 18 2026...`
+fn parse_summary(line: &str) -> Option<(String, ClimatePeriod)> {
+    let line = line.trim_matches('.');
+    let (station, period) = line.split_once(" CLIMATE SUMMARY FOR ")?;
+    let station = station.trim_start_matches("THE ").trim().to_owned();
+
+    let period = match period.strip_prefix("THE MONTH OF ") {
+        Some(month) => {
+            let date =
+                NaiveDate::parse_from_str(&format!("1 {}", month.trim()), "%d %B %Y").ok()?;
+            ClimatePeriod::Monthly {
+                year: date.year(),
+                month: date.month(),
+            }
+        }
+        None => ClimatePeriod::Daily(NaiveDate::parse_from_str(period.trim(), "%B %d %Y").ok()?),
+    };
+
+    Some((station, period))
+}
+
+impl ClimatePeriod {
+    /// Resolve a month and day to a date in the year of the report, or the year before if the
+    /// month comes after the report period such as a December date in a January report
+    fn resolve(&self, month: u32, day: u32) -> Option<NaiveDate> {
+        let (year, current) = match *self {
+            Self::Daily(date) => (date.year(), date.month()),
+            Self::Monthly { year, month } => (year, month),
+        };
+
+        let year = match month > current {
+            true => year - 1,
+            false => year,
+        };
+
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+}
+
+impl ClimateSection {
+    /// Get the first row with the given label
+    pub fn row(&self, label: &str) -> Option<&ClimateRow> {
+        self.rows.iter().find(|row| row.label == label)
+    }
+
+    /// Get the row for the reported day in daily reports, or the month total in monthly reports
+    pub fn period(&self) -> Option<&ClimateRow> {
+        self.rows.iter().find(|row| {
+            matches!(
+                row.label.as_str(),
+                "YESTERDAY" | "TODAY" | "TOTALS" | "TOTAL"
+            )
+        })
+    }
+
+    pub fn month_to_date(&self) -> Option<&ClimateRow> {
+        self.row("MONTH TO DATE")
+    }
+
+    /// Get the total since the start of the season, such as `SINCE JUL 1` for snowfall
+    pub fn season_to_date(&self) -> Option<&ClimateRow> {
+        self.rows
+            .iter()
+            .find(|row| row.label.starts_with("SINCE ") && row.label != "SINCE JAN 1")
+    }
+
+    pub fn year_to_date(&self) -> Option<&ClimateRow> {
+        self.row("SINCE JAN 1")
+    }
+
+    pub fn maximum(&self) -> Option<&ClimateRow> {
+        self.rows
+            .iter()
+            .find(|row| matches!(row.label.as_str(), "MAXIMUM" | "HIGHEST"))
+    }
+
+    pub fn minimum(&self) -> Option<&ClimateRow> {
+        self.rows
+            .iter()
+            .find(|row| matches!(row.label.as_str(), "MINIMUM" | "LOWEST"))
+    }
+
+    pub fn average(&self) -> Option<&ClimateRow> {
+        self.rows
+            .iter()
+            .find(|row| matches!(row.label.as_str(), "AVERAGE" | "MEAN"))
+    }
+}
+
+/// A single whitespace separated token of a table row
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token<'a> {
+    Value(Option<ClimateValue>),
+    Year(u16),
+    Time(HourMinute),
+    /// Month and day without a year
+    Date(u32, u32),
+    Word(&'a str),
+}
+
+impl ClimateRow {
+    /// Parse a row of a climate table, returning `None` if the row contains no values. Dates in
+    /// the row are resolved to the most recent year at or before the report period
+    fn parse(line: &str, prefix: Option<&str>, period: ClimatePeriod) -> Option<Self> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+
+        //Comparisons and dates following these words are a part of the label
+        let mut len = 0;
+        while let Some(word) = words.get(len) {
+            len += match *word {
+                ">=" | "<=" | ">" | "<" => 2,
+                "SINCE" => 3,
+                word if matches!(Token::parse(word), Token::Word(_)) => 1,
+                _ => break,
+            };
+        }
+
+        let len = len.min(words.len());
+        let label = words[..len].join(" ");
+
+        let mut tokens = vec![];
+        let mut idx = len;
+        while idx < words.len() {
+            let word = words[idx];
+            let token = match words.get(idx + 1) {
+                Some(&("AM" | "PM")) => {
+                    idx += 1;
+                    parse_time(word, words[idx]).map(Token::Time)
+                }
+                _ => None,
+            };
+
+            tokens.push(token.unwrap_or_else(|| Token::parse(word)));
+            idx += 1;
+        }
+
+        let label = match prefix {
+            Some(prefix) => format!("{} {}", prefix, label),
+            None => label,
+        };
+
+        let mut row = ClimateRow {
+            label,
+            ..Default::default()
+        };
+
+        let mut values = tokens.as_slice();
+
+        match values {
+            [Token::Value(record), Token::Year(year), rest @ ..] => {
+                row.record = *record;
+                row.record_year = Some(*year);
+                values = rest;
+            }
+            [Token::Value(observed), rest @ ..] => {
+                row.observed = *observed;
+                values = rest;
+            }
+            _ => return None,
+        }
+
+        while let [Token::Time(_) | Token::Date(..), rest @ ..] = values {
+            match values[0] {
+                Token::Time(time) => row.time = Some(time),
+                Token::Date(month, day) => row.dates.extend(period.resolve(month, day)),
+                _ => (),
+            }
+            values = rest;
+        }
+
+        if let [Token::Value(record), Token::Year(year), rest @ ..] = values {
+            row.record = *record;
+            row.record_year = Some(*year);
+            values = rest;
+        }
+
+        let mut rest = values
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, token)| match token {
+                Token::Value(v) => Some((*v, matches!(values.get(idx + 1), Some(Token::Date(..))))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        //A value given with dates is the value from last year
+        if let Some(pos) = rest.iter().position(|(_, dated)| *dated) {
+            row.last_year = rest.remove(pos).0;
+            rest.truncate(pos);
+        }
+
+        let mut rest = rest.into_iter().map(|(v, _)| v);
+        row.normal = rest.next().flatten();
+        row.departure = rest.next().flatten();
+        if let Some(last_year) = rest.next() {
+            row.last_year = last_year;
+        }
+
+        Some(row)
+    }
+}
+
+impl<'a> Token<'a> {
+    fn parse(word: &'a str) -> Self {
+        match word {
+            "MM" => return Self::Value(None),
+            "T" => return Self::Value(Some(ClimateValue::Trace)),
+            _ => (),
+        }
+
+        if let Some((month, day)) = word.split_once('/') {
+            if let (Ok(month), Ok(day)) = (month.parse::<u32>(), day.parse::<u32>()) {
+                return Self::Date(month, day);
+            }
+        }
+
+        //Values may be suffixed with R to indicate a record set or tied
+        let number = word.trim_end_matches('R');
+        if number.len() == 4 && !number.contains(['.', '-']) {
+            if let Ok(year @ 1800..=2200) = number.parse::<u16>() {
+                return Self::Year(year);
+            }
+        }
+
+        match number.parse::<f32>() {
+            Ok(v) => Self::Value(Some(ClimateValue::Value(v))),
+            Err(_) => Self::Word(word),
+        }
+    }
+}
+
+/// Parse a local time like `254 PM`
+fn parse_time(time: &str, meridiem: &str) -> Option<HourMinute> {
+    let time = time.parse::<u16>().ok()?;
+    let (hour, minute) = ((time / 100) as u8 % 12, (time % 100) as u8);
+    let hour = match meridiem {
+        "PM" => hour + 12,
+        _ => hour,
+    };
+
+    Some(HourMinute { hour, minute })
+}
+
+impl ClimateWind {
+    fn parse_line(&mut self, line: &str) {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        for (idx, window) in words.windows(3).enumerate() {
+            let value = words.get(idx + 3).copied();
+            let speed = || {
+                value?
+                    .parse::<f32>()
+                    .ok()
+                    .map(Velocity::new::<mile_per_hour>)
+            };
+            let direction = || {
+                //Directions are given as compass points followed by degrees in parentheses
+                words
+                    .get(idx + 4)
+                    .and_then(|deg| {
+                        deg.trim_matches(|c| c == '(' || c == ')')
+                            .parse::<f32>()
+                            .ok()
+                    })
+                    .map(Angle::new::<degree>)
+                    .or_else(|| compass16(value?).ok())
+            };
+
+            match window {
+                ["HIGHEST", "WIND", "SPEED"] => self.highest_speed = speed(),
+                ["HIGHEST", "WIND", "DIRECTION"] => self.highest_direction = direction(),
+                ["HIGHEST", "GUST", "SPEED"] => self.highest_gust = speed(),
+                ["HIGHEST", "GUST", "DIRECTION"] => self.gust_direction = direction(),
+                ["AVERAGE", "WIND", "SPEED"] => self.average_speed = speed(),
+                _ => (),
+            }
+        }
+    }
+}
+
+impl ClimateSky {
+    fn parse_line(&mut self, line: &str) {
+        let value = line
+            .split_whitespace()
+            .last()
+            .and_then(|v| v.trim_end_matches('%').parse::<f32>().ok());
+
+        if line.starts_with("POSSIBLE SUNSHINE") {
+            self.possible_sunshine = value.map(|v| v / 100f32);
+        } else if line.starts_with("AVERAGE SKY COVER") {
+            //Sky cover is reported in tenths or as a fraction
+            self.average_sky_cover = value.map(|v| if v > 1f32 { v / 10f32 } else { v });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_cli() {
+        let cli = ClimateReport::parse(EX_CLI)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(cli.station, "DENVER CO");
+        assert_eq!(
+            cli.period,
+            ClimatePeriod::Daily(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap())
+        );
+
+        let max = cli.temperature.maximum().unwrap();
+        assert_eq!(max.observed, Some(ClimateValue::Value(68f32)));
+        assert_eq!(
+            max.time,
+            Some(HourMinute {
+                hour: 14,
+                minute: 54
+            })
+        );
+        assert_eq!(max.record, Some(ClimateValue::Value(84f32)));
+        assert_eq!(max.record_year, Some(1950));
+        assert_eq!(max.normal, Some(ClimateValue::Value(64f32)));
+        assert_eq!(max.departure, Some(ClimateValue::Value(4f32)));
+        assert_eq!(max.last_year, Some(ClimateValue::Value(59f32)));
+
+        let avg = cli.temperature.average().unwrap();
+        assert_eq!(avg.record, None);
+        assert_eq!(avg.normal, Some(ClimateValue::Value(50f32)));
+
+        let precip = &cli.precipitation;
+        assert_eq!(precip.rows.len(), 4);
+        assert_eq!(precip.period().unwrap().record_year, Some(1969));
+        assert_eq!(
+            precip.season_to_date().unwrap().departure,
+            Some(ClimateValue::Value(-0.6))
+        );
+        assert_eq!(
+            precip.year_to_date().unwrap().observed,
+            Some(ClimateValue::Value(12.34))
+        );
+
+        let snow = &cli.snowfall;
+        assert_eq!(
+            snow.month_to_date().unwrap().observed,
+            Some(ClimateValue::Trace)
+        );
+        assert_eq!(snow.season_to_date().unwrap().last_year, None);
+        assert_eq!(
+            snow.row("SNOW DEPTH").unwrap().observed,
+            Some(ClimateValue::Value(0f32))
+        );
+
+        assert_eq!(cli.heating_degree_days.rows.len(), 3);
+        assert_eq!(
+            cli.cooling_degree_days.year_to_date().unwrap().observed,
+            Some(ClimateValue::Value(790f32))
+        );
+
+        assert_eq!(
+            cli.wind.highest_gust,
+            Some(Velocity::new::<mile_per_hour>(31f32))
+        );
+        assert_eq!(cli.wind.gust_direction, Some(Angle::new::<degree>(320f32)));
+        assert_eq!(
+            cli.wind.average_speed,
+            Some(Velocity::new::<mile_per_hour>(8.4))
+        );
+        assert_eq!(cli.sky.possible_sunshine, None);
+        assert_eq!(cli.sky.average_sky_cover, Some(0.3));
+
+        let humidity = cli.humidity.minimum().unwrap();
+        assert_eq!(humidity.observed, Some(ClimateValue::Value(15f32)));
+        assert_eq!(
+            humidity.time,
+            Some(HourMinute {
+                hour: 15,
+                minute: 0
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_clm() {
+        let clm = ClimateReport::parse(EX_CLM)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(
+            clm.period,
+            ClimatePeriod::Monthly {
+                year: 2026,
+                month: 9
+            }
+        );
+
+        let record = clm.temperature.row("RECORD HIGH").unwrap();
+        assert_eq!(record.record, Some(ClimateValue::Value(105f32)));
+        assert_eq!(record.record_year, Some(1878));
+
+        let highest = clm.temperature.maximum().unwrap();
+        assert_eq!(highest.observed, Some(ClimateValue::Value(92f32)));
+        assert_eq!(
+            highest.dates,
+            [NaiveDate::from_ymd_opt(2026, 9, 2).unwrap()]
+        );
+        assert_eq!(highest.normal, None);
+        assert_eq!(highest.last_year, Some(ClimateValue::Value(91f32)));
+
+        let period = ClimatePeriod::Monthly {
+            year: 2027,
+            month: 1,
+        };
+        assert_eq!(
+            ClimateRow::parse("HIGHEST 61 12/31 01/02", None, period)
+                .unwrap()
+                .dates,
+            [
+                NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+                NaiveDate::from_ymd_opt(2027, 1, 2).unwrap()
+            ]
+        );
+
+        let days = clm.temperature.row("DAYS MAX >= 90").unwrap();
+        assert_eq!(days.observed, Some(ClimateValue::Value(3f32)));
+        assert_eq!(days.normal, Some(ClimateValue::Value(3.1)));
+
+        assert_eq!(
+            clm.precipitation.row("RECORD MINIMUM").unwrap().record,
+            Some(ClimateValue::Trace)
+        );
+        assert_eq!(
+            clm.precipitation.period().unwrap().departure,
+            Some(ClimateValue::Value(-0.46))
+        );
+    }
+
+    const EX_CLI: &str = include_str!("test/cli.txt");
+    const EX_CLM: &str = include_str!("test/clm.txt");
+}
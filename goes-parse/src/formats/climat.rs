@@ -0,0 +1,537 @@
+//! Parsing for FM 71 CLIMAT monthly climatological reports from land stations
+
+use nom::{
+    character::complete::{multispace1, space1},
+    error::context,
+    sequence::{preceded, terminated, tuple},
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{
+    f32::{Length, Pressure, TemperatureInterval, ThermodynamicTemperature, Time, Velocity},
+    length::millimeter,
+    pressure::hectopascal,
+    temperature_interval,
+    thermodynamic_temperature::degree_celsius,
+    time::hour,
+    velocity::{knot, meter_per_second},
+};
+
+use crate::{
+    formats::{code_field, code_pressure, code_signed_tenths},
+    header::WMOProductIdentifier,
+    parse::fromstr_n,
+    ParseResult,
+};
+
+use super::codes::precipitation::PrecipitationAmount;
+
+/// Precipitation total used to indicate a trace in sections 1 and 2
+const TRACE: u16 = 8899;
+
+/// A CLIMAT bulletin containing reports for a single month from one or more stations
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ClimatReport {
+    pub header: WMOProductIdentifier,
+    pub month: u8,
+    pub year: u16,
+    /// Reports for each station, excluding stations reported as `NIL`
+    pub items: Vec<ClimatReportItem>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClimatReportItem {
+    /// WMO station index number IIiii
+    pub station: String,
+    /// Section 1 monthly means and totals
+    pub monthly: Option<ClimatValues>,
+    /// Section 2 monthly normals
+    pub normals: Option<ClimatNormals>,
+    /// Section 3 counts of days exceeding thresholds
+    pub days: Option<ClimatDays>,
+    /// Section 4 extreme values of the month
+    pub extremes: Option<ClimatExtremes>,
+}
+
+/// Mean and total values for a month, as given in sections 1 and 2
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClimatValues {
+    pub station_pressure: Option<Pressure>,
+    /// Mean pressure reduced to sea level
+    pub sea_level_pressure: Option<Pressure>,
+    pub temperature: Option<ThermodynamicTemperature>,
+    /// Standard deviation of daily mean temperatures
+    pub temperature_deviation: Option<TemperatureInterval>,
+    /// Mean of the daily maximum temperatures
+    pub max_temperature: Option<ThermodynamicTemperature>,
+    /// Mean of the daily minimum temperatures
+    pub min_temperature: Option<ThermodynamicTemperature>,
+    pub vapour_pressure: Option<Pressure>,
+    pub precipitation: Option<PrecipitationAmount>,
+    /// Quintile of the precipitation total in the distribution of the reference period, where
+    /// 0 and 6 are below and above all reference values
+    pub precipitation_quintile: Option<u8>,
+    /// Number of days with precipitation of 1 mm or more
+    pub precipitation_days: Option<u8>,
+    /// Total duration of sunshine
+    pub sunshine: Option<Time>,
+    /// Total duration of sunshine as a fraction of the normal, where 1. is normal
+    pub sunshine_percent: Option<f32>,
+    pub missing: ClimatMissing,
+}
+
+/// Number of days missing from the records in section 1, or of years missing from the normals in
+/// section 2
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClimatMissing {
+    pub pressure: Option<u8>,
+    pub temperature: Option<u8>,
+    pub max_temperature: Option<u8>,
+    pub min_temperature: Option<u8>,
+    pub vapour_pressure: Option<u8>,
+    pub precipitation: Option<u8>,
+    pub sunshine: Option<u8>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClimatNormals {
+    /// First year of the reference period
+    pub start_year: Option<u16>,
+    /// Last year of the reference period
+    pub end_year: Option<u16>,
+    pub values: ClimatValues,
+}
+
+/// Number of days in the month with values beyond a threshold
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClimatDays {
+    /// Maximum temperature >= 25 C
+    pub max_temperature_25: Option<u8>,
+    /// Maximum temperature >= 30 C
+    pub max_temperature_30: Option<u8>,
+    /// Maximum temperature >= 35 C
+    pub max_temperature_35: Option<u8>,
+    /// Maximum temperature >= 40 C
+    pub max_temperature_40: Option<u8>,
+    /// Minimum temperature < 0 C
+    pub min_temperature_below_0: Option<u8>,
+    /// Maximum temperature < 0 C
+    pub max_temperature_below_0: Option<u8>,
+    /// Precipitation >= 1 mm
+    pub precipitation_1: Option<u8>,
+    /// Precipitation >= 5 mm
+    pub precipitation_5: Option<u8>,
+    /// Precipitation >= 10 mm
+    pub precipitation_10: Option<u8>,
+    /// Precipitation >= 50 mm
+    pub precipitation_50: Option<u8>,
+    /// Precipitation >= 100 mm
+    pub precipitation_100: Option<u8>,
+    /// Precipitation >= 150 mm
+    pub precipitation_150: Option<u8>,
+    /// Snow depth > 0 cm
+    pub snow_depth_0: Option<u8>,
+    /// Snow depth > 1 cm
+    pub snow_depth_1: Option<u8>,
+    /// Snow depth > 10 cm
+    pub snow_depth_10: Option<u8>,
+    /// Snow depth > 50 cm
+    pub snow_depth_50: Option<u8>,
+    /// Wind speed >= 10 m/s
+    pub wind_10: Option<u8>,
+    /// Wind speed >= 20 m/s
+    pub wind_20: Option<u8>,
+    /// Wind speed >= 30 m/s
+    pub wind_30: Option<u8>,
+    /// Visibility < 50 m
+    pub visibility_50: Option<u8>,
+    /// Visibility < 100 m
+    pub visibility_100: Option<u8>,
+    /// Visibility < 1000 m
+    pub visibility_1000: Option<u8>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClimatExtremes {
+    pub highest_mean_temperature: Option<ClimatExtreme<ThermodynamicTemperature>>,
+    pub lowest_mean_temperature: Option<ClimatExtreme<ThermodynamicTemperature>>,
+    pub highest_max_temperature: Option<ClimatExtreme<ThermodynamicTemperature>>,
+    pub lowest_min_temperature: Option<ClimatExtreme<ThermodynamicTemperature>>,
+    /// Highest daily precipitation total
+    pub highest_precipitation: Option<ClimatExtreme<Length>>,
+    pub highest_gust: Option<ClimatExtreme<Velocity>>,
+    /// Number of days with thunderstorms
+    pub thunderstorm_days: Option<u8>,
+    /// Number of days with hail
+    pub hail_days: Option<u8>,
+}
+
+/// An extreme value along with the day of the month that it occurred on
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClimatExtreme<T> {
+    pub value: T,
+    pub day: u8,
+    /// If the value occurred on more than one day, with `day` giving the first
+    pub repeated: bool,
+}
+
+impl ClimatReport {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+        let (input, (month, year)) = context(
+            "CLIMAT MMJJJ",
+            preceded(
+                tuple((tag("CLIMAT"), space1)),
+                terminated(
+                    tuple((fromstr_n::<u8>(2), fromstr_n::<u16>(3))),
+                    multispace1,
+                ),
+            ),
+        )(input)?;
+
+        //JJJ gives the last three digits of the year
+        let year = match year {
+            0..=499 => 2000 + year,
+            _ => 1000 + year,
+        };
+
+        let items = input
+            .split('=')
+            .filter_map(|report| ClimatReportItem::parse(report.split_whitespace()))
+            .collect();
+
+        Ok((
+            "",
+            Self {
+                header,
+                month,
+                year,
+                items,
+            },
+        ))
+    }
+}
+
+impl ClimatReportItem {
+    /// Parse the groups of a single station report, returning `None` if the report is empty or
+    /// `NIL`
+    fn parse<'a>(mut groups: impl Iterator<Item = &'a str>) -> Option<Self> {
+        let station = groups.next().filter(|s| s.len() == 5)?;
+        let mut item = Self {
+            station: station.to_owned(),
+            monthly: None,
+            normals: None,
+            days: None,
+            extremes: None,
+        };
+
+        let mut section = None;
+        for group in groups {
+            match group {
+                "NIL" => return None,
+                "111" | "222" | "333" | "444" => {
+                    section = Some(group);
+                    continue;
+                }
+                _ => (),
+            }
+
+            match section {
+                Some("111") => item.monthly.get_or_insert_with(Default::default).set(group),
+                Some("222") => item
+                    .normals
+                    .get_or_insert(ClimatNormals {
+                        start_year: None,
+                        end_year: None,
+                        values: Default::default(),
+                    })
+                    .set(group),
+                Some("333") => item.days.get_or_insert_with(Default::default).set(group),
+                Some("444") => item
+                    .extremes
+                    .get_or_insert_with(Default::default)
+                    .set(group),
+                _ => (),
+            }
+        }
+
+        Some(item)
+    }
+}
+
+/// Parse a precipitation total in whole millimeters
+fn precipitation(group: &str) -> Option<PrecipitationAmount> {
+    match code_field::<u16>(group, 1..5)? {
+        TRACE => Some(PrecipitationAmount::Trace),
+        mm => Some(PrecipitationAmount::Amount(Length::new::<millimeter>(
+            mm as f32,
+        ))),
+    }
+}
+
+impl ClimatValues {
+    /// Set the values of a section 1 group
+    fn set(&mut self, group: &str) {
+        let temperature = |start| {
+            code_signed_tenths(group, start, 3).map(ThermodynamicTemperature::new::<degree_celsius>)
+        };
+
+        match group.get(..1) {
+            Some("1") => self.station_pressure = code_pressure(group),
+            Some("2") => self.sea_level_pressure = code_pressure(group),
+            Some("3") => {
+                self.temperature = temperature(1);
+                self.temperature_deviation = code_field::<f32>(group, 5..8).map(|sd| {
+                    TemperatureInterval::new::<temperature_interval::degree_celsius>(sd / 10f32)
+                });
+            }
+            Some("4") => {
+                self.max_temperature = temperature(1);
+                self.min_temperature = temperature(5);
+            }
+            Some("5") => {
+                self.vapour_pressure =
+                    code_field::<f32>(group, 1..4).map(|e| Pressure::new::<hectopascal>(e / 10f32))
+            }
+            Some("6") => {
+                self.precipitation = precipitation(group);
+                self.precipitation_quintile = code_field(group, 5..6);
+                self.precipitation_days = code_field(group, 6..8);
+            }
+            Some("7") => {
+                self.sunshine = code_field::<f32>(group, 1..4).map(Time::new::<hour>);
+                self.sunshine_percent = code_field::<f32>(group, 4..7).map(|p| p / 100f32);
+            }
+            Some("8") => {
+                self.missing.pressure = code_field(group, 1..3);
+                self.missing.temperature = code_field(group, 3..5);
+                self.missing.max_temperature = code_field(group, 5..6);
+                self.missing.min_temperature = code_field(group, 6..7);
+            }
+            Some("9") => {
+                self.missing.vapour_pressure = code_field(group, 1..3);
+                self.missing.precipitation = code_field(group, 3..5);
+                self.missing.sunshine = code_field(group, 5..7);
+            }
+            _ => (),
+        }
+    }
+}
+
+impl ClimatNormals {
+    /// Set the values of a section 2 group, which differ from section 1 in the reference period
+    /// group, the lack of a precipitation quintile and sunshine percentage, and in giving
+    /// missing years instead of days
+    fn set(&mut self, group: &str) {
+        let values = &mut self.values;
+        match group.get(..1) {
+            Some("0") => {
+                //Years are given with two digits
+                let year = |range| {
+                    code_field::<u16>(group, range).map(|yy| match yy {
+                        0..=49 => 2000 + yy,
+                        _ => 1900 + yy,
+                    })
+                };
+                self.start_year = year(1..3);
+                self.end_year = year(3..5);
+            }
+            Some("6") => {
+                values.precipitation = precipitation(group);
+                values.precipitation_days = code_field(group, 5..7);
+            }
+            Some("7") => values.sunshine = code_field::<f32>(group, 1..4).map(Time::new::<hour>),
+            Some("8") => {
+                values.missing.pressure = code_field(group, 1..3);
+                values.missing.temperature = code_field(group, 3..5);
+                values.missing.max_temperature = code_field(group, 5..7);
+                values.missing.min_temperature = values.missing.max_temperature;
+            }
+            _ => values.set(group),
+        }
+    }
+}
+
+impl ClimatDays {
+    /// Set the day counts of a section 3 group
+    fn set(&mut self, group: &str) {
+        let days = |n: usize| code_field::<u8>(group, 1 + 2 * n..3 + 2 * n);
+        let fields = match group.get(..1) {
+            Some("0") => vec![&mut self.max_temperature_25, &mut self.max_temperature_30],
+            Some("1") => vec![&mut self.max_temperature_35, &mut self.max_temperature_40],
+            Some("2") => vec![
+                &mut self.min_temperature_below_0,
+                &mut self.max_temperature_below_0,
+            ],
+            Some("3") => vec![&mut self.precipitation_1, &mut self.precipitation_5],
+            Some("4") => vec![&mut self.precipitation_10, &mut self.precipitation_50],
+            Some("5") => vec![&mut self.precipitation_100, &mut self.precipitation_150],
+            Some("6") => vec![&mut self.snow_depth_0, &mut self.snow_depth_1],
+            Some("7") => vec![&mut self.snow_depth_10, &mut self.snow_depth_50],
+            Some("8") => vec![&mut self.wind_10, &mut self.wind_20, &mut self.wind_30],
+            Some("9") => vec![
+                &mut self.visibility_50,
+                &mut self.visibility_100,
+                &mut self.visibility_1000,
+            ],
+            _ => return,
+        };
+
+        for (n, field) in fields.into_iter().enumerate() {
+            *field = days(n);
+        }
+    }
+}
+
+impl ClimatExtremes {
+    /// Set the values of a section 4 group
+    fn set(&mut self, group: &str) {
+        let temperature = || {
+            extreme(
+                group,
+                code_signed_tenths(group, 1, 3)
+                    .map(ThermodynamicTemperature::new::<degree_celsius>),
+            )
+        };
+
+        match group.get(..1) {
+            Some("0") => self.highest_mean_temperature = temperature(),
+            Some("1") => self.lowest_mean_temperature = temperature(),
+            Some("2") => self.highest_max_temperature = temperature(),
+            Some("3") => self.lowest_min_temperature = temperature(),
+            Some("4") => {
+                self.highest_precipitation = extreme(
+                    group,
+                    code_field::<f32>(group, 1..5).map(|r| Length::new::<millimeter>(r / 10f32)),
+                )
+            }
+            Some("5") => {
+                let speed = code_field::<f32>(group, 2..5);
+                let speed = match group.get(1..2) {
+                    Some("0" | "1") => speed.map(Velocity::new::<meter_per_second>),
+                    Some("3" | "4") => speed.map(Velocity::new::<knot>),
+                    _ => None,
+                };
+                self.highest_gust = extreme(group, speed);
+            }
+            Some("6") => {
+                self.thunderstorm_days = code_field(group, 1..3);
+                self.hail_days = code_field(group, 3..5);
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Pair a value with the day given in the last two digits of a section 4 group, where 50 is added
+/// to the day if the value occurred on more than one day
+fn extreme<T>(group: &str, value: Option<T>) -> Option<ClimatExtreme<T>> {
+    let day = code_field::<u8>(group, 5..7)?;
+    Some(ClimatExtreme {
+        value: value?,
+        day: day % 50,
+        repeated: day > 50,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_climat() {
+        let climat = ClimatReport::parse(EX_CLIMAT)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(climat.month, 9);
+        assert_eq!(climat.year, 2026);
+        assert_eq!(climat.items.len(), 2);
+
+        let den = &climat.items[0];
+        assert_eq!(den.station, "72469");
+
+        let monthly = den.monthly.unwrap();
+        assert_eq!(
+            monthly.station_pressure,
+            Some(Pressure::new::<hectopascal>(837.5))
+        );
+        assert_eq!(
+            monthly.sea_level_pressure,
+            Some(Pressure::new::<hectopascal>(1015.1))
+        );
+        assert_eq!(
+            monthly.temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(17.9))
+        );
+        assert_eq!(
+            monthly.min_temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(10.3))
+        );
+        assert_eq!(
+            monthly.precipitation,
+            Some(PrecipitationAmount::Amount(Length::new::<millimeter>(
+                18f32
+            )))
+        );
+        assert_eq!(monthly.precipitation_quintile, Some(2));
+        assert_eq!(monthly.precipitation_days, Some(4));
+        assert_eq!(monthly.sunshine, Some(Time::new::<hour>(253f32)));
+        assert_eq!(monthly.sunshine_percent, Some(1.12));
+        assert_eq!(monthly.missing.pressure, Some(0));
+
+        let normals = den.normals.unwrap();
+        assert_eq!(normals.start_year, Some(1991));
+        assert_eq!(normals.end_year, Some(2020));
+        assert_eq!(
+            normals.values.precipitation,
+            Some(PrecipitationAmount::Amount(Length::new::<millimeter>(
+                41f32
+            )))
+        );
+        assert_eq!(normals.values.precipitation_days, Some(6));
+        assert_eq!(normals.values.sunshine_percent, None);
+
+        let days = den.days.unwrap();
+        assert_eq!(days.max_temperature_25, Some(12));
+        assert_eq!(days.precipitation_5, Some(2));
+        assert_eq!(days.wind_10, Some(2));
+
+        let extremes = den.extremes.unwrap();
+        assert_eq!(
+            extremes.lowest_mean_temperature,
+            Some(ClimatExtreme {
+                value: ThermodynamicTemperature::new::<degree_celsius>(10f32),
+                day: 27,
+                repeated: true,
+            })
+        );
+        assert_eq!(
+            extremes.highest_precipitation.map(|p| p.value),
+            Some(Length::new::<millimeter>(11.5))
+        );
+        assert_eq!(
+            extremes.highest_gust.map(|g| g.value),
+            Some(Velocity::new::<meter_per_second>(24f32))
+        );
+        assert_eq!(extremes.thunderstorm_days, Some(5));
+
+        let rno = &climat.items[1];
+        let monthly = rno.monthly.unwrap();
+        assert_eq!(monthly.sea_level_pressure, None);
+        assert_eq!(monthly.precipitation, Some(PrecipitationAmount::Trace));
+        assert_eq!(monthly.sunshine, None);
+        assert_eq!(monthly.missing.sunshine, Some(30));
+        assert_eq!(rno.normals.unwrap().values.sunshine, None);
+        assert!(rno.extremes.is_none());
+    }
+
+    const EX_CLIMAT: &str = include_str!("test/climat.txt");
+}
@@ -0,0 +1,273 @@
+//! Parsing for the WPC coded surface bulletin (CODSUS) of fronts and pressure centers
+
+use nom::{character::complete::multispace1, sequence::terminated};
+use uom::si::{
+    angle::degree,
+    f32::{Angle, Pressure},
+    pressure::hectopascal,
+};
+
+use crate::{
+    formats::LatLon, header::WMOProductIdentifier, parse::time::DayHourMinute, ParseResult,
+};
+
+/// Positions of pressure centers and fronts from a surface analysis
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct CodedSurfaceBulletin {
+    pub header: WMOProductIdentifier,
+    /// Month of the analysis time from the `VALID MMDDHHZ` line
+    pub valid_month: Option<u8>,
+    pub valid: Option<DayHourMinute>,
+    pub pressure_centers: Vec<PressureCenter>,
+    pub fronts: Vec<Front>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PressureCenter {
+    pub kind: PressureCenterType,
+    /// Central pressure reduced to sea level
+    pub pressure: Pressure,
+    pub position: LatLon,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PressureCenterType {
+    High,
+    Low,
+}
+
+/// A front or other boundary given as a line through its points
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Front {
+    pub kind: FrontType,
+    /// Strength given by a `WK`, `MDT`, or `STG` qualifier after the front type
+    pub strength: Option<FrontStrength>,
+    pub points: Vec<LatLon>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrontType {
+    Cold,
+    Warm,
+    Stationary,
+    Occluded,
+    Trough,
+    Dryline,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrontStrength {
+    Weak,
+    Moderate,
+    Strong,
+}
+
+/// A line of the bulletin beginning with a keyword, along with its continuation lines
+enum Element {
+    Centers(PressureCenterType),
+    Front(FrontType),
+}
+
+impl CodedSurfaceBulletin {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut valid_month = None;
+        let mut valid = None;
+        let mut pressure_centers = vec![];
+        let mut fronts: Vec<Front> = vec![];
+
+        let mut element = None;
+        let mut pressure = None;
+
+        for line in input.lines() {
+            if line.starts_with("$$") {
+                break;
+            }
+
+            let mut words = line.split_whitespace().peekable();
+            let keyword = words.peek().copied().unwrap_or_default();
+            let next = match keyword {
+                "VALID" => {
+                    //Valid time is given as MMDDHHZ
+                    let time = line.split_whitespace().nth(1).unwrap_or_default();
+                    let field = |range| time.get(range).and_then(|s: &str| s.parse::<u8>().ok());
+                    valid_month = field(0..2);
+                    valid = field(2..4)
+                        .zip(field(4..6))
+                        .map(|(day, hour)| DayHourMinute {
+                            day,
+                            hour,
+                            minute: 0,
+                        });
+                    element = None;
+                    continue;
+                }
+                "HIGHS" => Some(Element::Centers(PressureCenterType::High)),
+                "LOWS" => Some(Element::Centers(PressureCenterType::Low)),
+                "COLD" => Some(Element::Front(FrontType::Cold)),
+                "WARM" => Some(Element::Front(FrontType::Warm)),
+                "STNRY" => Some(Element::Front(FrontType::Stationary)),
+                "OCFNT" => Some(Element::Front(FrontType::Occluded)),
+                "TROF" => Some(Element::Front(FrontType::Trough)),
+                "DRYLINE" => Some(Element::Front(FrontType::Dryline)),
+                _ => None,
+            };
+
+            if let Some(next) = next {
+                words.next();
+                pressure = None;
+                if let Element::Front(kind) = next {
+                    let strength = match words.peek().copied() {
+                        Some("WK") => Some(FrontStrength::Weak),
+                        Some("MDT") => Some(FrontStrength::Moderate),
+                        Some("STG") => Some(FrontStrength::Strong),
+                        _ => None,
+                    };
+                    if strength.is_some() {
+                        words.next();
+                    }
+
+                    fronts.push(Front {
+                        kind,
+                        strength,
+                        points: vec![],
+                    });
+                }
+                element = Some(next);
+            } else if !line.starts_with(' ') && !keyword.starts_with(|c: char| c.is_ascii_digit()) {
+                element = None;
+            }
+
+            for word in words {
+                match element {
+                    Some(Element::Centers(kind)) => match pressure.take() {
+                        None => pressure = word.parse::<f32>().ok(),
+                        Some(hpa) => {
+                            pressure_centers.extend(parse_position(word).map(|position| {
+                                PressureCenter {
+                                    kind,
+                                    pressure: Pressure::new::<hectopascal>(hpa),
+                                    position,
+                                }
+                            }))
+                        }
+                    },
+                    Some(Element::Front(_)) => {
+                        if let Some(front) = fronts.last_mut() {
+                            front.points.extend(parse_position(word));
+                        }
+                    }
+                    None => (),
+                }
+            }
+        }
+
+        Ok((
+            "",
+            Self {
+                header,
+                valid_month,
+                valid,
+                pressure_centers,
+                fronts,
+            },
+        ))
+    }
+}
+
+/// Parse a position in degrees north and west, given as `LLOO` or `LLOOO` in whole degrees or
+/// `LLLOOO` or `LLLOOOO` in tenths of degrees
+fn parse_position(position: &str) -> Option<LatLon> {
+    if !position.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let (lat, lon, scale) = match position.len() {
+        4 | 5 => (&position[..2], &position[2..], 1f32),
+        6 | 7 => (&position[..3], &position[3..], 10f32),
+        _ => return None,
+    };
+
+    Some(LatLon {
+        lat: Angle::new::<degree>(lat.parse::<f32>().ok()? / scale),
+        lon: Angle::new::<degree>(-lon.parse::<f32>().ok()? / scale),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn point(lat: f32, lon: f32) -> LatLon {
+        LatLon {
+            lat: Angle::new::<degree>(lat),
+            lon: Angle::new::<degree>(lon),
+        }
+    }
+
+    #[test]
+    pub fn test_coded_surface() {
+        let cod = CodedSurfaceBulletin::parse(EX_CODSUS)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(cod.valid_month, Some(10));
+        assert_eq!(
+            cod.valid,
+            Some(DayHourMinute {
+                day: 18,
+                hour: 18,
+                minute: 0
+            })
+        );
+
+        assert_eq!(cod.pressure_centers.len(), 8);
+        assert_eq!(
+            cod.pressure_centers[0],
+            PressureCenter {
+                kind: PressureCenterType::High,
+                pressure: Pressure::new::<hectopascal>(1031f32),
+                position: point(48f32, -89f32),
+            }
+        );
+        assert_eq!(cod.pressure_centers[2].position, point(31f32, -72f32));
+        assert_eq!(cod.pressure_centers[5].kind, PressureCenterType::Low);
+        assert_eq!(
+            cod.pressure_centers[5],
+            PressureCenter {
+                kind: PressureCenterType::Low,
+                pressure: Pressure::new::<hectopascal>(996f32),
+                position: point(55f32, -65f32),
+            }
+        );
+
+        assert_eq!(cod.fronts.len(), 9);
+        let cold = &cod.fronts[0];
+        assert_eq!(cold.kind, FrontType::Cold);
+        assert_eq!(cold.strength, Some(FrontStrength::Weak));
+        assert_eq!(cold.points.len(), 7);
+        assert_eq!(cold.points[0], point(47f32, -101f32));
+        assert_eq!(cold.points[6], point(32f32, -119f32));
+        assert_eq!(cod.fronts[1].strength, None);
+        assert_eq!(cod.fronts[1].points.len(), 10);
+        assert_eq!(cod.fronts[1].points[9], point(31f32, -83f32));
+        assert_eq!(cod.fronts[2].kind, FrontType::Warm);
+        assert_eq!(cod.fronts[2].strength, Some(FrontStrength::Moderate));
+        assert_eq!(cod.fronts[2].points[1], point(46f32, -98f32));
+        assert_eq!(cod.fronts[5].kind, FrontType::Occluded);
+        assert_eq!(cod.fronts[8].kind, FrontType::Dryline);
+
+        assert_eq!(parse_position("4511050"), Some(point(45.1, -105f32)));
+        assert_eq!(parse_position("451950"), Some(point(45.1, -95f32)));
+        assert_eq!(parse_position("123"), None);
+    }
+
+    const EX_CODSUS: &str = include_str!("test/coded_surface.txt");
+}
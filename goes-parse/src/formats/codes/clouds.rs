@@ -1,3 +1,5 @@
+use std::fmt;
+
 use nom::{
     branch::alt,
     bytes::complete::take,
@@ -32,7 +34,7 @@ pub enum CloudAmount {
 
 /// Report containing a cloud level and observed cloud height
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CloudReport {
     pub amount: Option<CloudAmount>,
     pub altitude: Length,
@@ -84,6 +86,26 @@ impl CloudReport {
     }
 }
 
+impl fmt::Display for CloudAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Few => "few clouds",
+            Self::Scattered => "scattered clouds",
+            Self::Broken => "broken clouds",
+            Self::Overcast => "overcast",
+        })
+    }
+}
+
+impl fmt::Display for CloudCover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Cumulonimbus => "cumulonimbus",
+            Self::ToweringCumulonimbus => "towering cumulus",
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use nom::{character::complete::multispace0, sequence::preceded};
@@ -18,6 +18,7 @@ use crate::{
 };
 
 pub mod clouds;
+pub mod precipitation;
 pub mod runway;
 pub mod sea;
 pub mod visibility;
@@ -54,6 +55,7 @@ pub fn parse_1690(input: &str) -> ParseResult<&str, Length> {
 }
 
 /// Time group specified by symbols TT
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug)]
 pub enum TimeGroup {
     At,
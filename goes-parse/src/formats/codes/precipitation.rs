@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use uom::si::{f32::Length, length::inch};
+
+/// Measured precipitation amount as reported in NWS text products, where an amount too small to
+/// measure is reported as a trace
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PrecipitationAmount {
+    Trace,
+    Amount(Length),
+}
+
+impl PrecipitationAmount {
+    /// Parse an amount in inches, or a trace written as `T` or `TRACE`, returning `None` if the
+    /// value is one of the missing markers `M`, `MM`, `MISG`, or `N/A`
+    pub fn parse_inches(s: &str) -> Result<Option<Self>, InvalidPrecipitationAmount> {
+        Ok(Some(match s {
+            "M" | "MM" | "MISG" | "N/A" | "NA" => return Ok(None),
+            "T" | "TRACE" => Self::Trace,
+            other => Self::Amount(Length::new::<inch>(
+                f32::from_str(other).map_err(|_| InvalidPrecipitationAmount)?,
+            )),
+        }))
+    }
+
+    /// Get the measured amount, treating a trace as zero
+    pub fn amount(&self) -> Length {
+        match self {
+            Self::Trace => Length::new::<inch>(0f32),
+            Self::Amount(len) => *len,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+#[error("Invalid precipitation amount")]
+pub struct InvalidPrecipitationAmount;
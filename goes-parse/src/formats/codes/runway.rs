@@ -29,7 +29,7 @@ pub enum RunwayDeposits {
 
 /// Runway contamination level as specified in code table 0519
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum RunwayContaminationLevel {
     Percent { from: f32, to: f32 },
     NotReported,
@@ -37,7 +37,7 @@ pub enum RunwayContaminationLevel {
 
 /// Runway deposit depth as specified by code table 1079
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum RunwayDepositDepth {
     Depth(Length),
     Inoperable,
@@ -46,7 +46,7 @@ pub enum RunwayDepositDepth {
 
 /// Expected runway surface friction as specified by code table 0366
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum RunwaySurfaceFriction {
     Coefficient(f32),
     BrakingAction(RunwaySurfaceBrakingAction),
@@ -55,7 +55,7 @@ pub enum RunwaySurfaceFriction {
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RunwaySurfaceBrakingAction {
     Poor,
     MediumPoor,
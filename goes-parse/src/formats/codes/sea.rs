@@ -1,10 +1,18 @@
-use nom::{character::complete::anychar, combinator::map_res, error::context};
+use nom::{
+    branch::alt, character::complete::anychar, combinator::map_res, error::context,
+    sequence::tuple, Parser,
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{
+    f32::{Angle, Length, Time},
+    length::{centimeter, meter},
+};
 
-use crate::ParseResult;
+use crate::{parse::fromstr_n, ParseResult};
 
 /// Sea state, parsed from code table 3700
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum StateOfTheSea {
     Glassy,
     Rippled,
@@ -40,3 +48,115 @@ impl StateOfTheSea {
         )(input)
     }
 }
+
+impl StateOfTheSea {
+    /// Get the state of the sea described by the given wave height using the height ranges of
+    /// code table 3700
+    pub fn from_wave_height(height: Length) -> Self {
+        let height = height.get::<meter>();
+        match height {
+            h if h <= 0f32 => Self::Glassy,
+            h if h <= 0.1 => Self::Rippled,
+            h if h <= 0.5 => Self::Wavelets,
+            h if h <= 1.25 => Self::Slight,
+            h if h <= 2.5 => Self::Moderate,
+            h if h <= 4. => Self::Rough,
+            h if h <= 6. => Self::VeryRough,
+            h if h <= 9. => Self::High,
+            h if h <= 14. => Self::VeryHigh,
+            _ => Self::Phenomenal,
+        }
+    }
+}
+
+/// Period and height of wind waves or of a swell system
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Waves {
+    /// True direction that the waves are coming from, only given for swell
+    pub direction: Option<Angle>,
+    pub period: Option<Time>,
+    pub height: Option<Length>,
+}
+
+impl Waves {
+    /// Get the state of the sea from the height of the waves
+    pub fn state(&self) -> Option<StateOfTheSea> {
+        self.height.map(StateOfTheSea::from_wave_height)
+    }
+}
+
+/// Ice accretion on ships, parsed from the `6IsEsEsRs` group
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IceAccretion {
+    pub cause: Option<IceAccretionCause>,
+    /// Thickness of the ice accumulated
+    pub thickness: Option<Length>,
+    pub rate: Option<IceAccretionRate>,
+}
+
+/// Cause of ice accretion on ships (code table 1751)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IceAccretionCause {
+    Spray,
+    Fog,
+    SprayAndFog,
+    Rain,
+    SprayAndRain,
+}
+
+/// Rate of ice accretion on ships (code table 3551)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IceAccretionRate {
+    NotBuilding,
+    BuildingSlowly,
+    BuildingRapidly,
+    MeltingSlowly,
+    MeltingRapidly,
+}
+
+impl IceAccretion {
+    /// Parse the `IsEsEsRs` digits of the ice accretion group
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        context(
+            "ice accretion IsEsEsRs",
+            tuple((
+                map_res(anychar, |c: char| {
+                    Ok(Some(match c {
+                        '1' => IceAccretionCause::Spray,
+                        '2' => IceAccretionCause::Fog,
+                        '3' => IceAccretionCause::SprayAndFog,
+                        '4' => IceAccretionCause::Rain,
+                        '5' => IceAccretionCause::SprayAndRain,
+                        '/' => return Ok(None),
+                        _ => return Err("Unrecognized ice accretion cause"),
+                    }))
+                }),
+                alt((
+                    tag("//").map(|_| None),
+                    fromstr_n::<f32>(2).map(|cm| Some(Length::new::<centimeter>(cm))),
+                )),
+                map_res(anychar, |c: char| {
+                    Ok(Some(match c {
+                        '0' => IceAccretionRate::NotBuilding,
+                        '1' => IceAccretionRate::BuildingSlowly,
+                        '2' => IceAccretionRate::BuildingRapidly,
+                        '3' => IceAccretionRate::MeltingSlowly,
+                        '4' => IceAccretionRate::MeltingRapidly,
+                        '/' => return Ok(None),
+                        _ => return Err("Unrecognized ice accretion rate"),
+                    }))
+                }),
+            )),
+        )
+        .map(|(cause, thickness, rate)| Self {
+            cause,
+            thickness,
+            rate,
+        })
+        .parse(input)
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use nom::{
     branch::alt,
     bytes::complete::take,
@@ -86,7 +88,7 @@ impl SignificantWeather {
                 })
             }),
             map_opt(take(2usize), |s: &str| {
-                (s == "VC").then_some(SignificantWeatherIntensity::Moderate)
+                (s == "VC").then_some(SignificantWeatherIntensity::Vicinity)
             }),
         )))(o_input)?;
 
@@ -139,6 +141,114 @@ impl SignificantWeather {
     }
 }
 
+impl fmt::Display for SignificantWeather {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut words = vec![];
+        match self.intensity {
+            SignificantWeatherIntensity::Light => words.push("light".to_owned()),
+            SignificantWeatherIntensity::Heavy => words.push("heavy".to_owned()),
+            SignificantWeatherIntensity::Moderate | SignificantWeatherIntensity::Vicinity => (),
+        }
+
+        let precipitation =
+            (!self.precipitation.is_empty()).then(|| self.precipitation.to_string());
+        match (self.descriptor, precipitation) {
+            (Some(SignificantWeatherDescriptor::Showers), Some(precip)) => {
+                words.push(format!("{} showers", precip))
+            }
+            (Some(SignificantWeatherDescriptor::Showers), None) => words.push("showers".to_owned()),
+            (Some(SignificantWeatherDescriptor::Thunderstorm), Some(precip)) => {
+                words.push(format!("thunderstorm with {}", precip))
+            }
+            (Some(SignificantWeatherDescriptor::Thunderstorm), None) => {
+                words.push("thunderstorm".to_owned())
+            }
+            (descriptor, precip) => {
+                if let Some(descriptor) = descriptor {
+                    words.push(descriptor.to_string());
+                }
+                if let Some(precip) = precip {
+                    words.push(precip);
+                }
+            }
+        }
+
+        if let Some(phenomena) = self.phenomena {
+            if self.precipitation.is_empty() {
+                words.push(phenomena.to_string());
+            } else {
+                words.push(format!("and {}", phenomena));
+            }
+        }
+
+        if self.intensity == SignificantWeatherIntensity::Vicinity {
+            words.push("in the vicinity".to_owned());
+        }
+
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+impl fmt::Display for SignificantWeatherDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Shallow => "shallow",
+            Self::Patches => "patches of",
+            Self::Partial => "partial",
+            Self::LowDrifting => "low drifting",
+            Self::Blowing => "blowing",
+            Self::Showers => "showers",
+            Self::Thunderstorm => "thunderstorm",
+            Self::Supercooled => "freezing",
+        })
+    }
+}
+
+impl fmt::Display for SignificantWeatherPrecipitation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const NAMES: [(SignificantWeatherPrecipitation, &str); 8] = [
+            (SignificantWeatherPrecipitation::DRIZZLE, "drizzle"),
+            (SignificantWeatherPrecipitation::RAIN, "rain"),
+            (SignificantWeatherPrecipitation::SNOW, "snow"),
+            (SignificantWeatherPrecipitation::SNOWGRAIN, "snow grains"),
+            (SignificantWeatherPrecipitation::ICEPELLET, "ice pellets"),
+            (SignificantWeatherPrecipitation::HAIL, "hail"),
+            (SignificantWeatherPrecipitation::SMALLHAIL, "small hail"),
+            (
+                SignificantWeatherPrecipitation::UNKNOWN,
+                "unknown precipitation",
+            ),
+        ];
+
+        let names = NAMES
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", names.join(" and "))
+    }
+}
+
+impl fmt::Display for SignificantWeatherPhenomena {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Mist => "mist",
+            Self::Fog => "fog",
+            Self::Smoke => "smoke",
+            Self::Ash => "volcanic ash",
+            Self::Dust => "widespread dust",
+            Self::Sand => "sand",
+            Self::Haze => "haze",
+            Self::DustSandSwirls => "dust/sand whirls",
+            Self::Squalls => "squalls",
+            Self::FunnelCloud => "funnel cloud",
+            Self::SandStorm => "sandstorm",
+            Self::DustStorm => "duststorm",
+        })
+    }
+}
+
 impl SignificantWeatherPrecipitation {
     pub fn parse(mut input: &str) -> ParseResult<&str, Self> {
         let mut me = Self::empty();
@@ -18,7 +18,7 @@ use crate::{parse::fromstr_n, ParseResult};
 
 /// Wind report on direction and speed parsed from dddff**G**f*m*f*m* format
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct WindSummary {
     pub direction: Angle,
     pub speed: Velocity,
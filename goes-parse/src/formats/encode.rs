@@ -0,0 +1,765 @@
+//! Encoding of decoded reports back into their canonical coded text forms
+
+use std::fmt::{self, Write};
+
+use uom::si::{
+    angle::degree,
+    length::{centimeter, decimeter, foot, meter, mile, millimeter},
+    pressure::{hectopascal, inch_of_mercury},
+    thermodynamic_temperature::degree_celsius,
+    velocity::{knot, meter_per_second},
+};
+
+use crate::{header::CCCC, parse::time::DayHourMinute};
+
+use super::{
+    amdar::{
+        AircraftReportType, AmdarReportItem, FlightPhase, HumidityOrDewPoint, NavigationSystem,
+        TemperaturePrecision, TransmissionSystem, Turbulence,
+    },
+    codes::{
+        clouds::{CloudAmount, CloudCover, CloudReport},
+        runway::{
+            RunwayContaminationLevel, RunwayDepositDepth, RunwayDeposits,
+            RunwaySurfaceBrakingAction, RunwaySurfaceFriction,
+        },
+        sea::StateOfTheSea,
+        weather::{
+            SignificantWeather, SignificantWeatherDescriptor, SignificantWeatherIntensity,
+            SignificantWeatherPhenomena, SignificantWeatherPrecipitation,
+        },
+        wind::WindSummary,
+    },
+    metar::{
+        MetarMinimumVisibility, MetarReport, MetarReportKind, MetarSeaSurfaceReport,
+        MetarVariableWindDir, RunwayState, RunwayTrend, RunwayWindShear,
+    },
+    taf::{TAFReportItem, TAFReportItemGroup, TAFReportItemGroupKind, TAFReportKind},
+    Compass, RunwayDesignator, RunwayDesignatorDirection,
+};
+
+/// Trait implemented by all decoded items that can be written back to the coded text they were
+/// parsed from
+pub trait Encode {
+    /// Write the canonical coded form of `self` to the given writer
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result;
+
+    /// Encode `self` to a newly allocated string
+    fn encoded(&self) -> String {
+        let mut buf = String::new();
+        self.encode(&mut buf)
+            .expect("Writing to a String does not fail");
+        buf
+    }
+}
+
+/// Returns `true` if `val` is within floating point error of an integer, used to recover the unit
+/// that a value was originally reported in
+fn integral(val: f32) -> bool {
+    (val - val.round()).abs() < 1e-3
+}
+
+/// Write a temperature in whole degrees C with `M` indicating a negative value
+fn temperature<W: Write>(w: &mut W, temp: uom::si::f32::ThermodynamicTemperature) -> fmt::Result {
+    let temp = temp.get::<degree_celsius>().round();
+    match temp < 0f32 {
+        true => write!(w, "M{:02}", -temp),
+        false => write!(w, "{:02}", temp),
+    }
+}
+
+/// Write a horizontal visibility in the `VVVV` format, using meters if the visibility was
+/// reported in whole meters and statute miles with fractions otherwise
+fn visibility<W: Write>(w: &mut W, vis: uom::si::f32::Length) -> fmt::Result {
+    let meters = vis.get::<meter>();
+    if integral(meters) {
+        return write!(w, "{:04}", meters.round());
+    }
+
+    let sixteenths = (vis.get::<mile>() * 16f32).round() as u32;
+    let (whole, mut num, mut den) = (sixteenths / 16, sixteenths % 16, 16);
+    while num != 0 && num % 2 == 0 {
+        num /= 2;
+        den /= 2;
+    }
+
+    match (whole, num) {
+        (whole, 0) => write!(w, "{}SM", whole),
+        (0, num) => write!(w, "{}/{}SM", num, den),
+        (whole, num) => write!(w, "{} {}/{}SM", whole, num, den),
+    }
+}
+
+impl Encode for CCCC {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        self.code.iter().try_for_each(|c| w.write_char(*c))
+    }
+}
+
+impl Encode for DayHourMinute {
+    /// Encodes the full `YYGGgg` form, callers that need `YYGG` should write the fields directly
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "{:02}{:02}{:02}", self.day, self.hour, self.minute)
+    }
+}
+
+impl Encode for RunwayDesignator {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "{:02}", self.num)?;
+        match self.dir {
+            Some(RunwayDesignatorDirection::Left) => w.write_char('L'),
+            Some(RunwayDesignatorDirection::Center) => w.write_char('C'),
+            Some(RunwayDesignatorDirection::Right) => w.write_char('R'),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Encode for Compass {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(match self {
+            Self::North => "N",
+            Self::NorthEast => "NE",
+            Self::East => "E",
+            Self::SouthEast => "SE",
+            Self::South => "S",
+            Self::SouthWest => "SW",
+            Self::West => "W",
+            Self::NorthWest => "NW",
+        })
+    }
+}
+
+impl Encode for WindSummary {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        let knots = self.speed.get::<knot>();
+
+        //Variable winds decode with a direction of 0 while calm winds also have no speed
+        let direction = self.direction.get::<degree>().round();
+        match direction == 0f32 && knots > 0f32 {
+            true => w.write_str("VRB")?,
+            false => write!(w, "{:03}", direction)?,
+        }
+
+        let (speed, gust, unit) = match integral(knots) {
+            true => (knots, self.max_speed.map(|v| v.get::<knot>()), "KT"),
+            false => (
+                self.speed.get::<meter_per_second>(),
+                self.max_speed.map(|v| v.get::<meter_per_second>()),
+                "MPS",
+            ),
+        };
+
+        write!(w, "{:02}", speed.round())?;
+        if let Some(gust) = gust {
+            write!(w, "G{:02}", gust.round())?;
+        }
+        w.write_str(unit)
+    }
+}
+
+impl Encode for CloudReport {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(match self.amount {
+            Some(CloudAmount::Few) => "FEW",
+            Some(CloudAmount::Scattered) => "SCT",
+            Some(CloudAmount::Broken) => "BKN",
+            Some(CloudAmount::Overcast) => "OVC",
+            None => "VV",
+        })?;
+        write!(w, "{:03}", (self.altitude.get::<meter>() / 30f32).round())?;
+        match self.cover {
+            Some(CloudCover::Cumulonimbus) => w.write_str("CB"),
+            Some(CloudCover::ToweringCumulonimbus) => w.write_str("TCU"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Encode for SignificantWeather {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(match self.intensity {
+            SignificantWeatherIntensity::Light => "-",
+            SignificantWeatherIntensity::Moderate => "",
+            SignificantWeatherIntensity::Heavy => "+",
+            SignificantWeatherIntensity::Vicinity => "VC",
+        })?;
+
+        if let Some(descriptor) = self.descriptor {
+            w.write_str(match descriptor {
+                SignificantWeatherDescriptor::Shallow => "MI",
+                SignificantWeatherDescriptor::Patches => "BC",
+                SignificantWeatherDescriptor::Partial => "PR",
+                SignificantWeatherDescriptor::LowDrifting => "DR",
+                SignificantWeatherDescriptor::Blowing => "BL",
+                SignificantWeatherDescriptor::Showers => "SH",
+                SignificantWeatherDescriptor::Thunderstorm => "TS",
+                SignificantWeatherDescriptor::Supercooled => "FZ",
+            })?;
+        }
+
+        const PRECIPITATION: [(SignificantWeatherPrecipitation, &str); 8] = [
+            (SignificantWeatherPrecipitation::DRIZZLE, "DZ"),
+            (SignificantWeatherPrecipitation::RAIN, "RA"),
+            (SignificantWeatherPrecipitation::SNOW, "SN"),
+            (SignificantWeatherPrecipitation::SNOWGRAIN, "SG"),
+            (SignificantWeatherPrecipitation::ICEPELLET, "PL"),
+            (SignificantWeatherPrecipitation::HAIL, "GR"),
+            (SignificantWeatherPrecipitation::SMALLHAIL, "GS"),
+            (SignificantWeatherPrecipitation::UNKNOWN, "UP"),
+        ];
+
+        for (flag, code) in PRECIPITATION {
+            if self.precipitation.contains(flag) {
+                w.write_str(code)?;
+            }
+        }
+
+        match self.phenomena {
+            Some(phenomena) => w.write_str(match phenomena {
+                SignificantWeatherPhenomena::Mist => "BR",
+                SignificantWeatherPhenomena::Fog => "FG",
+                SignificantWeatherPhenomena::Smoke => "FU",
+                SignificantWeatherPhenomena::Ash => "VA",
+                SignificantWeatherPhenomena::Dust => "DU",
+                SignificantWeatherPhenomena::Sand => "SA",
+                SignificantWeatherPhenomena::Haze => "HZ",
+                SignificantWeatherPhenomena::DustSandSwirls => "PO",
+                SignificantWeatherPhenomena::Squalls => "SQ",
+                SignificantWeatherPhenomena::FunnelCloud => "FC",
+                SignificantWeatherPhenomena::SandStorm => "SS",
+                SignificantWeatherPhenomena::DustStorm => "DS",
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Encode for StateOfTheSea {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_char(match self {
+            Self::Glassy => '0',
+            Self::Rippled => '1',
+            Self::Wavelets => '2',
+            Self::Slight => '3',
+            Self::Moderate => '4',
+            Self::Rough => '5',
+            Self::VeryRough => '6',
+            Self::High => '7',
+            Self::VeryHigh => '8',
+            Self::Phenomenal => '9',
+        })
+    }
+}
+
+impl Encode for RunwayDeposits {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_char(match self {
+            Self::Clear => '0',
+            Self::Damp => '1',
+            Self::Wet => '2',
+            Self::RimeFrost => '3',
+            Self::DrySnow => '4',
+            Self::WetSnow => '5',
+            Self::Slush => '6',
+            Self::Ice => '7',
+            Self::CompactedSnow => '8',
+            Self::FrozenRuts => '9',
+            Self::NotReported => '/',
+        })
+    }
+}
+
+impl Encode for RunwayContaminationLevel {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_char(match self {
+            Self::Percent { from, .. } if *from < 0.11f32 => '1',
+            Self::Percent { from, .. } if *from < 0.26f32 => '2',
+            Self::Percent { from, .. } if *from < 0.51f32 => '5',
+            Self::Percent { .. } => '9',
+            Self::NotReported => '/',
+        })
+    }
+}
+
+impl Encode for RunwayDepositDepth {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Self::Depth(depth) if depth.get::<millimeter>().round() <= 90f32 => {
+                write!(w, "{:02}", depth.get::<millimeter>().round())
+            }
+            Self::Depth(depth) => write!(
+                w,
+                "{:02}",
+                (depth.get::<centimeter>() / 5f32).round() + 90f32
+            ),
+            Self::Inoperable => w.write_str("99"),
+            Self::NotReported => w.write_str("//"),
+        }
+    }
+}
+
+impl Encode for RunwaySurfaceFriction {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Self::Coefficient(coeff) => write!(w, "{:02}", (coeff * 100f32).round()),
+            Self::BrakingAction(action) => w.write_str(match action {
+                RunwaySurfaceBrakingAction::Poor => "91",
+                RunwaySurfaceBrakingAction::MediumPoor => "92",
+                RunwaySurfaceBrakingAction::Medium => "93",
+                RunwaySurfaceBrakingAction::MediumGood => "94",
+                RunwaySurfaceBrakingAction::Good => "95",
+            }),
+            Self::Unreliable => w.write_str("99"),
+            Self::NotReported => w.write_str("//"),
+        }
+    }
+}
+
+impl Encode for RunwayState {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_char('R')?;
+        self.runway.encode(w)?;
+        w.write_char('/')?;
+        self.deposits.encode(w)?;
+        self.level.encode(w)?;
+        self.depth.encode(w)?;
+        self.friction.encode(w)
+    }
+}
+
+impl Encode for MetarVariableWindDir {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        write!(
+            w,
+            "{:03}V{:03}",
+            self.extreme_ccw.get::<degree>().round(),
+            self.extreme_cw.get::<degree>().round()
+        )
+    }
+}
+
+impl Encode for MetarMinimumVisibility {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "{:04}", self.visibility.get::<meter>().round())?;
+        self.direction.encode(w)
+    }
+}
+
+impl Encode for RunwayWindShear {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Self::All => w.write_str("WS ALL RWY"),
+            Self::Within(len) => write!(w, "WS R{:02}", len.get::<meter>().round()),
+        }
+    }
+}
+
+impl Encode for MetarSeaSurfaceReport {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Self::StateOfSea { temp, state } => {
+                w.write_char('W')?;
+                temperature(w, *temp)?;
+                w.write_str("/S")?;
+                state.encode(w)
+            }
+            Self::WaveHeight { temp, height } => {
+                w.write_char('W')?;
+                temperature(w, *temp)?;
+                write!(w, "/H{:03}", height.get::<decimeter>().round())
+            }
+        }
+    }
+}
+
+impl Encode for MetarReport {
+    /// Encode this report as a single FM 15 METAR line terminated by `=`
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        self.country.encode(w)?;
+        w.write_char(' ')?;
+        self.origin.encode(w)?;
+        w.write_char('Z')?;
+        w.write_str(match self.kind {
+            MetarReportKind::Normal => "",
+            MetarReportKind::Auto => " AUTO",
+            MetarReportKind::Cor => " COR",
+        })?;
+
+        if let Some(ref wind) = self.wind {
+            w.write_char(' ')?;
+            wind.encode(w)?;
+        }
+        if let Some(ref variable) = self.variable_wind_dir {
+            w.write_char(' ')?;
+            variable.encode(w)?;
+        }
+        if let Some(vis) = self.visibility {
+            w.write_char(' ')?;
+            visibility(w, vis)?;
+        }
+        if let Some(ref min) = self.minimum_visibility {
+            w.write_char(' ')?;
+            min.encode(w)?;
+        }
+
+        for (runway, distance, trend) in self.runway_range.iter() {
+            w.write_str(" R")?;
+            runway.encode(w)?;
+            write!(w, "/{:04}", distance.get::<meter>().round())?;
+            w.write_char(match trend {
+                RunwayTrend::Farther => 'U',
+                RunwayTrend::Closer => 'D',
+                RunwayTrend::NoChange => 'N',
+            })?;
+        }
+
+        for weather in self.weather.iter() {
+            w.write_char(' ')?;
+            weather.encode(w)?;
+        }
+        for cloud in self.clouds.iter() {
+            w.write_char(' ')?;
+            cloud.encode(w)?;
+        }
+
+        if self.air_temperature.is_some() || self.dewpoint_temperature.is_some() {
+            w.write_char(' ')?;
+            if let Some(temp) = self.air_temperature {
+                temperature(w, temp)?;
+            }
+            w.write_char('/')?;
+            if let Some(temp) = self.dewpoint_temperature {
+                temperature(w, temp)?;
+            }
+        }
+
+        if let Some(qnh) = self.qnh {
+            let hpa = qnh.get::<hectopascal>();
+            match integral(hpa) {
+                true => write!(w, " Q{:04}", hpa.round())?,
+                false => write!(
+                    w,
+                    " A{:04}",
+                    (qnh.get::<inch_of_mercury>() * 100f32).round()
+                )?,
+            }
+        }
+
+        if let Some(ref recent) = self.recent_weather {
+            w.write_str(" RE")?;
+            recent.encode(w)?;
+        }
+        if let Some(ref shear) = self.runway_wind_shear {
+            w.write_char(' ')?;
+            shear.encode(w)?;
+        }
+        for sea in self.sea.iter() {
+            w.write_char(' ')?;
+            sea.encode(w)?;
+        }
+        for state in self.runway_status.iter() {
+            w.write_char(' ')?;
+            state.encode(w)?;
+        }
+
+        w.write_char('=')
+    }
+}
+
+/// Write the visibility, weather, and cloud groups shared by TAF items and change groups, each
+/// preceded by a space
+fn vis_weather_clouds<W: Write>(
+    w: &mut W,
+    vis: Option<uom::si::f32::Length>,
+    weather: &[SignificantWeather],
+    clouds: &[CloudReport],
+) -> fmt::Result {
+    if let Some(vis) = vis {
+        w.write_char(' ')?;
+        visibility(w, vis)?;
+    }
+    for weather in weather {
+        w.write_char(' ')?;
+        weather.encode(w)?;
+    }
+    for cloud in clouds {
+        w.write_char(' ')?;
+        cloud.encode(w)?;
+    }
+
+    Ok(())
+}
+
+/// Write a period of validity in `YYGG/YYGG` format
+fn period<W: Write>(w: &mut W, from: &DayHourMinute, to: &DayHourMinute) -> fmt::Result {
+    write!(
+        w,
+        "{:02}{:02}/{:02}{:02}",
+        from.day, from.hour, to.day, to.hour
+    )
+}
+
+impl Encode for TAFReportItemGroup {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self.kind {
+            TAFReportItemGroupKind::TimeIndicator(from) => {
+                w.write_str("FM")?;
+                from.encode(w)?;
+            }
+            TAFReportItemGroupKind::Change(from, to) => {
+                w.write_str("BECMG ")?;
+                period(w, &from, &to)?;
+            }
+            TAFReportItemGroupKind::TemporaryChange {
+                probability,
+                from,
+                to,
+            } => {
+                if probability < 100f32 {
+                    write!(w, "PROB{:02} ", probability.round())?;
+                }
+                w.write_str("TEMPO ")?;
+                period(w, &from, &to)?;
+            }
+            TAFReportItemGroupKind::Probable {
+                probability,
+                from,
+                to,
+            } => {
+                write!(w, "PROB{:02} ", probability.round())?;
+                period(w, &from, &to)?;
+            }
+        }
+
+        if let Some(ref wind) = self.wind {
+            w.write_char(' ')?;
+            wind.encode(w)?;
+        }
+
+        vis_weather_clouds(w, self.visibility, &self.weather, &self.clouds)
+    }
+}
+
+impl Encode for TAFReportItem {
+    /// Encode this forecast as a FM 51 TAF with each change group on a new indented line,
+    /// terminated by `=`
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(match self.kind {
+            TAFReportKind::Report => "TAF ",
+            TAFReportKind::Amendment => "TAF AMD ",
+            TAFReportKind::Correction => "TAF COR ",
+        })?;
+
+        self.country.encode(w)?;
+        w.write_char(' ')?;
+        self.origin_date.encode(w)?;
+        w.write_str("Z ")?;
+        period(w, &self.time_range.0, &self.time_range.1)?;
+
+        w.write_char(' ')?;
+        match self.wind {
+            Some(ref wind) => wind.encode(w)?,
+            None => w.write_str("CNL")?,
+        }
+
+        vis_weather_clouds(
+            w,
+            self.horizontal_vis,
+            &self.significant_weather,
+            &self.clouds,
+        )?;
+
+        for group in self.groups.iter() {
+            w.write_str("\n     ")?;
+            group.encode(w)?;
+        }
+
+        w.write_char('=')
+    }
+}
+
+impl Encode for FlightPhase {
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(match self {
+            Self::NA => "///",
+            Self::LevelFlight(AircraftReportType::Routine) => "LVR",
+            Self::LevelFlight(AircraftReportType::MaxWind) => "LVW",
+            Self::Ascent => "ASC",
+            Self::Descent => "DES",
+            Self::Unsteady => "UNS",
+        })
+    }
+}
+
+/// Write a temperature in tenths of a degree C with `PS` or `MS` sign in the AMDAR format
+fn amdar_temperature<W: Write>(
+    w: &mut W,
+    temp: uom::si::f32::ThermodynamicTemperature,
+) -> fmt::Result {
+    let tenths = (temp.get::<degree_celsius>() * 10f32).round();
+    match tenths < 0f32 {
+        true => write!(w, "MS{:03}", -tenths),
+        false => write!(w, "PS{:03}", tenths),
+    }
+}
+
+/// Write an angle in the AMDAR degrees and whole minutes format with `D` degree digits
+fn degrees_minutes<W: Write>(w: &mut W, angle: f32, digits: usize) -> fmt::Result {
+    let minutes = (angle.abs() * 60f32).round() as u32;
+    write!(
+        w,
+        "{:0digits$}{:02}",
+        minutes / 60,
+        minutes % 60,
+        digits = digits
+    )
+}
+
+impl Encode for AmdarReportItem {
+    /// Encode this item as a single FM 42 AMDAR report terminated by `=`
+    fn encode<W: Write>(&self, w: &mut W) -> fmt::Result {
+        self.phase.encode(w)?;
+        write!(w, " {} ", self.aircraft_identifier)?;
+
+        let lat = self.lat.get::<degree>();
+        degrees_minutes(w, lat, 2)?;
+        w.write_char(if lat < 0f32 { 'S' } else { 'N' })?;
+        w.write_char(' ')?;
+
+        let lon = self.lon.get::<degree>();
+        degrees_minutes(w, lon, 3)?;
+        w.write_char(if lon < 0f32 { 'W' } else { 'E' })?;
+        w.write_char(' ')?;
+
+        self.time.encode(w)?;
+
+        let altitude = self.pressure_altitude.get::<foot>().round();
+        match altitude < 0f32 {
+            true => write!(w, " A{:03} ", -altitude)?,
+            false => write!(w, " F{:03} ", altitude)?,
+        }
+
+        amdar_temperature(w, self.air_temperature)?;
+        match self.humidity_or_dew_point {
+            Some(HumidityOrDewPoint::DewPoint(dewpoint)) => {
+                w.write_char(' ')?;
+                amdar_temperature(w, dewpoint)?;
+            }
+            Some(HumidityOrDewPoint::RelativeHumidity(rh)) => write!(w, " {:03}", rh.round())?,
+            None => (),
+        }
+
+        write!(
+            w,
+            " {:03}/{:03}",
+            self.true_wind_direction.get::<degree>().round(),
+            self.wind_speed.get::<knot>().round()
+        )?;
+
+        w.write_str(" TB")?;
+        w.write_char(match self.turbulence {
+            Some(Turbulence::None) => '0',
+            Some(Turbulence::Light) => '1',
+            Some(Turbulence::Moderate) => '2',
+            Some(Turbulence::Severe) => '3',
+            None => '/',
+        })?;
+
+        w.write_str(" S")?;
+        w.write_char(match self.navigation_system {
+            Some(NavigationSystem::Intertial) => '0',
+            Some(NavigationSystem::OMEGA) => '1',
+            None => '/',
+        })?;
+        w.write_char(match self.transmission_system {
+            Some(TransmissionSystem::ASDAR) => '0',
+            Some(TransmissionSystem::ASDARWithACARS(false)) => '1',
+            Some(TransmissionSystem::ASDARWithACARS(true)) => '2',
+            Some(TransmissionSystem::ACARS) => '3',
+            Some(TransmissionSystem::ACARSWithASDAR(false)) => '4',
+            Some(TransmissionSystem::ACARSWithASDAR(true)) => '5',
+            None => '/',
+        })?;
+        w.write_char(match self.precision {
+            Some(TemperaturePrecision::High) => '0',
+            Some(TemperaturePrecision::Low) => '1',
+            None => '/',
+        })?;
+
+        w.write_char('=')
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::formats::{amdar::AmdarReport, metar::EmwinMetarReport, taf::TAFReport};
+
+    use super::*;
+
+    const METAR: &str = include_str!("test/metar.txt");
+    const TAF: &str = include_str!("test/taf.txt");
+    const AMDAR: &str = include_str!("test/amdar.txt");
+
+    /// Decoding the encoded form of every report in the fixtures must produce the same report
+    #[test]
+    fn test_metar_roundtrip() {
+        let (_, metars) = EmwinMetarReport::parse(NaiveDate::default())(METAR)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)));
+        assert!(!metars.metars.is_empty());
+
+        for metar in metars.metars {
+            let encoded = metar.encoded();
+            let (rest, decoded) = MetarReport::parse(&encoded)
+                .unwrap_or_else(|e| panic!("{}: {}", encoded, crate::display_error(e)));
+            assert_eq!(rest, "=", "{}", encoded);
+            assert_eq!(decoded, Some(metar), "{}", encoded);
+        }
+    }
+
+    #[test]
+    fn test_taf_roundtrip() {
+        let (_, tafs) = TAFReport::parse_full(TAF, NaiveDate::default())
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)));
+        assert!(!tafs.items.is_empty());
+        assert!(tafs.items.iter().any(|taf| taf.horizontal_vis.is_some()));
+
+        for taf in tafs.items {
+            let encoded = taf.encoded();
+            let (_, decoded) = TAFReportItem::parse(&encoded)
+                .unwrap_or_else(|e| panic!("{}: {}", encoded, crate::display_error(e)));
+            assert_eq!(decoded, Some(taf), "{}", encoded);
+        }
+    }
+
+    #[test]
+    fn test_amdar_roundtrip() {
+        let (_, amdar) =
+            AmdarReport::parse(AMDAR).unwrap_or_else(|e| panic!("{}", crate::display_error(e)));
+
+        for item in amdar.items {
+            let encoded = item.encoded();
+            let (_, decoded) = AmdarReportItem::parse(&encoded)
+                .unwrap_or_else(|e| panic!("{}: {}", encoded, crate::display_error(e)));
+            assert_eq!(decoded, item, "{}", encoded);
+        }
+    }
+
+    #[test]
+    fn test_encode_metar() {
+        const LINE: &str = "KCLK 080615Z AUTO 00000KT 1 3/4SM -RA BKN003 OVC009 07/07 A3020=";
+        let (_, metar) = MetarReport::parse(LINE).unwrap();
+        assert_eq!(metar.unwrap().encoded(), LINE);
+
+        //Manned reports have no kind token
+        const MANNED: &str = "KXYZ 081955Z 24012KT 10SM FEW250 22/08 A2992=";
+        let (_, metar) = MetarReport::parse(MANNED).unwrap();
+        assert_eq!(metar.unwrap().encoded(), MANNED);
+
+        const VARIABLE: &str = "KXYZ 081955Z VRB05KT 10SM FEW040 22/08 A2992=";
+        let (_, metar) = MetarReport::parse(VARIABLE).unwrap();
+        assert_eq!(metar.unwrap().encoded(), VARIABLE);
+    }
+}
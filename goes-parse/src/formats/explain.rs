@@ -0,0 +1,418 @@
+//! Plain-English rendering of decoded METAR and TAF reports for readers unfamiliar with the
+//! coded formats
+
+use std::fmt;
+
+use uom::si::{
+    angle::degree,
+    f32::{Length, Pressure, ThermodynamicTemperature, Velocity},
+    length::{kilometer, meter, mile},
+    pressure::{hectopascal, inch_of_mercury},
+    thermodynamic_temperature::{degree_celsius, degree_fahrenheit},
+    velocity::{kilometer_per_hour, knot, mile_per_hour},
+};
+
+use crate::parse::time::DayHourMinute;
+
+use super::{
+    codes::{clouds::CloudReport, weather::SignificantWeather, wind::WindSummary},
+    metar::{MetarReport, MetarReportKind, RunwayWindShear},
+    taf::{TAFReportItem, TAFReportItemGroup, TAFReportItemGroupKind, TAFReportKind},
+};
+
+/// System of units used when rendering a report in plain English
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnitSystem {
+    /// km/h, kilometers, meters, degrees Celsius, and hectopascals
+    Metric,
+    /// mph, miles, feet, degrees Fahrenheit, and inches of mercury
+    Imperial,
+    /// Knots, statute miles, feet, degrees Celsius, and inches of mercury as used in US aviation
+    #[default]
+    Aviation,
+}
+
+/// Wrapper around a decoded report that implements [Display](fmt::Display) by describing the
+/// report in plain English using the selected [UnitSystem]
+#[derive(Clone, Copy, Debug)]
+pub struct Explain<'a, T> {
+    pub report: &'a T,
+    pub units: UnitSystem,
+}
+
+impl MetarReport {
+    /// Describe this report in plain English, e.g. `format!("{}", metar.explain(UnitSystem::Metric))`
+    pub fn explain(&self, units: UnitSystem) -> Explain<'_, Self> {
+        Explain {
+            report: self,
+            units,
+        }
+    }
+}
+
+impl TAFReportItem {
+    /// Describe this forecast in plain English, e.g. `format!("{}", taf.explain(UnitSystem::Metric))`
+    pub fn explain(&self, units: UnitSystem) -> Explain<'_, Self> {
+        Explain {
+            report: self,
+            units,
+        }
+    }
+}
+
+impl UnitSystem {
+    fn speed(&self, speed: Velocity) -> String {
+        match self {
+            Self::Metric => format!("{} km/h", number(speed.get::<kilometer_per_hour>(), 0)),
+            Self::Imperial => format!("{} mph", number(speed.get::<mile_per_hour>(), 0)),
+            Self::Aviation => format!("{} kt", number(speed.get::<knot>(), 0)),
+        }
+    }
+
+    fn visibility(&self, vis: Length) -> String {
+        match self {
+            Self::Metric => match vis.get::<meter>() < 5000f32 {
+                true => format!("{} meters", number(vis.get::<meter>(), 0)),
+                false => format!("{} kilometers", number(vis.get::<kilometer>(), 0)),
+            },
+            Self::Imperial => plural(vis.get::<mile>(), 2, "mile"),
+            Self::Aviation => plural(vis.get::<mile>(), 2, "statute mile"),
+        }
+    }
+
+    fn height(&self, height: Length) -> String {
+        match self {
+            Self::Metric => format!(
+                "{} m",
+                number((height.get::<meter>() / 10f32).round() * 10f32, 0)
+            ),
+            //Heights are reported in units of 30 m that correspond to 100 ft (code table 1690)
+            Self::Imperial | Self::Aviation => format!(
+                "{} ft",
+                number((height.get::<meter>() / 30f32).round() * 100f32, 0)
+            ),
+        }
+    }
+
+    fn temperature(&self, temp: ThermodynamicTemperature) -> String {
+        match self {
+            Self::Imperial => format!("{} °F", number(temp.get::<degree_fahrenheit>(), 0)),
+            Self::Metric | Self::Aviation => {
+                format!("{} °C", number(temp.get::<degree_celsius>(), 0))
+            }
+        }
+    }
+
+    fn pressure(&self, pressure: Pressure) -> String {
+        match self {
+            Self::Metric => format!("pressure {} hPa", number(pressure.get::<hectopascal>(), 0)),
+            Self::Imperial | Self::Aviation => {
+                format!("altimeter {:.2} inHg", pressure.get::<inch_of_mercury>())
+            }
+        }
+    }
+
+    fn wind(&self, wind: &WindSummary) -> String {
+        if wind.speed.get::<knot>() < 0.5f32 {
+            return "calm wind".to_owned();
+        }
+
+        let mut desc = match wind.direction.get::<degree>() == 0f32 {
+            true => format!("wind variable at {}", self.speed(wind.speed)),
+            false => format!(
+                "wind from {}° at {}",
+                wind.direction.get::<degree>().round(),
+                self.speed(wind.speed)
+            ),
+        };
+
+        if let Some(gust) = wind.max_speed {
+            desc.push_str(&format!(" gusting {}", self.speed(gust)));
+        }
+
+        desc
+    }
+
+    fn clouds(&self, clouds: &[CloudReport]) -> Vec<String> {
+        clouds
+            .iter()
+            .map(|cloud| {
+                let mut desc = match cloud.amount {
+                    Some(amount) => format!("{} at {}", amount, self.height(cloud.altitude)),
+                    None => format!(
+                        "sky obscured, vertical visibility {}",
+                        self.height(cloud.altitude)
+                    ),
+                };
+                if let Some(cover) = cloud.cover {
+                    desc.push_str(&format!(" ({})", cover));
+                }
+                desc
+            })
+            .collect()
+    }
+
+    /// Describe the wind, visibility, weather, and cloud conditions common to METAR and TAF
+    /// reports
+    fn conditions(
+        &self,
+        wind: Option<&WindSummary>,
+        visibility: Option<Length>,
+        weather: &[SignificantWeather],
+        clouds: &[CloudReport],
+    ) -> Vec<String> {
+        let mut parts = vec![];
+        if let Some(wind) = wind {
+            parts.push(self.wind(wind));
+        }
+        if let Some(vis) = visibility {
+            parts.push(format!("visibility {}", self.visibility(vis)));
+        }
+        parts.extend(weather.iter().map(ToString::to_string));
+        parts.extend(self.clouds(clouds));
+        parts
+    }
+}
+
+impl fmt::Display for Explain<'_, MetarReport> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let metar = self.report;
+        let units = self.units;
+
+        write!(
+            f,
+            "{} observation at {}",
+            metar.country,
+            time(&metar.origin)
+        )?;
+        match metar.kind {
+            MetarReportKind::Normal => (),
+            MetarReportKind::Auto => write!(f, " (automated)")?,
+            MetarReportKind::Cor => write!(f, " (corrected)")?,
+        }
+        write!(f, ": ")?;
+
+        let mut parts = units.conditions(
+            metar.wind.as_ref(),
+            metar.visibility,
+            &metar.weather,
+            &metar.clouds,
+        );
+
+        if let Some(variable) = metar.variable_wind_dir {
+            parts.insert(
+                metar.wind.is_some() as usize,
+                format!(
+                    "wind direction varying between {}° and {}°",
+                    variable.extreme_ccw.get::<degree>().round(),
+                    variable.extreme_cw.get::<degree>().round()
+                ),
+            );
+        }
+
+        if let Some(temp) = metar.air_temperature {
+            parts.push(format!("temperature {}", units.temperature(temp)));
+        }
+        if let Some(dewpoint) = metar.dewpoint_temperature {
+            parts.push(format!("dewpoint {}", units.temperature(dewpoint)));
+        }
+        if let Some(qnh) = metar.qnh {
+            parts.push(units.pressure(qnh));
+        }
+        if let Some(recent) = metar.recent_weather {
+            parts.push(format!("recent {}", recent));
+        }
+        match metar.runway_wind_shear {
+            Some(RunwayWindShear::All) => parts.push("wind shear on all runways".to_owned()),
+            Some(RunwayWindShear::Within(_)) => parts.push("wind shear reported".to_owned()),
+            None => (),
+        }
+
+        write!(f, "{}", parts.join("; "))
+    }
+}
+
+impl fmt::Display for Explain<'_, TAFReportItem> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let taf = self.report;
+        let units = self.units;
+
+        write!(
+            f,
+            "{} for {} issued {}, valid from {} until {}: ",
+            match taf.kind {
+                TAFReportKind::Report => "Forecast",
+                TAFReportKind::Amendment => "Amended forecast",
+                TAFReportKind::Correction => "Corrected forecast",
+            },
+            taf.country,
+            time(&taf.origin_date),
+            time(&taf.time_range.0),
+            time(&taf.time_range.1),
+        )?;
+
+        let parts = units.conditions(
+            taf.wind.as_ref(),
+            taf.horizontal_vis,
+            &taf.significant_weather,
+            &taf.clouds,
+        );
+        write!(f, "{}", parts.join("; "))?;
+
+        for group in taf.groups.iter() {
+            write!(
+                f,
+                ". {}",
+                Explain {
+                    report: group,
+                    units
+                }
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Explain<'_, TAFReportItemGroup> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let group = self.report;
+        match group.kind {
+            TAFReportItemGroupKind::TimeIndicator(from) => write!(f, "From {}", time(&from))?,
+            TAFReportItemGroupKind::Change(from, to) => {
+                write!(f, "Becoming between {} and {}", time(&from), time(&to))?
+            }
+            TAFReportItemGroupKind::TemporaryChange {
+                probability,
+                from,
+                to,
+            } => {
+                write!(f, "Temporarily between {} and {}", time(&from), time(&to))?;
+                if probability < 100f32 {
+                    write!(f, " ({}% chance)", probability)?;
+                }
+            }
+            TAFReportItemGroupKind::Probable {
+                probability,
+                from,
+                to,
+            } => write!(
+                f,
+                "{}% chance between {} and {}",
+                probability,
+                time(&from),
+                time(&to)
+            )?,
+        }
+
+        let parts = self.units.conditions(
+            group.wind.as_ref(),
+            group.visibility,
+            &group.weather,
+            &group.clouds,
+        );
+        write!(f, ": {}", parts.join("; "))
+    }
+}
+
+/// Format a time offset into the month as day and UTC time
+fn time(time: &DayHourMinute) -> String {
+    format!("day {} {:02}:{:02} UTC", time.day, time.hour, time.minute)
+}
+
+/// Format a quantity with a unit name, pluralizing the unit if the quantity is not one
+fn plural(val: f32, decimals: usize, unit: &str) -> String {
+    let num = number(val, decimals);
+    match num == "1" {
+        true => format!("{} {}", num, unit),
+        false => format!("{} {}s", num, unit),
+    }
+}
+
+/// Format a number with at most `decimals` digits after the decimal point, trimming trailing
+/// zeros and separating thousands with commas
+fn number(val: f32, decimals: usize) -> String {
+    let formatted = format!("{:.*}", decimals, val);
+    let formatted = match formatted.contains('.') {
+        true => formatted.trim_end_matches('0').trim_end_matches('.'),
+        false => formatted.as_str(),
+    };
+    let formatted = match formatted {
+        "-0" => "0",
+        other => other,
+    };
+
+    let (sign, rest) = match formatted.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", formatted),
+    };
+    let (whole, frac) = match rest.split_once('.') {
+        Some((whole, frac)) => (whole, Some(frac)),
+        None => (rest, None),
+    };
+
+    let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+    for (i, c) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+
+    match frac {
+        Some(frac) => format!("{}{}.{}", sign, grouped, frac),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const METAR: &str = "KXYZ 081955Z 24012G20KT 10SM -RA BKN035 OVC080 22/14 A2992 RMK AO2=";
+    const TAF: &str = r#"TAF KIAD 052059Z 0521/0624 18015G24KT P6SM FEW050 BKN250
+  FM052200 16010G18KT P6SM SCT050 BKN250
+  TEMPO 0603/0606 4SM SHRA BKN018="#;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(3500f32, 0), "3,500");
+        assert_eq!(number(-12.5f32, 1), "-12.5");
+        assert_eq!(number(0.25f32, 2), "0.25");
+        assert_eq!(number(1234567f32, 0), "1,234,567");
+    }
+
+    #[test]
+    fn test_explain_metar() {
+        let (_, metar) =
+            MetarReport::parse(METAR).unwrap_or_else(|e| panic!("{}", crate::display_error(e)));
+        let metar = metar.unwrap();
+
+        assert_eq!(
+            metar.explain(UnitSystem::Aviation).to_string(),
+            "KXYZ observation at day 8 19:55 UTC: wind from 240° at 12 kt gusting 20 kt; \
+            visibility 10 statute miles; light rain; broken clouds at 3,500 ft; \
+            overcast at 8,000 ft; temperature 22 °C; dewpoint 14 °C; altimeter 29.92 inHg"
+        );
+
+        let metric = metar.explain(UnitSystem::Metric).to_string();
+        assert!(metric.contains("visibility 16 kilometers"));
+        assert!(metric.contains("pressure 1,013 hPa"));
+
+        let imperial = metar.explain(UnitSystem::Imperial).to_string();
+        assert!(imperial.contains("temperature 72 °F"));
+    }
+
+    #[test]
+    fn test_explain_taf() {
+        let (_, taf) =
+            TAFReportItem::parse(TAF).unwrap_or_else(|e| panic!("{}", crate::display_error(e)));
+        let taf = taf.unwrap().explain(UnitSystem::Aviation).to_string();
+
+        assert!(taf.starts_with("Forecast for KIAD issued day 5 20:59 UTC"));
+        assert!(taf.contains("From day 5 22:00 UTC: wind from 160° at 10 kt gusting 18 kt"));
+        assert!(taf.contains("Temporarily between day 6 03:00 UTC and day 6 06:00 UTC"));
+        assert!(taf.contains("rain showers"));
+    }
+}
@@ -0,0 +1,675 @@
+//! Decoding of IWXXM 3.x XML bulletins (T1 = `L`) into the same METAR and TAF structures
+//! produced by the text decoders
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use roxmltree::{Document, Node};
+use uom::si::{
+    angle::degree,
+    f32::{Angle, Length, Pressure, ThermodynamicTemperature, Velocity},
+    length::{foot, kilometer, meter},
+    pressure::hectopascal,
+    thermodynamic_temperature::degree_celsius,
+    velocity::{kilometer_per_hour, knot, meter_per_second},
+};
+
+use crate::{
+    formats::{
+        codes::{
+            clouds::{CloudAmount, CloudCover, CloudReport},
+            sea::StateOfTheSea,
+            weather::SignificantWeather,
+            wind::WindSummary,
+        },
+        metar::{
+            MetarMinimumVisibility, MetarReport, MetarReportKind, MetarSeaSurfaceReport,
+            MetarVariableWindDir, RunwayTrend, RunwayWindShear,
+        },
+        taf::{TAFReportItem, TAFReportItemGroup, TAFReportItemGroupKind, TAFReportKind},
+        Compass, RunwayDesignator,
+    },
+    header::{WMOProductIdentifier, CCCC},
+    parse::time::DayHourMinute,
+};
+
+const XLINK: &str = "http://www.w3.org/1999/xlink";
+const GML: &str = "http://www.opengis.net/gml/3.2";
+
+/// METAR, SPECI, and TAF reports decoded from an IWXXM bulletin or single report document.
+/// Other IWXXM reports like SIGMETs and advisories are skipped.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct IwxxmBulletin {
+    /// WMO heading preceding the XML document, if given
+    pub header: Option<WMOProductIdentifier>,
+    /// METAR and SPECI reports, omitting `NIL` reports
+    pub metars: Vec<MetarReport>,
+    /// TAF reports, omitting `NIL` reports
+    pub tafs: Vec<TAFReportItem>,
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum IwxxmError {
+    #[error("Failed to parse WMO heading: {0}")]
+    Header(String),
+    #[error("Failed to parse XML document: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("Unsupported IWXXM version {0}")]
+    Version(String),
+    #[error("Missing {0} element")]
+    Missing(&'static str),
+    #[error("Invalid value {1} for {0}")]
+    Value(&'static str, String),
+    #[error("Unsupported unit of measure {1} for {0}")]
+    Unit(&'static str, String),
+}
+
+impl IwxxmBulletin {
+    pub fn parse(input: &str) -> Result<Self, IwxxmError> {
+        let input = input.trim_start();
+        let (xml, header) = match input.starts_with('<') {
+            true => (input, None),
+            false => {
+                let (_, header) = WMOProductIdentifier::parse(input)
+                    .map_err(|e| IwxxmError::Header(crate::display_error(e)))?;
+                let xml = input.find('<').map(|idx| &input[idx..]).unwrap_or("");
+                (xml, Some(header))
+            }
+        };
+
+        let doc = Document::parse(xml)?;
+        let mut metars = vec![];
+        let mut tafs = vec![];
+
+        for report in doc.descendants().filter(|node| node.is_element()) {
+            let Some(namespace) = report.tag_name().namespace() else {
+                continue;
+            };
+            let Some(version) = namespace.strip_prefix("http://icao.int/iwxxm/") else {
+                continue;
+            };
+
+            match report.tag_name().name() {
+                "METAR" | "SPECI" | "TAF" if !version.starts_with("3.") => {
+                    return Err(IwxxmError::Version(version.to_owned()))
+                }
+                "METAR" | "SPECI" => metars.extend(metar(report)?),
+                "TAF" => tafs.extend(taf(report)?),
+                _ => (),
+            }
+        }
+
+        Ok(Self {
+            header,
+            metars,
+            tafs,
+        })
+    }
+}
+
+/// Decode a METAR or SPECI element, returning `None` if the observation is missing
+fn metar(report: Node) -> Result<Option<MetarReport>, IwxxmError> {
+    let country = aerodrome(report)?;
+    let origin = child(report, "observationTime")
+        .or_else(|| child(report, "issueTime"))
+        .and_then(time_instant)
+        .ok_or(IwxxmError::Missing("observationTime"))?;
+
+    let Some(obs) = child(report, "observation")
+        .and_then(|obs| child(obs, "MeteorologicalAerodromeObservation"))
+    else {
+        return Ok(None);
+    };
+
+    let kind = match (
+        report.attribute("reportStatus"),
+        report.attribute("automatedStation"),
+    ) {
+        (Some("CORRECTION"), _) => MetarReportKind::Cor,
+        (_, Some("true")) => MetarReportKind::Auto,
+        _ => MetarReportKind::Normal,
+    };
+
+    let wind = child(obs, "surfaceWind").and_then(|w| child(w, "AerodromeSurfaceWind"));
+    let variable_wind_dir = wind
+        .map(|wind| {
+            let extreme = |name| measure(wind, name, angle);
+            Ok::<_, IwxxmError>(
+                match (
+                    extreme("extremeCounterClockwiseWindDirection")?,
+                    extreme("extremeClockwiseWindDirection")?,
+                ) {
+                    (Some(extreme_ccw), Some(extreme_cw)) => Some(MetarVariableWindDir {
+                        extreme_ccw,
+                        extreme_cw,
+                    }),
+                    _ => None,
+                },
+            )
+        })
+        .transpose()?
+        .flatten();
+    let wind = wind.map(wind_summary).transpose()?.flatten();
+
+    let vis = child(obs, "visibility").and_then(|v| child(v, "AerodromeHorizontalVisibility"));
+    let visibility = vis
+        .map(|vis| measure(vis, "prevailingVisibility", length))
+        .transpose()?
+        .flatten();
+    let minimum_visibility = match vis {
+        Some(vis) => match (
+            measure(vis, "minimumVisibility", length)?,
+            measure(vis, "minimumVisibilityDirection", angle)?,
+        ) {
+            (Some(visibility), Some(direction)) => Some(MetarMinimumVisibility {
+                visibility,
+                direction: compass8(direction),
+            }),
+            _ => None,
+        },
+        None => None,
+    };
+
+    let mut runway_range = vec![];
+    for rvr in children(obs, "rvr").filter_map(|r| child(r, "AerodromeRunwayVisualRange")) {
+        let (Some(runway), Some(range)) = (runway(rvr)?, measure(rvr, "meanRVR", length)?) else {
+            continue;
+        };
+        let trend = match rvr.attribute("pastTendency") {
+            Some("UPWARD") => RunwayTrend::Farther,
+            Some("DOWNWARD") => RunwayTrend::Closer,
+            _ => RunwayTrend::NoChange,
+        };
+        runway_range.push((runway, range, trend));
+    }
+
+    let recent_weather = weather(obs, "recentWeather")?.into_iter().next();
+    let weather = weather(obs, "presentWeather")?;
+    let clouds = clouds(obs, "AerodromeCloud")?;
+
+    let runway_wind_shear = match child(obs, "windShear")
+        .and_then(|w| child(w, "AerodromeWindShear"))
+    {
+        Some(shear) if shear.attribute("allRunways") == Some("true") => Some(RunwayWindShear::All),
+        Some(shear) => {
+            //Runways with wind shear are given by their number as in the text decoder
+            let runways = children(shear, "runway")
+                .map(runway_designator)
+                .collect::<Result<Vec<_>, _>>()?;
+            runways
+                .into_iter()
+                .flatten()
+                .next()
+                .map(|runway| RunwayWindShear::Within(Length::new::<meter>(runway.num as f32)))
+        }
+        None => None,
+    };
+
+    let mut sea = vec![];
+    for condition in children(obs, "seaCondition").filter_map(|s| child(s, "AerodromeSeaCondition"))
+    {
+        let Some(temp) = measure(condition, "seaSurfaceTemperature", temperature)? else {
+            continue;
+        };
+        if let Some(height) = measure(condition, "significantWaveHeight", length)? {
+            sea.push(MetarSeaSurfaceReport::WaveHeight { temp, height });
+        } else if let Some(code) = child(condition, "seaState").and_then(code) {
+            let (_, state) = StateOfTheSea::parse(code)
+                .map_err(|_| IwxxmError::Value("seaState", code.to_owned()))?;
+            sea.push(MetarSeaSurfaceReport::StateOfSea { temp, state });
+        }
+    }
+
+    Ok(Some(MetarReport {
+        country,
+        origin,
+        wind,
+        kind,
+        variable_wind_dir,
+        visibility,
+        minimum_visibility,
+        runway_range,
+        weather,
+        clouds,
+        air_temperature: measure(obs, "airTemperature", temperature)?,
+        dewpoint_temperature: measure(obs, "dewpointTemperature", temperature)?,
+        qnh: measure(obs, "qnh", pressure)?,
+        recent_weather,
+        runway_wind_shear,
+        sea,
+        runway_status: vec![],
+    }))
+}
+
+/// Decode a TAF element, returning `None` if the forecast is missing
+fn taf(report: Node) -> Result<Option<TAFReportItem>, IwxxmError> {
+    let country = aerodrome(report)?;
+    let origin_date = child(report, "issueTime")
+        .and_then(time_instant)
+        .ok_or(IwxxmError::Missing("issueTime"))?;
+    let kind = match report.attribute("reportStatus") {
+        Some("AMENDMENT") => TAFReportKind::Amendment,
+        Some("CORRECTION") => TAFReportKind::Correction,
+        _ => TAFReportKind::Report,
+    };
+
+    let Some(time_range) = child(report, "validPeriod").and_then(time_period) else {
+        return Ok(None);
+    };
+
+    //Cancelled forecasts have no conditions, as with `CNL` in the text format
+    let base = child(report, "baseForecast")
+        .and_then(|base| child(base, "MeteorologicalAerodromeForecast"));
+    let base = match base {
+        Some(base) => Some(forecast(base)?),
+        None if report.attribute("isCancelReport") == Some("true") => None,
+        None => return Ok(None),
+    };
+
+    let mut groups = vec![];
+    for change in children(report, "changeForecast")
+        .filter_map(|change| child(change, "MeteorologicalAerodromeForecast"))
+    {
+        let time = child(change, "phenomenonTime");
+        let (from, to) = time
+            .and_then(time_period)
+            .or_else(|| time.and_then(time_instant).map(|time| (time, time)))
+            .ok_or(IwxxmError::Missing("phenomenonTime"))?;
+        let indicator = change.attribute("changeIndicator").unwrap_or_default();
+        let probability = match indicator.strip_prefix("PROBABILITY_") {
+            Some(prob) => prob
+                .get(..2)
+                .and_then(|prob| prob.parse::<f32>().ok())
+                .ok_or_else(|| IwxxmError::Value("changeIndicator", indicator.to_owned()))?,
+            None => 100f32,
+        };
+
+        let kind = match indicator {
+            "FROM" => TAFReportItemGroupKind::TimeIndicator(from),
+            "BECOMING" => TAFReportItemGroupKind::Change(from, to),
+            i if i.ends_with("TEMPORARY_FLUCTUATIONS") => TAFReportItemGroupKind::TemporaryChange {
+                probability,
+                from,
+                to,
+            },
+            i if i.starts_with("PROBABILITY_") => TAFReportItemGroupKind::Probable {
+                probability,
+                from,
+                to,
+            },
+            i => return Err(IwxxmError::Value("changeIndicator", i.to_owned())),
+        };
+
+        let (wind, visibility, weather, clouds) = forecast(change)?;
+        groups.push(TAFReportItemGroup {
+            kind,
+            wind,
+            visibility,
+            weather,
+            clouds,
+        });
+    }
+
+    let (wind, horizontal_vis, significant_weather, clouds) = base.unwrap_or_default();
+    Ok(Some(TAFReportItem {
+        kind,
+        country,
+        origin_date,
+        time_range,
+        wind,
+        horizontal_vis,
+        significant_weather,
+        clouds,
+        groups,
+    }))
+}
+
+type Forecast = (
+    Option<WindSummary>,
+    Option<Length>,
+    Vec<SignificantWeather>,
+    Vec<CloudReport>,
+);
+
+/// Decode the wind, visibility, weather, and clouds of a base or change forecast
+fn forecast(forecast: Node) -> Result<Forecast, IwxxmError> {
+    let wind = child(forecast, "surfaceWind")
+        .and_then(|wind| child(wind, "AerodromeSurfaceWindForecast"))
+        .map(wind_summary)
+        .transpose()?
+        .flatten();
+
+    Ok((
+        wind,
+        measure(forecast, "prevailingVisibility", length)?,
+        weather(forecast, "weather")?,
+        clouds(forecast, "AerodromeCloudForecast")?,
+    ))
+}
+
+/// Get the ICAO location indicator of the aerodrome a report is for
+fn aerodrome(report: Node) -> Result<CCCC, IwxxmError> {
+    let slice = child(report, "aerodrome")
+        .and_then(|a| child(a, "AirportHeliport"))
+        .and_then(|a| child(a, "timeSlice"))
+        .and_then(|t| child(t, "AirportHeliportTimeSlice"))
+        .ok_or(IwxxmError::Missing("aerodrome"))?;
+    let icao = child(slice, "locationIndicatorICAO")
+        .or_else(|| child(slice, "designator"))
+        .and_then(|node| node.text())
+        .ok_or(IwxxmError::Missing("locationIndicatorICAO"))?;
+
+    match icao.len() {
+        4 => icao
+            .parse()
+            .map_err(|_| IwxxmError::Value("locationIndicatorICAO", icao.to_owned())),
+        _ => Err(IwxxmError::Value("locationIndicatorICAO", icao.to_owned())),
+    }
+}
+
+/// Decode a surface wind, returning `None` if the speed is not given. Variable directions are
+/// given as 0 degrees as with `VRB` in text reports.
+fn wind_summary(wind: Node) -> Result<Option<WindSummary>, IwxxmError> {
+    let Some(speed) = measure(wind, "meanWindSpeed", velocity)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(WindSummary {
+        direction: measure(wind, "meanWindDirection", angle)?.unwrap_or(Angle::new::<degree>(0f32)),
+        speed,
+        max_speed: measure(wind, "windGustSpeed", velocity)?,
+    }))
+}
+
+/// Decode all weather codes given by elements with the given name
+fn weather(node: Node, name: &'static str) -> Result<Vec<SignificantWeather>, IwxxmError> {
+    children(node, name)
+        .filter_map(code)
+        .map(|code| match SignificantWeather::parse(code) {
+            Ok(("", weather)) => Ok(weather),
+            _ => Err(IwxxmError::Value(name, code.to_owned())),
+        })
+        .collect()
+}
+
+/// Decode the cloud layers and vertical visibility of a cloud element
+fn clouds(node: Node, name: &'static str) -> Result<Vec<CloudReport>, IwxxmError> {
+    let Some(cloud) = child(node, "cloud").and_then(|cloud| child(cloud, name)) else {
+        return Ok(vec![]);
+    };
+
+    if let Some(altitude) = measure(cloud, "verticalVisibility", length)? {
+        return Ok(vec![CloudReport {
+            amount: None,
+            altitude,
+            cover: None,
+        }]);
+    }
+
+    let mut clouds = vec![];
+    for layer in children(cloud, "layer").filter_map(|layer| child(layer, "CloudLayer")) {
+        let amount = match child(layer, "amount").and_then(code) {
+            Some("FEW") => CloudAmount::Few,
+            Some("SCT") => CloudAmount::Scattered,
+            Some("BKN") => CloudAmount::Broken,
+            Some("OVC") => CloudAmount::Overcast,
+            Some(other) => return Err(IwxxmError::Value("amount", other.to_owned())),
+            None => continue,
+        };
+        let Some(altitude) = measure(layer, "base", length)? else {
+            continue;
+        };
+        let cover = match child(layer, "cloudType").and_then(code) {
+            Some("CB") => Some(CloudCover::Cumulonimbus),
+            Some("TCU") => Some(CloudCover::ToweringCumulonimbus),
+            _ => None,
+        };
+
+        clouds.push(CloudReport {
+            amount: Some(amount),
+            altitude,
+            cover,
+        });
+    }
+
+    Ok(clouds)
+}
+
+/// Get the runway designator of a runway visual range
+fn runway(rvr: Node) -> Result<Option<RunwayDesignator>, IwxxmError> {
+    child(rvr, "runway")
+        .map(runway_designator)
+        .transpose()
+        .map(Option::flatten)
+}
+
+fn runway_designator(runway: Node) -> Result<Option<RunwayDesignator>, IwxxmError> {
+    let Some(designator) = runway
+        .descendants()
+        .find(|n| n.tag_name().name() == "designator")
+        .and_then(|n| n.text())
+    else {
+        return Ok(None);
+    };
+
+    match RunwayDesignator::parse(designator) {
+        Ok(("", runway)) => Ok(Some(runway)),
+        _ => Err(IwxxmError::Value("designator", designator.to_owned())),
+    }
+}
+
+/// Get the first child element with the given local name
+fn child<'a, 'i>(node: Node<'a, 'i>, name: &'a str) -> Option<Node<'a, 'i>> {
+    children(node, name).next()
+}
+
+fn children<'a, 'i: 'a>(
+    node: Node<'a, 'i>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'i>> + 'a {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Get the child element with the given name, following an `xlink:href` reference to the
+/// element with a matching `gml:id` in the same document as is done for times shared between
+/// elements
+fn resolve<'a, 'i>(node: Node<'a, 'i>, name: &'a str) -> Option<Node<'a, 'i>> {
+    match node
+        .attribute((XLINK, "href"))
+        .and_then(|href| href.strip_prefix('#'))
+    {
+        Some(id) => node
+            .document()
+            .descendants()
+            .find(|n| n.attribute((GML, "id")) == Some(id))
+            .filter(|n| n.tag_name().name() == name),
+        None => child(node, name),
+    }
+}
+
+/// Get the final component of a code list reference like
+/// `http://codes.wmo.int/49-2/CloudAmountReportedAtAerodrome/FEW`
+fn code<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    node.attribute((XLINK, "href"))
+        .and_then(|href| href.rsplit('/').next())
+        .filter(|code| !code.is_empty())
+}
+
+fn timestamp(node: Option<Node>) -> Option<DateTime<Utc>> {
+    node.and_then(|n| n.text())
+        .and_then(|time| DateTime::parse_from_rfc3339(time.trim()).ok())
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn day_hour_minute(time: DateTime<Utc>) -> DayHourMinute {
+    DayHourMinute {
+        day: time.day() as u8,
+        hour: time.hour() as u8,
+        minute: time.minute() as u8,
+    }
+}
+
+/// Get the time of a `gml:TimeInstant` contained or referenced by an element
+fn time_instant(node: Node) -> Option<DayHourMinute> {
+    let instant = resolve(node, "TimeInstant")?;
+    timestamp(child(instant, "timePosition")).map(day_hour_minute)
+}
+
+/// Get the beginning and end of a `gml:TimePeriod` contained or referenced by an element
+fn time_period(node: Node) -> Option<(DayHourMinute, DayHourMinute)> {
+    let period = resolve(node, "TimePeriod")?;
+    let begin = timestamp(child(period, "beginPosition"))?;
+    let end = timestamp(child(period, "endPosition"))?;
+    Some((day_hour_minute(begin), day_hour_minute(end)))
+}
+
+/// Decode the value of a child element with a `uom` attribute, returning `None` if the element
+/// is not present or is given with a `nilReason`
+fn measure<T>(
+    node: Node,
+    name: &'static str,
+    unit: fn(f32, &str) -> Option<T>,
+) -> Result<Option<T>, IwxxmError> {
+    let Some(value) = child(node, name) else {
+        return Ok(None);
+    };
+    let Some(text) = value.text().map(str::trim).filter(|t| !t.is_empty()) else {
+        return Ok(None);
+    };
+
+    let number = text
+        .parse::<f32>()
+        .map_err(|_| IwxxmError::Value(name, text.to_owned()))?;
+    let uom = value.attribute("uom").unwrap_or_default();
+    unit(number, uom)
+        .map(Some)
+        .ok_or_else(|| IwxxmError::Unit(name, uom.to_owned()))
+}
+
+fn length(v: f32, uom: &str) -> Option<Length> {
+    Some(match uom {
+        "m" => Length::new::<meter>(v),
+        "km" => Length::new::<kilometer>(v),
+        "[ft_i]" => Length::new::<foot>(v),
+        _ => return None,
+    })
+}
+
+fn angle(v: f32, uom: &str) -> Option<Angle> {
+    (uom == "deg").then(|| Angle::new::<degree>(v))
+}
+
+fn velocity(v: f32, uom: &str) -> Option<Velocity> {
+    Some(match uom {
+        "[kn_i]" => Velocity::new::<knot>(v),
+        "m/s" => Velocity::new::<meter_per_second>(v),
+        "km/h" => Velocity::new::<kilometer_per_hour>(v),
+        _ => return None,
+    })
+}
+
+fn temperature(v: f32, uom: &str) -> Option<ThermodynamicTemperature> {
+    (uom == "Cel").then(|| ThermodynamicTemperature::new::<degree_celsius>(v))
+}
+
+fn pressure(v: f32, uom: &str) -> Option<Pressure> {
+    (uom == "hPa").then(|| Pressure::new::<hectopascal>(v))
+}
+
+/// Round a direction to the nearest of the eight compass points
+fn compass8(direction: Angle) -> Compass {
+    match ((direction.get::<degree>().rem_euclid(360f32) + 22.5) / 45f32) as u8 {
+        1 => Compass::NorthEast,
+        2 => Compass::East,
+        3 => Compass::SouthEast,
+        4 => Compass::South,
+        5 => Compass::SouthWest,
+        6 => Compass::West,
+        7 => Compass::NorthWest,
+        _ => Compass::North,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_iwxxm() {
+        let bulletin = IwxxmBulletin::parse(EX_METAR).unwrap();
+        assert!(bulletin.header.is_some());
+        assert_eq!(bulletin.metars.len(), 2);
+        assert!(bulletin.tafs.is_empty());
+
+        let (_, text) = MetarReport::parse(
+            "KDEN 181453Z 27015G25KT 240V300 8000 3000E R16L/1200U -SHRA FEW045TCU BKN090 12/M02 \
+            Q1013 RETSRA WS ALL RWY=",
+        )
+        .unwrap_or_else(|e| panic!("{}", crate::display_error(e)));
+        let text = text.unwrap();
+        let kden = &bulletin.metars[0];
+        assert_eq!(kden.country, text.country);
+        assert_eq!(kden.origin, text.origin);
+        assert_eq!(kden.kind, MetarReportKind::Normal);
+        assert_eq!(kden.wind, text.wind);
+        assert_eq!(kden.variable_wind_dir, text.variable_wind_dir);
+        assert_eq!(kden.visibility, text.visibility);
+        assert_eq!(kden.minimum_visibility, text.minimum_visibility);
+        assert_eq!(kden.runway_range, text.runway_range);
+        assert_eq!(kden.weather, text.weather);
+        //Code table 1690 rounds 100 ft to 30 m, where IWXXM gives the height exactly
+        assert_eq!(
+            kden.clouds
+                .iter()
+                .map(|c| (c.amount, c.altitude.get::<foot>().round(), c.cover))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    Some(CloudAmount::Few),
+                    4500f32,
+                    Some(CloudCover::ToweringCumulonimbus)
+                ),
+                (Some(CloudAmount::Broken), 9000f32, None),
+            ]
+        );
+        assert_eq!(kden.air_temperature, text.air_temperature);
+        assert_eq!(kden.dewpoint_temperature, text.dewpoint_temperature);
+        assert_eq!(kden.qnh, text.qnh);
+        assert_eq!(kden.recent_weather, text.recent_weather);
+        assert_eq!(kden.runway_wind_shear, text.runway_wind_shear);
+
+        let kcos = &bulletin.metars[1];
+        assert_eq!(kcos.kind, MetarReportKind::Cor);
+        assert_eq!(
+            kcos.wind.unwrap().speed,
+            Velocity::new::<meter_per_second>(2f32)
+        );
+        assert!(kcos.clouds.is_empty());
+
+        let bulletin = IwxxmBulletin::parse(EX_TAF).unwrap();
+        assert_eq!(bulletin.tafs.len(), 1);
+
+        let (_, text) = TAFReportItem::parse(
+            "TAF AMD KDEN 181720Z 1818/2000 28012G22KT 9999 SCT080
+            PROB30 TEMPO 1821/1901 4800 TSRA BKN060CB
+            FM190300 20008KT=",
+        )
+        .unwrap_or_else(|e| panic!("{}", crate::display_error(e)));
+        let text = text.unwrap();
+        let taf = &bulletin.tafs[0];
+        assert_eq!(taf.kind, text.kind);
+        assert_eq!(taf.country, text.country);
+        assert_eq!(taf.origin_date, text.origin_date);
+        assert_eq!(taf.wind, text.wind);
+        assert_eq!(taf.horizontal_vis, text.horizontal_vis);
+        assert_eq!(taf.groups.len(), 2);
+        assert_eq!(taf.groups[0].kind, text.groups[0].kind);
+        assert_eq!(taf.groups[0].visibility, text.groups[0].visibility);
+        assert_eq!(taf.groups[0].weather, text.groups[0].weather);
+        assert_eq!(taf.groups[1].kind, text.groups[1].kind);
+        assert_eq!(taf.groups[1].wind, text.groups[1].wind);
+    }
+
+    const EX_METAR: &str = include_str!("test/iwxxm_metar.xml");
+    const EX_TAF: &str = include_str!("test/iwxxm_taf.xml");
+}
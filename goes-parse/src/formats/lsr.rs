@@ -0,0 +1,474 @@
+//! Parsing for Local Storm Reports (LSR) in both the single report and summary layouts
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc};
+use nom::{character::complete::multispace1, sequence::terminated};
+use uom::si::{
+    f32::{Angle, Length, Velocity},
+    length::{inch, mile},
+    velocity::{knot, mile_per_hour},
+};
+
+use crate::{
+    formats::{compass16, parse_decimal_latlon},
+    header::WMOProductIdentifier,
+    parse::time::us_time_zone_offset,
+    ParseResult,
+};
+
+/// A local storm report product containing one or more reports of severe weather
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct LocalStormReport {
+    pub header: WMOProductIdentifier,
+    /// Office that issued the product, e.g. `DENVER CO`
+    pub office: String,
+    /// Abbreviation of the time zone that report times are given in, e.g. `MDT`
+    pub time_zone: Option<String>,
+    /// If the product is a summary of multiple reports
+    pub summary: bool,
+    pub reports: Vec<StormReport>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StormReport {
+    /// Time that the event occurred at, converted from the time zone of the product
+    pub time: DateTime<Utc>,
+    pub event: StormEvent,
+    pub location: StormReportLocation,
+    pub county: String,
+    pub state: Option<String>,
+    pub lat: Angle,
+    pub lon: Angle,
+    pub magnitude: Option<StormReportMagnitude>,
+    /// Source of the report, e.g. `TRAINED SPOTTER`
+    pub source: String,
+    pub remarks: String,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StormEvent {
+    Tornado,
+    FunnelCloud,
+    Waterspout,
+    Hail,
+    ThunderstormWindGust,
+    ThunderstormWindDamage,
+    NonThunderstormWindGust,
+    NonThunderstormWindDamage,
+    FlashFlood,
+    Flood,
+    HeavyRain,
+    Snow,
+    HeavySnow,
+    Blizzard,
+    FreezingRain,
+    Sleet,
+    Lightning,
+    DustStorm,
+    Other(String),
+}
+
+/// Place that an event occurred, given as a distance and direction from a named place
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StormReportLocation {
+    pub distance: Option<Length>,
+    pub direction: Option<Angle>,
+    pub place: String,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StormReportMagnitude {
+    pub qualifier: Option<MagnitudeQualifier>,
+    pub value: StormMagnitude,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MagnitudeQualifier {
+    Measured,
+    Estimated,
+    Unknown,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum StormMagnitude {
+    /// Hail size or precipitation and snowfall amounts
+    Length(Length),
+    /// Wind gust speeds
+    Velocity(Velocity),
+    /// Damage rating such as `EF1`
+    Rating(String),
+    Other(String),
+}
+
+/// Columns of the report table found from the `..TIME...` and `..DATE...` header lines
+#[derive(Clone, Copy, Debug)]
+struct Columns {
+    event: usize,
+    city: usize,
+    latlon: usize,
+    magnitude: usize,
+    county: usize,
+    source: usize,
+}
+
+impl LocalStormReport {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut office = String::new();
+        let mut time_zone = None;
+        let mut summary = false;
+        let mut reports = vec![];
+
+        let mut columns: Option<Columns> = None;
+        let mut first_row: Option<&str> = None;
+
+        let mut lines = input.lines().map(str::trim_end).peekable();
+        while let Some(line) = lines.next() {
+            if line.starts_with("&&") || line.starts_with("$$") {
+                break;
+            }
+
+            let columns = match columns {
+                Some(columns) => columns,
+                None => {
+                    if line.contains("LOCAL STORM REPORT") {
+                        summary = line.contains("SUMMARY");
+                    } else if let Some(name) = line.strip_prefix("NATIONAL WEATHER SERVICE ") {
+                        office = name.trim().to_owned();
+                        //The issuance time line follows the office
+                        time_zone = lines
+                            .peek()
+                            .and_then(|l| l.split_whitespace().nth(2))
+                            .map(str::to_owned);
+                    } else if line.starts_with("..TIME...") {
+                        let date = lines.next().unwrap_or_default();
+                        columns = Columns::parse(line, date);
+                    }
+                    continue;
+                }
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(row) = first_row.take() {
+                let offset = time_zone.as_deref().and_then(us_time_zone_offset);
+                match offset.and_then(|offset| StormReport::parse(columns, offset, row, line)) {
+                    Some(report) => reports.push(report),
+                    None => log::warn!("Failed to parse storm report:\n{}\n{}", row, line),
+                }
+                continue;
+            }
+
+            if is_time(line) {
+                first_row = Some(line);
+            } else if let Some(report) = reports.last_mut() {
+                if !report.remarks.is_empty() {
+                    report.remarks.push(' ');
+                }
+                report.remarks.push_str(line.trim());
+            }
+        }
+
+        Ok((
+            "",
+            Self {
+                header,
+                office,
+                time_zone,
+                summary,
+                reports,
+            },
+        ))
+    }
+}
+
+/// Check if a line begins with a report time like `0405 PM`
+fn is_time(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    bytes.len() > 7
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && matches!(line.get(5..7), Some("AM" | "PM"))
+}
+
+impl Columns {
+    fn parse(time: &str, date: &str) -> Option<Self> {
+        Some(Self {
+            event: time.find("...EVENT")?,
+            city: time.find("...CITY")?,
+            latlon: time.find("...LAT")?,
+            magnitude: date.find("....MAG")?,
+            county: date.find("..COUNTY")?,
+            source: date.find("...SOURCE")?,
+        })
+    }
+}
+
+/// Get the trimmed text between two columns, stopping at the end of the line
+fn cell(line: &str, start: usize, end: Option<usize>) -> &str {
+    let end = end.unwrap_or(line.len()).min(line.len());
+    line.get(start.min(end)..end).unwrap_or_default().trim()
+}
+
+impl StormReport {
+    /// Parse a report from the two lines of the report table, with times given at `offset`
+    fn parse(columns: Columns, offset: FixedOffset, first: &str, second: &str) -> Option<Self> {
+        let time =
+            NaiveTime::parse_from_str(cell(first, 0, Some(columns.event)), "%I%M %p").ok()?;
+        let date =
+            NaiveDate::parse_from_str(cell(second, 0, Some(columns.magnitude)), "%m/%d/%Y").ok()?;
+
+        let event = StormEvent::from(cell(first, columns.event, Some(columns.city)));
+        let location = StormReportLocation::parse(cell(first, columns.city, Some(columns.latlon)));
+        let (_, (lat, lon)) = parse_decimal_latlon(cell(first, columns.latlon, None)).ok()?;
+
+        let magnitude =
+            StormReportMagnitude::parse(cell(second, columns.magnitude, Some(columns.county)));
+
+        //The state is given at the end of the county column
+        let county = cell(second, columns.county, Some(columns.source));
+        let (county, state) = match county.rsplit_once(' ') {
+            Some((county, state))
+                if state.len() == 2 && state.chars().all(|c| c.is_ascii_uppercase()) =>
+            {
+                (county.trim(), Some(state.to_owned()))
+            }
+            _ => (county, None),
+        };
+
+        let time = offset
+            .from_local_datetime(&date.and_time(time))
+            .single()?
+            .with_timezone(&Utc);
+
+        Some(Self {
+            time,
+            event,
+            location,
+            county: county.to_owned(),
+            state,
+            lat,
+            lon,
+            magnitude,
+            source: cell(second, columns.source, None).to_owned(),
+            remarks: String::new(),
+        })
+    }
+}
+
+impl From<&str> for StormEvent {
+    fn from(value: &str) -> Self {
+        match value {
+            "TORNADO" => Self::Tornado,
+            "FUNNEL CLOUD" => Self::FunnelCloud,
+            "WATERSPOUT" => Self::Waterspout,
+            "HAIL" | "MARINE HAIL" => Self::Hail,
+            "TSTM WND GST" | "MARINE TSTM WIND" => Self::ThunderstormWindGust,
+            "TSTM WND DMG" => Self::ThunderstormWindDamage,
+            "NON-TSTM WND GST" | "HIGH SUST WINDS" => Self::NonThunderstormWindGust,
+            "NON-TSTM WND DMG" => Self::NonThunderstormWindDamage,
+            "FLASH FLOOD" => Self::FlashFlood,
+            "FLOOD" | "COASTAL FLOOD" => Self::Flood,
+            "HEAVY RAIN" | "RAIN" => Self::HeavyRain,
+            "SNOW" => Self::Snow,
+            "HEAVY SNOW" => Self::HeavySnow,
+            "BLIZZARD" => Self::Blizzard,
+            "FREEZING RAIN" | "ICE STORM" => Self::FreezingRain,
+            "SLEET" => Self::Sleet,
+            "LIGHTNING" => Self::Lightning,
+            "DUST STORM" => Self::DustStorm,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+}
+
+impl StormReportLocation {
+    /// Parse a location like `3 SSE LIMON`
+    fn parse(location: &str) -> Self {
+        let mut words = location.splitn(3, ' ');
+        if let (Some(distance), Some(direction), Some(place)) =
+            (words.next(), words.next(), words.next())
+        {
+            if let (Ok(distance), Ok(direction)) = (distance.parse::<f32>(), compass16(direction)) {
+                return Self {
+                    distance: Some(Length::new::<mile>(distance)),
+                    direction: Some(direction),
+                    place: place.trim().to_owned(),
+                };
+            }
+        }
+
+        Self {
+            distance: None,
+            direction: None,
+            place: location.to_owned(),
+        }
+    }
+}
+
+impl StormReportMagnitude {
+    /// Parse a magnitude like `M1.75 INCH`, returning `None` if no magnitude is given
+    fn parse(magnitude: &str) -> Option<Self> {
+        if magnitude.is_empty() {
+            return None;
+        }
+
+        let mut chars = magnitude.chars();
+        let qualifier = match chars.next() {
+            Some('M') => Some(MagnitudeQualifier::Measured),
+            Some('E') => Some(MagnitudeQualifier::Estimated),
+            Some('U') => Some(MagnitudeQualifier::Unknown),
+            _ => None,
+        };
+
+        let rest = chars.as_str();
+        let (qualifier, value) = match qualifier {
+            Some(qualifier) if rest.starts_with(|c: char| c.is_ascii_digit() || c == '.') => {
+                (Some(qualifier), rest)
+            }
+            _ => (None, magnitude),
+        };
+
+        let (number, unit) = value.split_once(' ').unwrap_or((value, ""));
+        let value = match (number.parse::<f32>(), unit.trim()) {
+            (Ok(v), "INCH" | "INCHES" | "IN") => StormMagnitude::Length(Length::new::<inch>(v)),
+            (Ok(v), "MPH") => StormMagnitude::Velocity(Velocity::new::<mile_per_hour>(v)),
+            (Ok(v), "KT" | "KTS") => StormMagnitude::Velocity(Velocity::new::<knot>(v)),
+            _ if magnitude.starts_with("EF") || magnitude.starts_with("F") => {
+                StormMagnitude::Rating(magnitude.to_owned())
+            }
+            _ => StormMagnitude::Other(magnitude.to_owned()),
+        };
+
+        Some(Self { qualifier, value })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use uom::si::angle::degree;
+
+    use super::*;
+
+    #[test]
+    pub fn test_lsr() {
+        let lsr = LocalStormReport::parse(EX_LSR)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(lsr.office, "DENVER CO");
+        assert_eq!(lsr.time_zone.as_deref(), Some("MDT"));
+        assert!(!lsr.summary);
+        assert_eq!(lsr.reports.len(), 1);
+
+        let hail = &lsr.reports[0];
+        assert_eq!(
+            hail.time,
+            Utc.with_ymd_and_hms(2026, 10, 18, 22, 5, 0).unwrap()
+        );
+        assert_eq!(hail.event, StormEvent::Hail);
+        assert_eq!(
+            hail.location,
+            StormReportLocation {
+                distance: Some(Length::new::<mile>(3f32)),
+                direction: Some(Angle::new::<degree>(157.5)),
+                place: "LIMON".to_owned(),
+            }
+        );
+        assert_eq!(hail.county, "LINCOLN");
+        assert_eq!(hail.state.as_deref(), Some("CO"));
+        assert_eq!(hail.lat, Angle::new::<degree>(39.22));
+        assert_eq!(hail.lon, Angle::new::<degree>(-103.67));
+        assert_eq!(
+            hail.magnitude,
+            Some(StormReportMagnitude {
+                qualifier: Some(MagnitudeQualifier::Measured),
+                value: StormMagnitude::Length(Length::new::<inch>(1.75)),
+            })
+        );
+        assert_eq!(hail.source, "TRAINED SPOTTER");
+        assert_eq!(hail.remarks, "HAIL COVERED THE GROUND ALONG HIGHWAY 24.");
+    }
+
+    #[test]
+    pub fn test_lsr_summary() {
+        let lsr = LocalStormReport::parse(EX_LSR_SUMMARY)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert!(lsr.summary);
+        assert_eq!(lsr.reports.len(), 8);
+
+        let tornado = &lsr.reports[0];
+        assert_eq!(tornado.event, StormEvent::Tornado);
+        assert_eq!(tornado.magnitude, None);
+        assert_eq!(
+            tornado.remarks,
+            "BRIEF TORNADO TOUCHDOWN IN AN OPEN FIELD. NO DAMAGE REPORTED."
+        );
+
+        assert_eq!(
+            lsr.reports[1].magnitude.as_ref().map(|m| &m.value),
+            Some(&StormMagnitude::Velocity(Velocity::new::<mile_per_hour>(
+                62f32
+            )))
+        );
+        assert_eq!(lsr.reports[2].county, "LA SALLE");
+        assert_eq!(lsr.reports[3].remarks, "");
+        assert_eq!(
+            lsr.reports[3].magnitude.as_ref().and_then(|m| m.qualifier),
+            Some(MagnitudeQualifier::Estimated)
+        );
+        assert_eq!(lsr.reports[4].event, StormEvent::FlashFlood);
+        assert_eq!(
+            lsr.reports[6].magnitude.as_ref().map(|m| &m.value),
+            Some(&StormMagnitude::Rating("EF1".to_owned()))
+        );
+
+        let gust = &lsr.reports[7];
+        assert_eq!(gust.event, StormEvent::NonThunderstormWindGust);
+        assert_eq!(gust.location.place, "O'HARE AIRPORT");
+        assert_eq!(gust.location.distance, None);
+        assert_eq!(
+            gust.time.time(),
+            NaiveTime::from_hms_opt(16, 50, 0).unwrap()
+        );
+        assert_eq!(
+            gust.magnitude.as_ref().map(|m| &m.value),
+            Some(&StormMagnitude::Velocity(Velocity::new::<knot>(48f32)))
+        );
+    }
+
+    #[test]
+    pub fn test_magnitude() {
+        assert_eq!(
+            StormReportMagnitude::parse("E60 MPH"),
+            Some(StormReportMagnitude {
+                qualifier: Some(MagnitudeQualifier::Estimated),
+                value: StormMagnitude::Velocity(Velocity::new::<mile_per_hour>(60f32)),
+            })
+        );
+        assert_eq!(
+            StormReportMagnitude::parse("½ INCH"),
+            Some(StormReportMagnitude {
+                qualifier: None,
+                value: StormMagnitude::Other("½ INCH".to_owned()),
+            })
+        );
+        assert_eq!(
+            StormReportMagnitude::parse("EF1").map(|m| m.value),
+            Some(StormMagnitude::Rating("EF1".to_owned()))
+        );
+    }
+
+    const EX_LSR: &str = include_str!("test/lsr.txt");
+    const EX_LSR_SUMMARY: &str = include_str!("test/lsr_summary.txt");
+}
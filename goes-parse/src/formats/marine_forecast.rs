@@ -0,0 +1,394 @@
+//! Parsing for coastal waters, offshore, and nearshore marine forecasts (CWF, OFF, and NSH), with
+//! the wind and sea conditions of each forecast period decoded from the text
+
+use uom::si::{
+    f32::{Angle, Length, Time, Velocity},
+    length::foot,
+    time::second,
+    velocity::knot,
+};
+
+use crate::{
+    formats::{
+        compass16,
+        text_product::{TextProduct, VtecAction, VtecSignificance},
+        warning::WarningText,
+        zfp::{product_issuance, ZoneForecast},
+    },
+    ParseResult,
+};
+
+/// A marine forecast product with the forecast for each group of marine zones
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct MarineForecastProduct {
+    pub product: TextProduct,
+    /// Identifier of the issuing office, i.e. `SEW`
+    pub office: Option<String>,
+    pub forecasts: Vec<MarineZoneForecast>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarineZoneForecast {
+    /// Marine zones, periods, and synopsis of the segment
+    pub forecast: ZoneForecast,
+    /// Headlines of the segment, i.e. `SMALL CRAFT ADVISORY IN EFFECT THROUGH LATE TONIGHT`
+    pub headlines: Vec<String>,
+    /// If a small craft advisory is in effect according to the VTEC or headlines of the segment
+    pub small_craft_advisory: bool,
+    /// Conditions decoded from the text of each forecast period, in the same order as the periods
+    pub conditions: Vec<MarineConditions>,
+}
+
+/// Wind and sea conditions given in the text of a forecast period
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MarineConditions {
+    pub wind: Option<MarineWind>,
+    pub seas: Option<WaveHeight>,
+    pub wind_waves: Option<WaveHeight>,
+    pub swell: Vec<Swell>,
+}
+
+/// Wind like `SW wind 15 to 20 kt with gusts up to 25 kt`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarineWind {
+    /// Direction the wind is blowing from, unset for variable winds
+    pub direction: Option<Angle>,
+    pub low: Velocity,
+    pub high: Velocity,
+    pub gusts: Option<Velocity>,
+}
+
+/// A range of wave heights like `Seas 4 to 6 ft at 9 seconds` or `Wind waves 2 ft or less`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WaveHeight {
+    pub low: Length,
+    pub high: Length,
+    /// Dominant wave period
+    pub period: Option<Time>,
+}
+
+/// Swell like `W swell 5 ft at 11 seconds`, or a wave train like `W 5 ft at 9 seconds` given
+/// after `Wave Detail:`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Swell {
+    /// Direction the swell is coming from
+    pub direction: Option<Angle>,
+    pub height: Length,
+    pub period: Option<Time>,
+}
+
+impl MarineForecastProduct {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, product) = TextProduct::parse(input)?;
+        let office = product.office_id().map(str::to_owned);
+        let issued = product_issuance(&product);
+
+        let forecasts = product
+            .segments
+            .iter()
+            .filter_map(|segment| {
+                let forecast = ZoneForecast::from_segment(segment, issued)?;
+                let headlines = WarningText::from_segment(segment).headlines;
+
+                let small_craft_advisory = segment.vtec.iter().any(|vtec| {
+                    vtec.phenomenon == "SC"
+                        && vtec.significance == VtecSignificance::Advisory
+                        && !matches!(vtec.action, VtecAction::Cancelled | VtecAction::Expired)
+                }) || headlines.iter().any(|headline| {
+                    headline.contains("SMALL CRAFT ADVISORY")
+                        && !headline.contains("CANCEL")
+                        && !headline.contains("EXPIRE")
+                });
+
+                let conditions = forecast
+                    .periods
+                    .iter()
+                    .map(|period| MarineConditions::parse(&period.text))
+                    .collect();
+
+                Some(MarineZoneForecast {
+                    forecast,
+                    headlines,
+                    small_craft_advisory,
+                    conditions,
+                })
+            })
+            .collect();
+
+        Ok((
+            input,
+            Self {
+                product,
+                office,
+                forecasts,
+            },
+        ))
+    }
+}
+
+impl MarineConditions {
+    /// Decode the first wind, seas, and wind waves given in the sentences of a forecast period,
+    /// along with all swell and the wave trains of `Wave Detail:` sentences
+    pub fn parse(text: &str) -> Self {
+        let mut conditions = Self::default();
+
+        for sentence in text.split('.') {
+            let words = sentence
+                .split_whitespace()
+                .map(|word| word.trim_end_matches(','))
+                .collect::<Vec<_>>();
+            let word = |i: usize, expected: &str| {
+                words
+                    .get(i)
+                    .is_some_and(|word| word.eq_ignore_ascii_case(expected))
+            };
+            let direction = |i: Option<usize>| i.and_then(|i| compass16(words[i]).ok());
+
+            if word(0, "wave") && word(1, "detail:") {
+                //Wave trains like `W 5 ft at 9 seconds and SW 2 ft at 5 seconds`
+                for train in words[2..].split(|word| word.eq_ignore_ascii_case("and")) {
+                    if let Some((_, height)) = train.get(1..).and_then(|w| range(w, "ft")) {
+                        conditions.swell.push(Swell {
+                            direction: train.first().and_then(|d| compass16(d).ok()),
+                            height: Length::new::<foot>(height),
+                            period: period(train),
+                        });
+                    }
+                }
+            } else if word(0, "seas") {
+                conditions.seas = conditions.seas.or_else(|| wave_height(&words[1..]));
+            } else if word(0, "wind") && word(1, "waves") {
+                conditions.wind_waves = conditions.wind_waves.or_else(|| wave_height(&words[2..]));
+            } else if let Some(i) = (0..words.len()).find(|i| word(*i, "swell")) {
+                if let Some((_, height)) = range(&words[i + 1..], "ft") {
+                    conditions.swell.push(Swell {
+                        direction: direction(i.checked_sub(1)),
+                        height: Length::new::<foot>(height),
+                        period: period(&words[i + 1..]),
+                    });
+                }
+            } else if let Some(i) = (0..words.len()).find(|i| word(*i, "wind") || word(*i, "winds"))
+            {
+                if conditions.wind.is_some() {
+                    continue;
+                }
+
+                let gusts = (0..words.len())
+                    .find(|i| word(*i, "gusts"))
+                    .and_then(|i| words[i..].iter().find_map(|w| w.parse::<f32>().ok()));
+                conditions.wind = range(&words[i + 1..], "kt").map(|(low, high)| MarineWind {
+                    direction: direction(i.checked_sub(1)),
+                    low: Velocity::new::<knot>(low),
+                    high: Velocity::new::<knot>(high),
+                    gusts: gusts.map(Velocity::new::<knot>),
+                });
+            }
+        }
+
+        conditions
+    }
+}
+
+/// Parse a range of heights in feet followed by an optional period
+fn wave_height(words: &[&str]) -> Option<WaveHeight> {
+    let (low, high) = range(words, "ft")?;
+    Some(WaveHeight {
+        low: Length::new::<foot>(low),
+        high: Length::new::<foot>(high),
+        period: period(words),
+    })
+}
+
+/// Parse a range of values at the start of `words` like `15 to 20 kt`, `10 kt`, `2 ft or less`,
+/// or `less than 5 kt`
+fn range(words: &[&str], unit: &str) -> Option<(f32, f32)> {
+    let number = |i: usize| words.get(i).and_then(|w| w.parse::<f32>().ok());
+    let word = |i: usize, expected: &str| {
+        words
+            .get(i)
+            .is_some_and(|w| w.eq_ignore_ascii_case(expected))
+    };
+    let unit = |i: usize| {
+        words
+            .get(i)
+            .and_then(|w| w.get(..unit.len()))
+            .is_some_and(|w| w.eq_ignore_ascii_case(unit))
+    };
+
+    if word(0, "less") && word(1, "than") && unit(3) {
+        Some((0f32, number(2)?))
+    } else if word(1, "to") && unit(3) {
+        Some((number(0)?, number(2)?))
+    } else if word(2, "or") && word(3, "less") && unit(1) {
+        Some((0f32, number(0)?))
+    } else if unit(1) {
+        Some((number(0)?, number(0)?))
+    } else {
+        None
+    }
+}
+
+/// Find a period given as `at 9 seconds`
+fn period(words: &[&str]) -> Option<Time> {
+    words.windows(3).find_map(|window| match window {
+        [at, seconds, unit]
+            if at.eq_ignore_ascii_case("at") && unit.eq_ignore_ascii_case("seconds") =>
+        {
+            seconds.parse().ok().map(Time::new::<second>)
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+    use uom::si::angle::degree;
+
+    use super::*;
+
+    #[test]
+    pub fn test_marine_forecast() {
+        let cwf = MarineForecastProduct::parse(EX_CWF)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(cwf.office.as_deref(), Some("SEW"));
+        assert_eq!(cwf.forecasts.len(), 3);
+
+        let synopsis = &cwf.forecasts[0];
+        assert_eq!(synopsis.forecast.zones, ["PZZ100"]);
+        assert_eq!(
+            synopsis.forecast.synopsis.as_deref(),
+            Some(
+                "A front will move through the waters tonight. High pressure will build over \
+                the waters Monday."
+            )
+        );
+        assert!(synopsis.forecast.periods.is_empty());
+
+        let coastal = &cwf.forecasts[1];
+        assert_eq!(coastal.forecast.zones, ["PZZ150", "PZZ153"]);
+        assert!(coastal.small_craft_advisory);
+        assert_eq!(
+            coastal.headlines,
+            ["SMALL CRAFT ADVISORY IN EFFECT THROUGH LATE TONIGHT"]
+        );
+        let monday = &coastal.forecast.periods[2];
+        assert_eq!(monday.name, "MON");
+        assert_eq!(
+            monday.start,
+            Utc.with_ymd_and_hms(2026, 10, 19, 13, 0, 0).single()
+        );
+
+        assert_eq!(
+            coastal.conditions[0],
+            MarineConditions {
+                wind: Some(MarineWind {
+                    direction: Some(Angle::new::<degree>(225f32)),
+                    low: Velocity::new::<knot>(15f32),
+                    high: Velocity::new::<knot>(20f32),
+                    gusts: Some(Velocity::new::<knot>(25f32)),
+                }),
+                seas: Some(WaveHeight {
+                    low: Length::new::<foot>(4f32),
+                    high: Length::new::<foot>(6f32),
+                    period: None,
+                }),
+                wind_waves: None,
+                swell: vec![
+                    Swell {
+                        direction: Some(Angle::new::<degree>(270f32)),
+                        height: Length::new::<foot>(5f32),
+                        period: Some(Time::new::<second>(9f32)),
+                    },
+                    Swell {
+                        direction: Some(Angle::new::<degree>(225f32)),
+                        height: Length::new::<foot>(2f32),
+                        period: Some(Time::new::<second>(5f32)),
+                    }
+                ],
+            }
+        );
+        assert_eq!(
+            coastal.conditions[1].wind.unwrap().high,
+            Velocity::new::<knot>(25f32)
+        );
+
+        let monday = &coastal.conditions[2];
+        assert_eq!(
+            monday.wind_waves,
+            Some(WaveHeight {
+                low: Length::new::<foot>(0f32),
+                high: Length::new::<foot>(2f32),
+                period: None,
+            })
+        );
+        assert_eq!(
+            monday.swell,
+            [Swell {
+                direction: Some(Angle::new::<degree>(270f32)),
+                height: Length::new::<foot>(5f32),
+                period: Some(Time::new::<second>(11f32)),
+            }]
+        );
+
+        let strait = &cwf.forecasts[2];
+        assert!(!strait.small_craft_advisory);
+        let wind = strait.conditions[0].wind.unwrap();
+        assert_eq!(wind.direction, None);
+        assert_eq!(wind.low, Velocity::new::<knot>(0f32));
+        assert_eq!(wind.high, Velocity::new::<knot>(5f32));
+    }
+
+    #[test]
+    pub fn test_upper_case_conditions() {
+        let conditions =
+            MarineConditions::parse("SW WINDS 15 TO 20 KT. SEAS 4 TO 6 FT AT 9 SECONDS.");
+        assert_eq!(
+            conditions.wind,
+            Some(MarineWind {
+                direction: Some(Angle::new::<degree>(225f32)),
+                low: Velocity::new::<knot>(15f32),
+                high: Velocity::new::<knot>(20f32),
+                gusts: None,
+            })
+        );
+        assert_eq!(
+            conditions.seas,
+            Some(WaveHeight {
+                low: Length::new::<foot>(4f32),
+                high: Length::new::<foot>(6f32),
+                period: Some(Time::new::<second>(9f32)),
+            })
+        );
+
+        let conditions = MarineConditions::parse("SEAS 3 FT. WAVE DETAIL: NW 3 FT AT 12 SECONDS.");
+        assert_eq!(
+            conditions.swell,
+            [Swell {
+                direction: Some(Angle::new::<degree>(315f32)),
+                height: Length::new::<foot>(3f32),
+                period: Some(Time::new::<second>(12f32)),
+            }]
+        );
+
+        let conditions = MarineConditions::parse("WIND WAVES 2 FT OR LESS. WINDS LESS THAN 5 KT.");
+        assert_eq!(
+            conditions.wind_waves.map(|w| (w.low, w.high)),
+            Some((Length::new::<foot>(0f32), Length::new::<foot>(2f32)))
+        );
+        assert_eq!(
+            conditions.wind.map(|w| w.high),
+            Some(Velocity::new::<knot>(5f32))
+        );
+    }
+
+    const EX_CWF: &str = include_str!("test/cwf.txt");
+}
@@ -54,7 +54,7 @@ pub struct EmwinMetarReport {
 
 /// A single METAR weather report parsed from a FM 15/16 report
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct MetarReport {
     pub country: CCCC,
     pub origin: DayHourMinute,
@@ -76,7 +76,7 @@ pub struct MetarReport {
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum MetarSeaSurfaceReport {
     StateOfSea {
         temp: ThermodynamicTemperature,
@@ -90,7 +90,7 @@ pub enum MetarSeaSurfaceReport {
 
 /// Wind shear report with segment of runway affected
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum RunwayWindShear {
     Within(Length),
     All,
@@ -107,7 +107,7 @@ pub enum RunwayTrend {
 
 /// Directions that variables winds blow between in a METAR report
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MetarVariableWindDir {
     pub extreme_ccw: Angle,
     pub extreme_cw: Angle,
@@ -115,7 +115,7 @@ pub struct MetarVariableWindDir {
 
 /// Optional METAR report specifying the direction and length of minimum horizontal visibility
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MetarMinimumVisibility {
     pub visibility: Length,
     pub direction: Compass,
@@ -125,13 +125,15 @@ pub struct MetarMinimumVisibility {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MetarReportKind {
+    /// Manned report without a kind token
+    Normal,
     Auto,
     Cor,
 }
 
 /// Reported runway contamination status
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct RunwayState {
     pub runway: RunwayDesignator,
     pub deposits: RunwayDeposits,
@@ -208,7 +210,7 @@ impl MetarReport {
             ))(input)?
             {
                 (input, Some(Some(k))) => (input, k),
-                (input, None) => (input, MetarReportKind::Auto),
+                (input, None) => (input, MetarReportKind::Normal),
                 (input, Some(None)) => return Ok((input, None)),
             },
         };
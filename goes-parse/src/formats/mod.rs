@@ -3,21 +3,59 @@ use std::str::FromStr;
 use nom::{
     branch::alt,
     bytes::complete::take,
-    character::streaming::char,
-    combinator::{map_res, opt},
+    character::{
+        complete::{digit1, space1},
+        streaming::char,
+    },
+    combinator::{map_res, opt, recognize},
+    sequence::{preceded, tuple},
     Parser,
 };
 
-use crate::{parse::fromstr_n, ParseResult};
+use nom_supreme::tag::complete::tag;
+use uom::si::{
+    angle::degree,
+    f32::{Angle, Pressure},
+    pressure::hectopascal,
+};
+
+use crate::{
+    parse::{fromstr_n, fromstr_with},
+    ParseResult,
+};
 
+pub mod afd;
 pub mod amdar;
+pub mod buoy;
+pub mod cli;
+pub mod climat;
+pub mod coded_surface;
 pub mod codes;
+pub mod encode;
+pub mod explain;
+#[cfg(feature = "iwxxm")]
+pub mod iwxxm;
+pub mod lsr;
+pub mod marine_forecast;
 pub mod metar;
+pub mod mos;
+pub mod pfm;
+pub mod rtp;
 pub mod rwr;
+pub mod shef;
+pub mod ship;
+pub mod spc;
+pub mod swpc;
 pub mod taf;
+pub mod tcm;
+pub mod text_product;
+pub mod warning;
+pub mod winds_aloft;
+pub mod zfp;
 
 /// A runway designator containing runway number and approach direction
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct RunwayDesignator {
     pub num: u8,
     pub dir: Option<RunwayDesignatorDirection>,
@@ -25,6 +63,7 @@ pub struct RunwayDesignator {
 
 /// Letter appended to a two-digit runway designator indicating direction of approach for parallel
 /// runways
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RunwayDesignatorDirection {
     Left,
@@ -33,6 +72,7 @@ pub enum RunwayDesignatorDirection {
 }
 
 /// Eight directions of a compass needle used for rough geographic angles
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Compass {
     North,
@@ -54,12 +94,93 @@ pub fn parse_degreesminutes<const D: usize>(input: &str) -> ParseResult<&str, f3
     Ok((input, degrees + minutes / 60f32))
 }
 
+/// Convert a 16-point compass direction to an angle from north
+pub fn compass16(dir: &str) -> Result<Angle, InvalidCompassDirection> {
+    const POINTS: [&str; 16] = [
+        "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW",
+        "NW", "NNW",
+    ];
+
+    POINTS
+        .iter()
+        .position(|p| *p == dir)
+        .map(|idx| Angle::new::<degree>(idx as f32 * 22.5f32))
+        .ok_or(InvalidCompassDirection)
+}
+
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+#[error("invalid compass direction")]
+pub struct InvalidCompassDirection;
+
+/// Check if a line is a UGC zone line like `WAZ507-509-555>559-231900-`
+pub(crate) fn is_ugc(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    line.ends_with('-')
+        && bytes.len() > 6
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && matches!(bytes[2], b'Z' | b'C')
+        && bytes[3..6].iter().all(u8::is_ascii_digit)
+}
+
+/// Split a line of a fixed-width table into whitespace separated cells along with the offset of
+/// the end of each cell, used to match right-aligned cells to their column headers
+pub(crate) fn column_cells(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split(' ')
+        .scan(0usize, |offset, cell| {
+            let start = *offset;
+            *offset += cell.len() + 1;
+            Some((start + cell.len(), cell))
+        })
+        .filter(|(_, cell)| !cell.is_empty())
+}
+
+/// Parse the digits at `range` of a WMO numeric code group, returning `None` if the field is
+/// out of bounds or reported as missing with solidi
+pub(crate) fn code_field<T: FromStr>(group: &str, range: std::ops::Range<usize>) -> Option<T> {
+    group
+        .get(range)
+        .filter(|field| !field.contains('/'))?
+        .parse()
+        .ok()
+}
+
+/// Parse a signed value in tenths from a code group, where the sign digit at `start` is `0` for
+/// positive and `1` for negative values and is followed by `len` digits
+pub(crate) fn code_signed_tenths(group: &str, start: usize, len: usize) -> Option<f32> {
+    let value = code_field::<f32>(group, start + 1..start + 1 + len)? / 10f32;
+    match group.get(start..start + 1)? {
+        "0" => Some(value),
+        "1" => Some(-value),
+        _ => None,
+    }
+}
+
+/// Parse a pressure from the last four digits of a code group, given in tenths of hPa with the
+/// thousands digit omitted
+pub(crate) fn code_pressure(group: &str) -> Option<Pressure> {
+    let tenths = code_field::<f32>(group, 1..5)?;
+    Some(Pressure::new::<hectopascal>(match tenths < 5000f32 {
+        true => tenths / 10f32 + 1000f32,
+        false => tenths / 10f32,
+    }))
+}
+
+/// A point on the surface of the earth given in degrees north and east
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LatLon {
+    pub lat: Angle,
+    pub lon: Angle,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LatitudeDir {
     North,
     South,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LongitudeDir {
     East,
@@ -112,6 +233,24 @@ impl LongitudeDir {
     }
 }
 
+/// Parse a decimal latitude and longitude pair with hemisphere suffixes like `25.6N  84.3W`,
+/// returning degrees north and east
+pub fn parse_decimal_latlon(input: &str) -> ParseResult<&str, (Angle, Angle)> {
+    fn decimal(input: &str) -> ParseResult<&str, f32> {
+        fromstr_with(recognize(tuple((digit1, opt(tuple((tag("."), digit1)))))))(input)
+    }
+
+    tuple((
+        tuple((decimal, map_res(take(1usize), |c: &str| c.parse::<LatitudeDir>())))
+            .map(|(lat, dir)| Angle::new::<degree>(dir.to_north(lat))),
+        preceded(
+            space1,
+            tuple((decimal, map_res(take(1usize), |c: &str| c.parse::<LongitudeDir>()))),
+        )
+        .map(|(lon, dir)| Angle::new::<degree>(dir.to_east(lon))),
+    ))(input)
+}
+
 impl RunwayDesignator {
     /// Parse a runway designator from the DrDr{L,C,R} format
     pub fn parse(input: &str) -> ParseResult<&str, Self> {
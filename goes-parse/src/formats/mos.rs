@@ -0,0 +1,430 @@
+//! Parsing for Model Output Statistics (MOS) guidance bulletins, both short range (FOUS) with
+//! an `HR` row of hours and extended range (FEUS) with an `FHR` row of projections
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Timelike, Utc};
+use nom::{
+    bytes::complete::take_until,
+    character::complete::{alphanumeric1, char, multispace1, space0, space1},
+    combinator::map_opt,
+    sequence::{preceded, terminated, tuple},
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{
+    angle::degree,
+    f32::{Angle, ThermodynamicTemperature, Velocity},
+    thermodynamic_temperature::degree_fahrenheit,
+    velocity::knot,
+};
+
+use crate::{
+    formats::column_cells,
+    header::WMOProductIdentifier,
+    parse::{fromstr_n, time::gggg},
+    ParseResult,
+};
+
+/// Value used in numeric MOS rows to indicate missing data
+const MISSING: &str = "999";
+
+/// A MOS bulletin containing guidance for one or more stations
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct MosBulletin {
+    pub header: WMOProductIdentifier,
+    /// Issuance time of the bulletin, resolved from the WMO header using the model run time
+    pub issued: Option<DateTime<Utc>>,
+    pub stations: Vec<MosStation>,
+}
+
+/// Guidance for a single station from one model run
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct MosStation {
+    pub station: String,
+    /// Name of the model that the guidance was produced from, e.g. `GFS` or `NAM`
+    pub model: String,
+    pub run: DateTime<Utc>,
+    /// Forecast values keyed by the time that they are valid for
+    pub forecasts: BTreeMap<DateTime<Utc>, MosForecast>,
+}
+
+/// All MOS elements given for a single forecast hour, with elements not forecast for the hour
+/// left as `None`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MosForecast {
+    /// Hours after the model run that this forecast is valid for
+    pub projection: u16,
+    /// Daytime maximum or nighttime minimum temperature for the 12 hours ending at this time,
+    /// from the `N/X` or `X/N` row
+    pub extreme_temperature: Option<ThermodynamicTemperature>,
+    pub temperature: Option<ThermodynamicTemperature>,
+    pub dew_point: Option<ThermodynamicTemperature>,
+    pub clouds: Option<MosCloudCover>,
+    pub wind_direction: Option<Angle>,
+    pub wind_speed: Option<Velocity>,
+    /// Probability of precipitation in the 6 hours ending at this time, ranging from [0., 1.]
+    pub pop6: Option<f32>,
+    /// Probability of precipitation in the 12 hours ending at this time, ranging from [0., 1.]
+    pub pop12: Option<f32>,
+    /// Probability of precipitation in the 24 hours ending at this time, ranging from [0., 1.]
+    pub pop24: Option<f32>,
+    /// Quantitative precipitation forecast category for the 6 hours ending at this time
+    pub qpf6: Option<u8>,
+    /// Quantitative precipitation forecast category for the 12 hours ending at this time
+    pub qpf12: Option<u8>,
+    /// Quantitative precipitation forecast category for the 24 hours ending at this time
+    pub qpf24: Option<u8>,
+    pub thunderstorm6: Option<MosThunderstorm>,
+    /// Ceiling height category, from 1 (< 200 ft) to 8 (> 12,000 ft or unlimited)
+    pub ceiling: Option<u8>,
+    /// Visibility category, from 1 (< 1/2 mi) to 7 (> 6 mi)
+    pub visibility: Option<u8>,
+    pub obstruction: Option<MosObstruction>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MosCloudCover {
+    Clear,
+    Few,
+    Scattered,
+    /// Partly cloudy, given by extended range guidance in place of few and scattered
+    PartlyCloudy,
+    Broken,
+    Overcast,
+}
+
+/// Probabilities of thunderstorms and of severe thunderstorms in a 6 hour period, ranging from
+/// [0., 1.]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MosThunderstorm {
+    pub probability: f32,
+    pub severe: f32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MosObstruction {
+    None,
+    Haze,
+    Mist,
+    Fog,
+    Blowing,
+}
+
+impl MosBulletin {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut stations: Vec<MosStation> = vec![];
+        let mut columns: Vec<(usize, DateTime<Utc>)> = vec![];
+
+        for line in input.lines().map(str::trim_end) {
+            if let Ok((_, station)) = MosStation::parse_header(line) {
+                columns.clear();
+                stations.push(station);
+                continue;
+            }
+
+            let station = match stations.last_mut() {
+                Some(station) => station,
+                None => continue,
+            };
+
+            let label = line.trim_start().split(' ').next().unwrap_or_default();
+
+            //Extended range guidance separates days with `|` and gives hours after the run
+            let row = line.replace('|', " ");
+            let hours = match label {
+                "HR" => Some(station.resolve_hours(&row)),
+                "FHR" => Some(station.resolve_projections(&row)),
+                _ => None,
+            };
+            if let Some(hours) = hours {
+                columns = hours;
+                for (_, valid) in columns.iter() {
+                    station.forecasts.insert(
+                        *valid,
+                        MosForecast {
+                            projection: (*valid - station.run).num_hours() as u16,
+                            ..Default::default()
+                        },
+                    );
+                }
+                continue;
+            }
+
+            if columns.is_empty() {
+                continue;
+            }
+
+            //Thunderstorm probabilities are formatted as `%2d/%2d`
+            let row = row.replace("/ ", "/0");
+            for (end, cell) in column_cells(&row).skip(1) {
+                let valid = match columns.iter().find(|(col, _)| col.abs_diff(end) <= 1) {
+                    Some((_, valid)) => *valid,
+                    None => continue,
+                };
+
+                if let Some(forecast) = station.forecasts.get_mut(&valid) {
+                    forecast.set(label, cell);
+                }
+            }
+        }
+
+        let issued = stations.first().and_then(|s| header.resolve(s.run));
+
+        Ok((
+            "",
+            Self {
+                header,
+                issued,
+                stations,
+            },
+        ))
+    }
+}
+
+impl MosStation {
+    /// Parse the station header line like ` KDEN   GFS MOS GUIDANCE   10/18/2026  1200 UTC`
+    pub fn parse_header(input: &str) -> ParseResult<&str, Self> {
+        let (input, (station, model, run)) = tuple((
+            preceded(space0, alphanumeric1),
+            preceded(space1, take_until(" MOS GUIDANCE")),
+            preceded(
+                tuple((tag(" MOS GUIDANCE"), space1)),
+                map_opt(
+                    tuple((
+                        fromstr_n::<u32>(2),
+                        preceded(char('/'), fromstr_n::<u32>(2)),
+                        preceded(char('/'), fromstr_n::<i32>(4)),
+                        terminated(preceded(space1, gggg), tuple((space1, tag("UTC")))),
+                    )),
+                    |(month, day, year, time)| {
+                        NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(
+                            time.hour as u32,
+                            time.minute as u32,
+                            0,
+                        )
+                    },
+                ),
+            ),
+        ))(input)?;
+
+        let run = Utc.from_utc_datetime(&run);
+
+        Ok((
+            input,
+            Self {
+                station: station.to_owned(),
+                model: model.trim().to_owned(),
+                run,
+                forecasts: BTreeMap::new(),
+            },
+        ))
+    }
+
+    /// Resolve the hours of the `HR` row to absolute times after the model run, returning the
+    /// offset of the end of each column with its time
+    fn resolve_hours(&self, line: &str) -> Vec<(usize, DateTime<Utc>)> {
+        let mut current = self.run;
+        column_cells(line)
+            .skip(1)
+            .filter_map(|(end, hour)| {
+                let hour = hour.parse::<u32>().ok()?;
+                let elapsed = (hour + 24 - current.hour()) % 24;
+                current += Duration::hours(elapsed as i64);
+                Some((end, current))
+            })
+            .collect()
+    }
+
+    /// Resolve the projections of the extended range `FHR` row to absolute times after the model
+    /// run, returning the offset of the end of each column with its time
+    fn resolve_projections(&self, line: &str) -> Vec<(usize, DateTime<Utc>)> {
+        column_cells(line)
+            .skip(1)
+            .filter_map(|(end, hours)| {
+                let hours = hours.parse::<i64>().ok()?;
+                Some((end, self.run + Duration::hours(hours)))
+            })
+            .collect()
+    }
+}
+
+impl MosForecast {
+    /// Set the value of the element given by the row label to the value of a single cell
+    fn set(&mut self, label: &str, cell: &str) {
+        let number = || Some(cell).filter(|c| *c != MISSING)?.parse::<f32>().ok();
+        let category = || cell.parse::<u8>().ok();
+
+        match label {
+            "N/X" | "X/N" => {
+                self.extreme_temperature =
+                    number().map(ThermodynamicTemperature::new::<degree_fahrenheit>)
+            }
+            "TMP" => {
+                self.temperature = number().map(ThermodynamicTemperature::new::<degree_fahrenheit>)
+            }
+            "DPT" => {
+                self.dew_point = number().map(ThermodynamicTemperature::new::<degree_fahrenheit>)
+            }
+            "CLD" => {
+                self.clouds = match cell {
+                    "CL" => Some(MosCloudCover::Clear),
+                    "FW" => Some(MosCloudCover::Few),
+                    "SC" => Some(MosCloudCover::Scattered),
+                    "PC" => Some(MosCloudCover::PartlyCloudy),
+                    "BK" => Some(MosCloudCover::Broken),
+                    "OV" => Some(MosCloudCover::Overcast),
+                    _ => None,
+                }
+            }
+            "WDR" => {
+                self.wind_direction = number()
+                    .filter(|dir| *dir <= 36f32)
+                    .map(|dir| Angle::new::<degree>(dir * 10f32))
+            }
+            "WSP" | "WND" => {
+                self.wind_speed = number().filter(|s| *s < 99f32).map(Velocity::new::<knot>)
+            }
+            "P06" => self.pop6 = number().map(|p| p / 100f32),
+            "P12" => self.pop12 = number().map(|p| p / 100f32),
+            "P24" => self.pop24 = number().map(|p| p / 100f32),
+            "Q06" => self.qpf6 = category(),
+            "Q12" => self.qpf12 = category(),
+            "Q24" => self.qpf24 = category(),
+            "T06" => {
+                self.thunderstorm6 = cell.split_once('/').and_then(|(p, s)| {
+                    Some(MosThunderstorm {
+                        probability: p.parse::<f32>().ok()? / 100f32,
+                        severe: s.parse::<f32>().ok()? / 100f32,
+                    })
+                })
+            }
+            "CIG" => self.ceiling = category(),
+            "VIS" => self.visibility = category(),
+            "OBV" => {
+                self.obstruction = match cell {
+                    "N" => Some(MosObstruction::None),
+                    "HZ" => Some(MosObstruction::Haze),
+                    "BR" => Some(MosObstruction::Mist),
+                    "FG" => Some(MosObstruction::Fog),
+                    "BL" => Some(MosObstruction::Blowing),
+                    _ => None,
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_mos() {
+        let mos = MosBulletin::parse(EX_MOS)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        let run = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+        assert_eq!(mos.issued, Some(run));
+        assert_eq!(mos.stations.len(), 2);
+
+        let den = &mos.stations[0];
+        assert_eq!(den.station, "KDEN");
+        assert_eq!(den.model, "GFS");
+        assert_eq!(den.run, run);
+        assert_eq!(den.forecasts.len(), 21);
+
+        let (last, _) = den.forecasts.iter().next_back().unwrap();
+        assert_eq!(*last, Utc.with_ymd_and_hms(2026, 10, 21, 12, 0, 0).unwrap());
+
+        let first = &den.forecasts[&Utc.with_ymd_and_hms(2026, 10, 18, 18, 0, 0).unwrap()];
+        assert_eq!(first.projection, 6);
+        assert_eq!(
+            first.temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(58f32))
+        );
+        assert_eq!(first.clouds, Some(MosCloudCover::Clear));
+        assert_eq!(first.wind_direction, Some(Angle::new::<degree>(90f32)));
+        assert_eq!(first.pop6, None);
+
+        let day2 = &den.forecasts[&Utc.with_ymd_and_hms(2026, 10, 20, 12, 0, 0).unwrap()];
+        assert_eq!(day2.projection, 48);
+        assert_eq!(day2.pop6, Some(0.2f32));
+        assert_eq!(day2.pop12, Some(0.22f32));
+        assert_eq!(day2.qpf6, Some(1));
+        assert_eq!(
+            day2.thunderstorm6,
+            Some(MosThunderstorm {
+                probability: 0.12,
+                severe: 0.03
+            })
+        );
+
+        let last = den.forecasts.values().next_back().unwrap();
+        assert_eq!(last.projection, 72);
+        assert_eq!(last.visibility, Some(5));
+        assert_eq!(last.obstruction, Some(MosObstruction::Mist));
+
+        let cos = &mos.stations[1];
+        assert!(cos.forecasts.values().all(|f| f.wind_direction.is_none()));
+
+        assert!(
+            MosStation::parse_header(" KDEN   GFS MOS GUIDANCE   10/18/2026  2500 UTC").is_err()
+        );
+    }
+
+    #[test]
+    pub fn test_extended_mos() {
+        let mos = MosBulletin::parse(EX_EXTENDED_MOS)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        let run = Utc.with_ymd_and_hms(2026, 10, 18, 0, 0, 0).unwrap();
+        assert_eq!(mos.issued, Some(run));
+
+        let den = &mos.stations[0];
+        assert_eq!(den.model, "GFSX");
+        //Climatology columns after the last projection are not forecasts
+        assert_eq!(den.forecasts.len(), 15);
+
+        let day2 = &den.forecasts[&Utc.with_ymd_and_hms(2026, 10, 20, 0, 0, 0).unwrap()];
+        assert_eq!(day2.projection, 48);
+        assert_eq!(
+            day2.extreme_temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(70f32))
+        );
+        assert_eq!(
+            day2.temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(63f32))
+        );
+        assert_eq!(day2.clouds, Some(MosCloudCover::PartlyCloudy));
+        assert_eq!(day2.wind_speed, Some(Velocity::new::<knot>(12f32)));
+        assert_eq!(day2.pop12, Some(0.04f32));
+        assert_eq!(day2.pop24, Some(0.05f32));
+        assert_eq!(day2.qpf12, Some(0));
+        assert_eq!(day2.qpf24, Some(1));
+
+        let night = &den.forecasts[&Utc.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap()];
+        assert_eq!(
+            night.extreme_temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(40f32))
+        );
+        assert_eq!(night.pop24, None);
+
+        let last = den.forecasts.values().next_back().unwrap();
+        assert_eq!(last.projection, 192);
+        assert_eq!(last.qpf12, None);
+    }
+
+    const EX_MOS: &str = include_str!("test/mos.txt");
+    const EX_EXTENDED_MOS: &str = include_str!("test/mos_extended.txt");
+}
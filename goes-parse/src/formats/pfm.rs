@@ -0,0 +1,469 @@
+//! Parsing for Point Forecast Matrices (PFM), tabular forecasts of weather elements for a set of
+//! forecast points or zones
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use nom::{
+    character::complete::{char, digit1, multispace1, space1},
+    combinator::opt,
+    sequence::{preceded, terminated, tuple},
+    Parser,
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{
+    f32::{Angle, Length, ThermodynamicTemperature, Velocity},
+    length::foot,
+    thermodynamic_temperature::degree_fahrenheit,
+    velocity::mile_per_hour,
+};
+
+use crate::{
+    formats::{
+        codes::precipitation::PrecipitationAmount, column_cells, compass16, is_ugc,
+        parse_decimal_latlon,
+    },
+    header::WMOProductIdentifier,
+    parse::fromstr_with,
+    ParseResult,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct PointForecastMatrix {
+    pub header: WMOProductIdentifier,
+    pub segments: Vec<PointForecastMatrixSegment>,
+}
+
+/// Forecast for a single point or zone, terminated by `$$` in the product
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct PointForecastMatrixSegment {
+    /// UGC zone line of the segment
+    pub zones: String,
+    pub name: String,
+    pub lat: Option<Angle>,
+    pub lon: Option<Angle>,
+    pub elevation: Option<Length>,
+    /// Forecast values keyed by the time that they are valid for
+    pub forecasts: BTreeMap<DateTime<Utc>, PointForecast>,
+}
+
+/// Elements forecast for a single column of the matrix, with elements not given for the column
+/// left empty
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PointForecast {
+    /// Number of hours between this column and the previous one, either 3 or 6
+    pub interval: u8,
+    pub max_temperature: Option<ThermodynamicTemperature>,
+    pub min_temperature: Option<ThermodynamicTemperature>,
+    pub temperature: Option<ThermodynamicTemperature>,
+    pub dew_point: Option<ThermodynamicTemperature>,
+    /// Ranging from [0., 1.]
+    pub relative_humidity: Option<f32>,
+    /// Direction the wind is blowing from, or the prevailing direction in 6-hourly columns
+    pub wind_direction: Option<Angle>,
+    pub wind_speed: Option<Velocity>,
+    pub wind_gust: Option<Velocity>,
+    pub wind_character: Option<PointForecastWindCharacter>,
+    pub clouds: Option<PointForecastCloudCover>,
+    /// Probability of precipitation in the 12 hours ending at this time, ranging from [0., 1.]
+    pub pop12: Option<f32>,
+    /// Precipitation amount in the 12 hours ending at this time
+    pub qpf12: Option<PrecipitationAmount>,
+    pub weather: Vec<PointForecastWeather>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointForecastCloudCover {
+    Clear,
+    Few,
+    Scattered,
+    /// Broken clouds covering 5/8 to 6/8 of the sky
+    MostlyCloudy,
+    /// Broken clouds covering 7/8 of the sky
+    Broken,
+    Overcast,
+}
+
+/// Wind speed category given for 6-hourly columns
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointForecastWindCharacter {
+    /// 0-7 MPH
+    Light,
+    /// 8-14 MPH
+    Gentle,
+    /// 15-22 MPH
+    Breezy,
+    /// 23-30 MPH
+    Windy,
+    /// 31-39 MPH
+    VeryWindy,
+    /// 40-73 MPH
+    StrongDamaging,
+    /// 74+ MPH
+    HurricaneForce,
+}
+
+/// A weather type row (e.g. `RAIN SHWRS`) with its coverage in a column
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PointForecastWeather {
+    /// Row label of the weather type
+    pub kind: String,
+    pub coverage: PointForecastCoverage,
+}
+
+/// Probability or areal coverage code of a weather type
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointForecastCoverage {
+    SlightChance,
+    Chance,
+    Likely,
+    Occasional,
+    Definite,
+    Isolated,
+    Scattered,
+    Numerous,
+    Areas,
+    Patchy,
+    Widespread,
+}
+
+/// Columns of a single 3-hourly or 6-hourly block, with the offset of the end of each column
+/// and the time it is valid for
+#[derive(Clone, Debug)]
+struct Block {
+    label_width: usize,
+    interval: u8,
+    columns: Vec<(usize, DateTime<Utc>)>,
+}
+
+impl PointForecastMatrix {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut segments: Vec<PointForecastMatrixSegment> = vec![];
+        let mut current: Option<PointForecastMatrixSegment> = None;
+        let mut dates: Option<&str> = None;
+        let mut utc: Option<&str> = None;
+        let mut block: Option<Block> = None;
+
+        for line in input.lines().map(str::trim_end) {
+            if line.starts_with("$$") {
+                segments.extend(current.take());
+                block = None;
+                continue;
+            }
+
+            let segment = match current {
+                Some(ref mut segment) => segment,
+                None => {
+                    if is_ugc(line) {
+                        current = Some(PointForecastMatrixSegment {
+                            zones: line.to_owned(),
+                            name: String::new(),
+                            lat: None,
+                            lon: None,
+                            elevation: None,
+                            forecasts: BTreeMap::new(),
+                        });
+                    }
+                    continue;
+                }
+            };
+
+            if line.starts_with("DATE") {
+                dates = Some(line);
+                block = None;
+            } else if line.starts_with("UTC ") {
+                utc = Some(line);
+            } else if let (Some(utc_line), Some(date_line)) = (utc.take(), dates) {
+                //The line following the UTC hours gives the local hours
+                block = Block::resolve(date_line, utc_line, line);
+                if block.is_none() {
+                    log::info!("Failed to resolve PFM column times");
+                }
+            } else if let Some(ref block) = block {
+                block.apply(line, segment);
+            } else if segment.name.is_empty() {
+                segment.name = line.trim().trim_end_matches('-').to_owned();
+            } else if segment.lat.is_none() {
+                if let Ok((_, (lat, lon, elevation))) = parse_location(line.trim()) {
+                    segment.lat = Some(lat);
+                    segment.lon = Some(lon);
+                    segment.elevation = elevation;
+                }
+            }
+        }
+
+        segments.extend(current);
+
+        Ok(("", Self { header, segments }))
+    }
+}
+
+/// Parse the location line of a segment like `39.87N  104.67W  ELEV. 5431 FT`
+fn parse_location(input: &str) -> ParseResult<&str, (Angle, Angle, Option<Length>)> {
+    tuple((
+        parse_decimal_latlon,
+        opt(preceded(
+            tuple((space1, tag("ELEV."), space1)),
+            terminated(
+                tuple((opt(char('-')), fromstr_with::<f32, _>(digit1))).map(|(neg, elev)| {
+                    if neg.is_some() {
+                        -elev
+                    } else {
+                        elev
+                    }
+                }),
+                tag(" FT"),
+            )
+            .map(Length::new::<foot>),
+        )),
+    ))
+    .map(|((lat, lon), elevation)| (lat, lon, elevation))
+    .parse(input)
+}
+
+impl Block {
+    /// Resolve the time of each column from the `DATE`, UTC hour, and local hour lines of a block
+    fn resolve(dates: &str, utc: &str, local: &str) -> Option<Self> {
+        let label_width = utc.find("HRLY")? + 4;
+        let label_width = label_width + utc[label_width..].find(|c: char| c != ' ')?;
+        let interval = match utc.contains("6HRLY") {
+            true => 6,
+            false => 3,
+        };
+
+        let first_date = dates
+            .split_whitespace()
+            .find_map(|word| NaiveDate::parse_from_str(word, "%m/%d/%y").ok())?;
+
+        let utc_hours = column_cells(&utc[label_width..])
+            .map(|(end, hour)| Some((end + label_width, hour.parse::<i64>().ok()?)))
+            .collect::<Option<Vec<_>>>()?;
+        let local_hours = column_cells(local.get(label_width..)?)
+            .map(|(_, hour)| hour.parse::<i64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        let (_, first_utc) = utc_hours.first()?;
+        let offset = (first_utc - local_hours.first()?).rem_euclid(24);
+
+        let mut date = first_date;
+        let mut last_hour = None;
+        let columns = utc_hours
+            .iter()
+            .zip(local_hours.iter())
+            .map(|((end, _), local)| {
+                if last_hour.map(|last| *local <= last).unwrap_or(false) {
+                    date = date.succ_opt()?;
+                }
+                last_hour = Some(*local);
+
+                let midnight = Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?);
+                Some((*end, midnight + Duration::hours(*local + offset)))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            label_width,
+            interval,
+            columns,
+        })
+    }
+
+    /// Assign the values of one row of the matrix to the segment's forecasts
+    fn apply(&self, line: &str, segment: &mut PointForecastMatrixSegment) {
+        let (label, row) = match (line.get(..self.label_width), line.get(self.label_width..)) {
+            (Some(label), Some(row)) => (label.trim(), row),
+            _ => return,
+        };
+
+        //Rows giving extremes alternate between maximum and minimum temperatures
+        let mut max = label.starts_with("MAX");
+
+        for (end, cell) in column_cells(row) {
+            let end = end + self.label_width;
+            let valid = match self.columns.iter().find(|(col, _)| col.abs_diff(end) <= 1) {
+                Some((_, valid)) => *valid,
+                None => continue,
+            };
+
+            let forecast = segment.forecasts.entry(valid).or_default();
+            forecast.interval = self.interval;
+
+            let number = || cell.parse::<f32>().ok();
+            let fahrenheit = || number().map(ThermodynamicTemperature::new::<degree_fahrenheit>);
+
+            match label {
+                "MAX/MIN" | "MIN/MAX" => {
+                    match max {
+                        true => forecast.max_temperature = fahrenheit(),
+                        false => forecast.min_temperature = fahrenheit(),
+                    }
+                    max = !max;
+                }
+                "TEMP" => forecast.temperature = fahrenheit(),
+                "DEWPT" => forecast.dew_point = fahrenheit(),
+                "RH" => forecast.relative_humidity = number().map(|rh| rh / 100f32),
+                "WIND DIR" | "PWIND DIR" => forecast.wind_direction = compass16(cell).ok(),
+                "WIND SPD" => forecast.wind_speed = number().map(Velocity::new::<mile_per_hour>),
+                "WIND GUST" => forecast.wind_gust = number().map(Velocity::new::<mile_per_hour>),
+                "WIND CHAR" => {
+                    forecast.wind_character = match cell {
+                        "LT" => Some(PointForecastWindCharacter::Light),
+                        "GN" => Some(PointForecastWindCharacter::Gentle),
+                        "BZ" => Some(PointForecastWindCharacter::Breezy),
+                        "WY" => Some(PointForecastWindCharacter::Windy),
+                        "VW" => Some(PointForecastWindCharacter::VeryWindy),
+                        "SD" => Some(PointForecastWindCharacter::StrongDamaging),
+                        "HF" => Some(PointForecastWindCharacter::HurricaneForce),
+                        _ => None,
+                    }
+                }
+                "CLOUDS" | "AVG CLOUDS" => {
+                    forecast.clouds = match cell {
+                        "CL" => Some(PointForecastCloudCover::Clear),
+                        "FW" => Some(PointForecastCloudCover::Few),
+                        "SC" => Some(PointForecastCloudCover::Scattered),
+                        "B1" => Some(PointForecastCloudCover::MostlyCloudy),
+                        "B2" | "BK" => Some(PointForecastCloudCover::Broken),
+                        "OV" => Some(PointForecastCloudCover::Overcast),
+                        _ => None,
+                    }
+                }
+                "POP 12HR" => forecast.pop12 = number().map(|pop| pop / 100f32),
+                "QPF 12HR" => {
+                    forecast.qpf12 = PrecipitationAmount::parse_inches(cell).ok().flatten()
+                }
+                kind => {
+                    let coverage = match cell {
+                        "S" => PointForecastCoverage::SlightChance,
+                        "C" => PointForecastCoverage::Chance,
+                        "L" => PointForecastCoverage::Likely,
+                        "O" => PointForecastCoverage::Occasional,
+                        "D" => PointForecastCoverage::Definite,
+                        "IS" => PointForecastCoverage::Isolated,
+                        "SC" => PointForecastCoverage::Scattered,
+                        "NM" => PointForecastCoverage::Numerous,
+                        "AR" => PointForecastCoverage::Areas,
+                        "PA" => PointForecastCoverage::Patchy,
+                        "WD" => PointForecastCoverage::Widespread,
+                        _ => {
+                            log::trace!("Unknown PFM row {} with value {}", kind, cell);
+                            continue;
+                        }
+                    };
+
+                    forecast.weather.push(PointForecastWeather {
+                        kind: kind.to_owned(),
+                        coverage,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use uom::si::{angle::degree, length::inch};
+
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, day, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    pub fn test_pfm() {
+        let pfm = PointForecastMatrix::parse(EX_PFM)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(pfm.segments.len(), 2);
+
+        let den = &pfm.segments[0];
+        assert_eq!(den.zones, "COZ040-182200-");
+        assert_eq!(den.name, "DENVER INTL AIRPORT");
+        assert_eq!(den.lat, Some(Angle::new::<degree>(39.87)));
+        assert_eq!(den.lon, Some(Angle::new::<degree>(-104.67)));
+        assert_eq!(den.elevation, Some(Length::new::<foot>(5431f32)));
+        assert_eq!(den.forecasts.len(), 42);
+
+        let first = &den.forecasts[&at(18, 12)];
+        assert_eq!(first.interval, 3);
+        assert_eq!(
+            first.temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(43f32))
+        );
+        assert_eq!(first.wind_direction, Some(Angle::new::<degree>(180f32)));
+        assert_eq!(first.clouds, Some(PointForecastCloudCover::Clear));
+
+        let evening = &den.forecasts[&at(19, 0)];
+        assert_eq!(
+            evening.max_temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(68f32))
+        );
+        assert_eq!(evening.pop12, Some(0f32));
+
+        let morning = &den.forecasts[&at(19, 12)];
+        assert_eq!(
+            morning.min_temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(41f32))
+        );
+        assert_eq!(morning.wind_gust, None);
+
+        let wet = &den.forecasts[&at(20, 0)];
+        assert_eq!(
+            wet.qpf12,
+            Some(PrecipitationAmount::Amount(Length::new::<inch>(0.02)))
+        );
+        assert_eq!(wet.clouds, Some(PointForecastCloudCover::Broken));
+        assert_eq!(
+            wet.weather,
+            vec![
+                PointForecastWeather {
+                    kind: "RAIN SHWRS".to_owned(),
+                    coverage: PointForecastCoverage::Chance,
+                },
+                PointForecastWeather {
+                    kind: "TSTMS".to_owned(),
+                    coverage: PointForecastCoverage::SlightChance,
+                },
+            ]
+        );
+
+        let extended = &den.forecasts[&at(21, 12)];
+        assert_eq!(extended.interval, 6);
+        assert_eq!(
+            extended.min_temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(42f32))
+        );
+        assert_eq!(
+            extended.wind_character,
+            Some(PointForecastWindCharacter::Light)
+        );
+        assert_eq!(
+            den.forecasts.keys().next_back(),
+            Some(&Utc.with_ymd_and_hms(2026, 10, 26, 0, 0, 0).unwrap())
+        );
+
+        let fnl = &pfm.segments[1];
+        assert_eq!(fnl.name, "FORT COLLINS");
+        assert_eq!(fnl.forecasts.len(), 22);
+
+        //A truncated local hour line leaves the block unresolved
+        let dates = "DATE             SUN 10/18/26";
+        let utc = "UTC 3HRLY     12 15 18 21";
+        assert!(Block::resolve(dates, utc, "").is_none());
+        assert!(Block::resolve(dates, utc, "MDT 3HRLY").is_none());
+    }
+
+    const EX_PFM: &str = include_str!("test/pfm.txt");
+}
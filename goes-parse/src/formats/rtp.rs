@@ -0,0 +1,267 @@
+//! Parsing for State Temperature and Precipitation tables (RTP), which are encoded as SHEF `.B`
+//! tables bracketed by free text
+
+use nom::{
+    bytes::complete::{take, take_while1},
+    character::complete::{alpha1, anychar, char, multispace1, space1},
+    combinator::map_res,
+    multi::many0,
+    sequence::{preceded, terminated, tuple},
+    Parser,
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{f32::ThermodynamicTemperature, thermodynamic_temperature::degree_fahrenheit};
+
+use crate::{
+    dt::area::AreaCode,
+    formats::codes::precipitation::PrecipitationAmount,
+    header::WMOProductIdentifier,
+    parse::{fromstr_n, fromstr_with},
+    ParseResult,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct StateTemperaturePrecipitation {
+    pub header: WMOProductIdentifier,
+    pub area: AreaCode,
+    pub tables: Vec<StateTemperaturePrecipitationTable>,
+}
+
+/// One `.B` table of a temperature and precipitation product
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct StateTemperaturePrecipitationTable {
+    /// Identifier of the office or group issuing the table
+    pub source: String,
+    pub month: u8,
+    pub day: u8,
+    /// SHEF time zone code the observation time is given in
+    pub zone: char,
+    /// Hour that the observation period ends at
+    pub hour: u8,
+    /// SHEF physical element codes of each value column, in order
+    pub elements: Vec<String>,
+    pub sections: Vec<StateTemperaturePrecipitationSection>,
+}
+
+/// A group of stations within a table, titled by the comment line given above them
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct StateTemperaturePrecipitationSection {
+    pub name: Option<String>,
+    pub reports: Vec<StateTemperaturePrecipitationItem>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct StateTemperaturePrecipitationItem {
+    pub id: String,
+    pub name: Option<String>,
+    pub max_temperature: Option<ThermodynamicTemperature>,
+    pub min_temperature: Option<ThermodynamicTemperature>,
+    pub precipitation: Option<PrecipitationAmount>,
+    pub snowfall: Option<PrecipitationAmount>,
+    pub snow_depth: Option<PrecipitationAmount>,
+}
+
+impl StateTemperaturePrecipitation {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let (input, area) = preceded(
+            tag("RTP"),
+            map_res(take(2usize), |code: &str| code.parse::<AreaCode>()),
+        )(input)?;
+
+        let mut tables = vec![];
+        let mut current: Option<StateTemperaturePrecipitationTable> = None;
+        let mut name = None;
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if line.starts_with(".END") {
+                tables.extend(current.take());
+            } else if line.starts_with(".B") {
+                tables.extend(current.take());
+                name = None;
+                match StateTemperaturePrecipitationTable::parse_header(line) {
+                    Ok((_, table)) => current = Some(table),
+                    Err(e) => log::info!("Failed to parse RTP table header: {}", e),
+                }
+            } else if let Some(ref mut table) = current {
+                match line.strip_prefix(':') {
+                    Some(comment) => {
+                        let comment = comment.trim();
+                        //Column titles and period descriptions are not section names
+                        if !comment.is_empty()
+                            && !comment.contains(':')
+                            && !comment.contains('/')
+                            && !comment.contains("PERIOD")
+                        {
+                            name = Some(comment.to_owned());
+                        }
+                    }
+                    None => match table.parse_row(line) {
+                        Some(item) => {
+                            if table.sections.is_empty() || name.is_some() {
+                                table.sections.push(StateTemperaturePrecipitationSection {
+                                    name: name.take(),
+                                    reports: vec![],
+                                });
+                            }
+
+                            table.sections.last_mut().unwrap().reports.push(item);
+                        }
+                        None => log::info!("Failed to parse RTP row {}", line),
+                    },
+                }
+            }
+        }
+
+        tables.extend(current);
+
+        Ok((
+            "",
+            Self {
+                header,
+                area,
+                tables,
+            },
+        ))
+    }
+
+    /// Iterate over the station reports of every table in this product
+    pub fn reports(&self) -> impl Iterator<Item = &StateTemperaturePrecipitationItem> {
+        self.tables
+            .iter()
+            .flat_map(|table| table.sections.iter())
+            .flat_map(|section| section.reports.iter())
+    }
+}
+
+impl StateTemperaturePrecipitationTable {
+    /// Parse a SHEF `.B` header line like `.BR BOU 1018 M DH06/TX/TN/PP/SF/SD`
+    pub fn parse_header(input: &str) -> ParseResult<&str, Self> {
+        let (input, (source, month, day, zone, hour)) = tuple((
+            preceded(
+                tuple((
+                    tag(".B"),
+                    take_while1(|c: char| c.is_ascii_alphanumeric()),
+                    space1,
+                )),
+                alpha1,
+            ),
+            preceded(space1, fromstr_n(2)),
+            fromstr_n(2),
+            preceded(space1, anychar),
+            preceded(tuple((space1, tag("DH"))), fromstr_with(take(2usize))),
+        ))(input)?;
+
+        let (input, elements) = many0(preceded(
+            char('/'),
+            take_while1(|c: char| c.is_ascii_alphanumeric()).map(str::to_owned),
+        ))(input)?;
+
+        Ok((
+            input,
+            Self {
+                source: source.to_owned(),
+                month,
+                day,
+                zone,
+                hour,
+                elements,
+                sections: vec![],
+            },
+        ))
+    }
+
+    /// Parse a table row like `DEN   : DENVER INTL  :  68 /  41 / 0.00 /  0.0 /  0`, using the
+    /// element codes given in the table header to assign each value
+    fn parse_row(&self, line: &str) -> Option<StateTemperaturePrecipitationItem> {
+        let mut parts = line.split(':').map(str::trim);
+        let id = parts.next().filter(|id| !id.is_empty())?.to_owned();
+        let (name, values) = match (parts.next(), parts.next()) {
+            (Some(name), Some(values)) => (Some(name.to_owned()), values),
+            (Some(values), None) => (None, values),
+            _ => return None,
+        };
+
+        let mut item = StateTemperaturePrecipitationItem {
+            id,
+            name,
+            max_temperature: None,
+            min_temperature: None,
+            precipitation: None,
+            snowfall: None,
+            snow_depth: None,
+        };
+
+        for (element, value) in self.elements.iter().zip(values.split('/').map(str::trim)) {
+            let amount = || PrecipitationAmount::parse_inches(value).ok().flatten();
+            let temperature = || {
+                value
+                    .parse::<f32>()
+                    .ok()
+                    .map(ThermodynamicTemperature::new::<degree_fahrenheit>)
+            };
+
+            match element.get(..2).unwrap_or_default() {
+                "TX" => item.max_temperature = temperature(),
+                "TN" => item.min_temperature = temperature(),
+                "PP" => item.precipitation = amount(),
+                "SF" => item.snowfall = amount(),
+                "SD" => item.snow_depth = amount(),
+                other => log::trace!("Unknown RTP element {}", other),
+            }
+        }
+
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use uom::si::{f32::Length, length::inch};
+
+    use super::*;
+
+    #[test]
+    pub fn test_rtp() {
+        let rtp = StateTemperaturePrecipitation::parse(EX_RTP)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(rtp.tables.len(), 2);
+
+        let table = &rtp.tables[0];
+        assert_eq!(table.source, "BOU");
+        assert_eq!((table.month, table.day, table.hour), (10, 18, 6));
+        assert_eq!(table.elements, ["TX", "TN", "PP", "SF", "SD"]);
+        assert_eq!(table.sections.len(), 2);
+        assert_eq!(
+            table.sections[1].name.as_deref(),
+            Some("FOOTHILLS AND MOUNTAINS")
+        );
+
+        let den = &table.sections[0].reports[0];
+        assert_eq!(den.id, "DEN");
+        assert_eq!(den.name.as_deref(), Some("DENVER INTL AIRPORT"));
+        assert_eq!(
+            den.max_temperature,
+            Some(ThermodynamicTemperature::new::<degree_fahrenheit>(68f32))
+        );
+        assert_eq!(
+            den.precipitation,
+            Some(PrecipitationAmount::Amount(Length::new::<inch>(0f32)))
+        );
+
+        let bjc = &table.sections[0].reports[1];
+        assert_eq!(bjc.precipitation, Some(PrecipitationAmount::Trace));
+        assert_eq!(bjc.snowfall, None);
+
+        assert_eq!(rtp.reports().count(), 7);
+    }
+
+    const EX_RTP: &str = include_str!("test/rtp.txt");
+}
@@ -3,43 +3,150 @@
 use std::str::FromStr;
 
 use nom::{
-    bytes::complete::{take, take_while},
-    character::complete::{anychar, char, multispace1, space0, space1},
-    combinator::{map_res, opt},
-    multi::many_till,
-    sequence::{preceded, terminated},
+    branch::alt,
+    bytes::complete::{take, take_while1},
+    character::complete::{alpha1, char, digit1, multispace1},
+    combinator::{all_consuming, map_res, opt},
+    sequence::{preceded, terminated, tuple},
+    Parser,
 };
 use nom_supreme::tag::complete::tag;
+use uom::si::{
+    f32::{Angle, Pressure, ThermodynamicTemperature, Velocity},
+    pressure::inch_of_mercury,
+    thermodynamic_temperature::degree_fahrenheit,
+    velocity::mile_per_hour,
+};
 
-use crate::{dt::area::AreaCode, header::WMOProductIdentifier, ParseResult};
+use crate::{
+    dt::area::AreaCode,
+    formats::{codes::precipitation::PrecipitationAmount, compass16, is_ugc},
+    header::WMOProductIdentifier,
+    parse::fromstr_with,
+    ParseResult,
+};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub struct RegionalWeatherRoundup {
     pub header: WMOProductIdentifier,
     pub area: AreaCode,
+    pub sections: Vec<RegionalWeatherRoundupSection>,
+}
+
+/// A single table of observations in a roundup, with the zones and region name given above the
+/// table header
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct RegionalWeatherRoundupSection {
+    /// UGC zone line of the product segment containing this table, if any
+    pub zones: Option<String>,
+    /// Name of the region that the observations are for
+    pub name: Option<String>,
     pub reports: Vec<RegionalWeatherRoundupItem>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub struct RegionalWeatherRoundupItem {
     pub city: String,
     pub sky: RegionalWeatherSkyCondition,
-    pub temperature: i32,
-    pub dew_point: i32,
-    pub relative_humidity: i32,
+    pub temperature: Option<ThermodynamicTemperature>,
+    pub dew_point: Option<ThermodynamicTemperature>,
+    /// Ranging from [0., 1.]
+    pub relative_humidity: Option<f32>,
+    pub wind: Option<RegionalWeatherWind>,
+    pub pressure: Option<Pressure>,
+    pub pressure_tendency: Option<PressureTendency>,
+    pub remarks: String,
+    /// Extremes and precipitation totals reported on the line following the observation
+    pub supplemental: Vec<RegionalWeatherSupplemental>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RegionalWeatherSkyCondition {
     NA,
     LightRain,
+    Rain,
+    HeavyRain,
+    FreezingRain,
     Drizzle,
+    FreezingDrizzle,
+    LightSnow,
+    Snow,
+    HeavySnow,
+    BlowingSnow,
+    Flurries,
+    RainAndSnow,
+    Sleet,
+    Thunderstorm,
+    Fog,
+    Haze,
+    Smoke,
+    Dust,
     Cloudy,
+    MostlyCloudy,
+    PartlyCloudy,
     PartlySunny,
     MostlySunny,
     Sunny,
     Fair,
     Clear,
+    /// A condition not recognized by this parser, with the original text
+    Other(String),
+}
+
+/// Wind observation given in the `WIND` column of a roundup
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RegionalWeatherWind {
+    Calm,
+    Variable {
+        speed: Velocity,
+        gust: Option<Velocity>,
+    },
+    Directional {
+        /// Direction the wind is blowing from
+        direction: Angle,
+        speed: Velocity,
+        gust: Option<Velocity>,
+    },
+}
+
+/// Trend of station pressure given after the pressure reading
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PressureTendency {
+    Rising,
+    Steady,
+    Falling,
+}
+
+/// Additional values such as `6HR MAX TEMP:  49;` reported below an observation
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RegionalWeatherSupplemental {
+    MinTemperature {
+        hours: u8,
+        temperature: ThermodynamicTemperature,
+    },
+    MaxTemperature {
+        hours: u8,
+        temperature: ThermodynamicTemperature,
+    },
+    Precipitation {
+        hours: u8,
+        amount: PrecipitationAmount,
+    },
+}
+
+/// Character offsets of the columns in a roundup table, read from the `CITY` header line
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegionalWeatherRoundupColumns {
+    pub sky: usize,
+    pub temperature: usize,
 }
 
 impl RegionalWeatherRoundup {
@@ -51,85 +158,262 @@ impl RegionalWeatherRoundup {
             map_res(take(2usize), |code: &str| code.parse::<AreaCode>()),
         )(input)?;
 
-        let mut reports = vec![];
-        let mut city = false;
+        let mut sections = vec![];
+        let mut current: Option<(RegionalWeatherRoundupColumns, RegionalWeatherRoundupSection)> =
+            None;
+        let mut zones = None;
+        let mut name = None;
 
-        for line in input.lines().filter(|line| line.len() > 2) {
-            match city {
-                false => {
-                    if line.starts_with("CITY") {
-                        city = true
-                    }
-                }
-                true => {
-                    if line.starts_with("$$") {
-                        city = false
-                    } else {
-                        match RegionalWeatherRoundupItem::parse(line) {
-                            Ok((_, item)) => reports.push(item),
-                            Err(e) => {
-                                log::info!("Failed to parse RWR item: {}", e);
-                            }
+        let lines = input
+            .lines()
+            .map(str::trim_end)
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<_>>();
+
+        for (idx, &line) in lines.iter().enumerate() {
+            //Sub-headings naming the stations of a table are directly followed by its header
+            let subheading = !line.starts_with(' ')
+                && lines
+                    .get(idx + 1)
+                    .is_some_and(|next| RegionalWeatherRoundupColumns::from_header(next).is_some());
+
+            if line.starts_with("$$") {
+                sections.extend(current.take().map(|(_, section)| section));
+                zones = None;
+                name = None;
+            } else if let Some(columns) = RegionalWeatherRoundupColumns::from_header(line) {
+                sections.extend(current.take().map(|(_, section)| section));
+                current = Some((
+                    columns,
+                    RegionalWeatherRoundupSection {
+                        zones: zones.clone(),
+                        name: name.take(),
+                        reports: vec![],
+                    },
+                ));
+            } else if current.is_some() && subheading {
+                sections.extend(current.take().map(|(_, section)| section));
+                name = Some(line.trim().to_owned());
+            } else if let Some((columns, ref mut section)) = current {
+                match RegionalWeatherSupplemental::parse_line(line) {
+                    Some(supplemental) => match section.reports.last_mut() {
+                        Some(last) => last.supplemental.extend(supplemental),
+                        None => log::info!("RWR supplemental line without an observation"),
+                    },
+                    None => match RegionalWeatherRoundupItem::parse(columns)(line) {
+                        Ok((_, item)) => section.reports.push(item),
+                        Err(e) => {
+                            log::info!("Failed to parse RWR item: {}", e);
                         }
-                    }
+                    },
                 }
+            } else if is_ugc(line) {
+                zones = Some(line.to_owned());
+            } else {
+                name = Some(line.trim().to_owned());
             }
         }
 
+        sections.extend(current.map(|(_, section)| section));
+
         Ok((
             "",
             Self {
                 header,
                 area,
-                reports,
+                sections,
             },
         ))
     }
+
+    /// Iterate over the observations of every section in this roundup
+    pub fn reports(&self) -> impl Iterator<Item = &RegionalWeatherRoundupItem> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.reports.iter())
+    }
+}
+
+impl RegionalWeatherRoundupColumns {
+    /// Read column offsets from a table header line, returning `None` if the line is not a
+    /// header
+    pub fn from_header(line: &str) -> Option<Self> {
+        if !line.starts_with("CITY") {
+            return None;
+        }
+
+        Some(Self {
+            sky: line.find("SKY/WX")?,
+            temperature: line.find("TMP")?,
+        })
+    }
 }
 
 impl RegionalWeatherRoundupItem {
-    /// Parse a single weather report from one line
-    pub fn parse(input: &str) -> ParseResult<&str, Self> {
-        let (input, (city_parts, sky)) = preceded(
-            opt(char('*')),
-            many_till(
-                anychar,
-                preceded(space0, RegionalWeatherSkyCondition::parse),
-            ),
-        )(input)?;
+    /// Parse a single weather report from one line, using column offsets read from the table
+    /// header to separate the city and sky condition. Rows that end before the values or give
+    /// them as `NOT AVBL` are reports with every value missing
+    pub fn parse(
+        columns: RegionalWeatherRoundupColumns,
+    ) -> impl FnMut(&str) -> ParseResult<&str, Self> {
+        move |input: &str| {
+            let fail = || {
+                nom::Err::Error(nom::error::ParseError::from_error_kind(
+                    input,
+                    nom::error::ErrorKind::Verify,
+                ))
+            };
 
-        let city = city_parts.into_iter().collect::<String>();
+            //Columns are cut short for stations that are not reporting
+            let column = |start: usize, end: usize| {
+                let end = end.min(input.len());
+                input.get(start.min(end)..end).ok_or_else(fail)
+            };
 
-        let mut num = preceded(
-            space1,
-            map_res(take_while(|c: char| !c.is_whitespace()), |s: &str| {
-                s.parse::<i32>()
-            }),
-        );
+            let city = column(0, columns.sky)?;
+            let city = city.trim().trim_start_matches('*').trim().to_owned();
+            if city.is_empty() {
+                return Err(fail());
+            }
 
-        let (input, temperature) = num(input)?;
-        let (input, dew_point) = num(input)?;
-        let (input, relative_humidity) = num(input)?;
+            let sky = column(columns.sky, columns.temperature)?.trim();
+            let rest = column(columns.temperature, input.len())?.trim();
+            let rest = match rest {
+                "NOT AVBL" => "",
+                rest => rest,
+            };
+            let sky = match sky {
+                "" if rest.is_empty() => RegionalWeatherSkyCondition::NA,
+                sky => sky
+                    .parse::<RegionalWeatherSkyCondition>()
+                    .map_err(|_| fail())?,
+            };
 
-        Ok((
-            input,
-            Self {
-                city,
-                sky,
-                temperature,
-                dew_point,
-                relative_humidity,
-            },
-        ))
+            let mut tokens = rest.split_whitespace();
+            let mut next = || tokens.next().filter(|tok| !is_missing(tok));
+
+            let temperature = next()
+                .map(|t| t.parse::<f32>().map_err(|_| fail()))
+                .transpose()?
+                .map(ThermodynamicTemperature::new::<degree_fahrenheit>);
+            let dew_point = next()
+                .map(|t| t.parse::<f32>().map_err(|_| fail()))
+                .transpose()?
+                .map(ThermodynamicTemperature::new::<degree_fahrenheit>);
+            let relative_humidity = next()
+                .map(|t| t.parse::<f32>().map_err(|_| fail()))
+                .transpose()?
+                .map(|rh| rh / 100f32);
+
+            let wind = next()
+                .map(|t| RegionalWeatherWind::parse(t).map(|(_, w)| w))
+                .transpose()?;
+
+            let (pressure, pressure_tendency) = match next() {
+                Some(pres) => {
+                    let (_, (pressure, tendency)) = all_consuming(tuple((
+                        fromstr_with(take_while1(|c: char| c.is_ascii_digit() || c == '.'))
+                            .map(Pressure::new::<inch_of_mercury>),
+                        opt(alt((
+                            char('R').map(|_| PressureTendency::Rising),
+                            char('S').map(|_| PressureTendency::Steady),
+                            char('F').map(|_| PressureTendency::Falling),
+                        ))),
+                    )))(pres)?;
+                    (Some(pressure), tendency)
+                }
+                None => (None, None),
+            };
+
+            let remarks = tokens.collect::<Vec<_>>().join(" ");
+
+            Ok((
+                "",
+                Self {
+                    city,
+                    sky,
+                    temperature,
+                    dew_point,
+                    relative_humidity,
+                    wind,
+                    pressure,
+                    pressure_tendency,
+                    remarks,
+                    supplemental: vec![],
+                },
+            ))
+        }
     }
 }
 
-impl RegionalWeatherSkyCondition {
+/// Check if a column value is one of the markers used for missing data
+fn is_missing(s: &str) -> bool {
+    matches!(s, "MISG" | "N/A" | "NA" | "M" | "MM")
+}
+
+impl RegionalWeatherWind {
+    /// Parse a wind report like `CALM`, `VRB3`, `NW14`, or `SW12G25` with speeds in MPH
     pub fn parse(input: &str) -> ParseResult<&str, Self> {
-        let (rest, designator) =
-            map_res(take_while(|c: char| !c.is_whitespace()), Self::from_str)(input)?;
+        fn mph(input: &str) -> ParseResult<&str, Velocity> {
+            fromstr_with(digit1)
+                .map(Velocity::new::<mile_per_hour>)
+                .parse(input)
+        }
 
-        Ok((rest, designator))
+        all_consuming(alt((
+            tag("CALM").map(|_| Self::Calm),
+            preceded(tag("VRB"), tuple((mph, opt(preceded(char('G'), mph)))))
+                .map(|(speed, gust)| Self::Variable { speed, gust }),
+            tuple((
+                map_res(alpha1, compass16),
+                mph,
+                opt(preceded(char('G'), mph)),
+            ))
+            .map(|(direction, speed, gust)| Self::Directional {
+                direction,
+                speed,
+                gust,
+            }),
+        )))(input)
+    }
+}
+
+impl RegionalWeatherSupplemental {
+    /// Parse a line of `;` separated supplemental values, returning `None` if the line is not a
+    /// supplemental line
+    pub fn parse_line(line: &str) -> Option<Vec<Self>> {
+        let line = line.trim();
+        if !line.contains("HR ") || !line.contains(':') {
+            return None;
+        }
+
+        line.split(';')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                let (key, value) = item.split_once(':')?;
+                let value = value.trim();
+                let (hours, kind) = key.trim().split_once("HR ")?;
+                let hours = hours.parse::<u8>().ok()?;
+
+                Some(match kind {
+                    "MIN TEMP" => value.parse::<f32>().ok().map(|t| Self::MinTemperature {
+                        hours,
+                        temperature: ThermodynamicTemperature::new::<degree_fahrenheit>(t),
+                    }),
+                    "MAX TEMP" => value.parse::<f32>().ok().map(|t| Self::MaxTemperature {
+                        hours,
+                        temperature: ThermodynamicTemperature::new::<degree_fahrenheit>(t),
+                    }),
+                    "PCP" | "PCPN" => PrecipitationAmount::parse_inches(value)
+                        .ok()
+                        .flatten()
+                        .map(|amount| Self::Precipitation { hours, amount }),
+                    _ => None,
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|items| items.into_iter().flatten().collect())
     }
 }
 
@@ -142,26 +426,113 @@ impl FromStr for RegionalWeatherSkyCondition {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
             "CLOUDY" => Self::Cloudy,
+            "MOCLDY" => Self::MostlyCloudy,
+            "PTCLDY" => Self::PartlyCloudy,
             "PTSUNNY" => Self::PartlySunny,
             "MOSUNNY" => Self::MostlySunny,
             "SUNNY" => Self::Sunny,
             "DRIZZLE" => Self::Drizzle,
+            "FRZ DRZL" => Self::FreezingDrizzle,
+            "LGT RAIN" => Self::LightRain,
+            "RAIN" => Self::Rain,
+            "HVY RAIN" => Self::HeavyRain,
+            "FRZ RAIN" => Self::FreezingRain,
+            "LGT SNOW" => Self::LightSnow,
+            "SNOW" => Self::Snow,
+            "HVY SNOW" => Self::HeavySnow,
+            "BLWG SNOW" => Self::BlowingSnow,
+            "FLURRIES" => Self::Flurries,
+            "RAIN/SNOW" | "RAIN/SNO" => Self::RainAndSnow,
+            "SLEET" => Self::Sleet,
+            "TSTM" | "T-STORM" => Self::Thunderstorm,
+            "FOG" => Self::Fog,
+            "HAZE" => Self::Haze,
+            "SMOKE" => Self::Smoke,
+            "DUST" => Self::Dust,
             "FAIR" => Self::Fair,
             "CLEAR" => Self::Clear,
-            "N/A" => Self::NA,
-            _ => return Err(RegionalWeatherSkyConditionParseErr),
+            "N/A" | "NOT AVBL" | "MISG" => Self::NA,
+            "" => return Err(RegionalWeatherSkyConditionParseErr),
+            other => Self::Other(other.to_owned()),
         })
     }
 }
 
 #[cfg(test)]
 mod test {
+    use uom::si::{angle::degree, velocity::mile_per_hour};
+
     use super::*;
 
     #[test]
     pub fn test_rwr() {
-        let _ = RegionalWeatherRoundup::parse(EX_RWR).unwrap_or_else(|e| panic!("{}", e));
+        let rwr = RegionalWeatherRoundup::parse(EX_RWR)
+            .unwrap_or_else(|e| panic!("{}", e))
+            .1;
+
+        assert_eq!(rwr.sections.len(), 9);
+        assert_eq!(
+            rwr.sections[0].name.as_deref(),
+            Some("SEATTLE, TACOMA, EVERETT, AND VICINITY")
+        );
+        assert_eq!(rwr.reports().count(), 52);
+
+        let everett = &rwr.sections[0].reports[0];
+        assert_eq!(everett.city, "EVERETT");
+        assert_eq!(everett.pressure_tendency, Some(PressureTendency::Rising));
+        assert_eq!(everett.supplemental.len(), 3);
+
+        let bremerton = rwr.reports().find(|r| r.city == "BREMERTON").unwrap();
+        assert_eq!(bremerton.sky, RegionalWeatherSkyCondition::Fog);
+        assert_eq!(bremerton.remarks, "VSB 1");
+    }
+
+    #[test]
+    pub fn test_rwr_sections() {
+        let rwr = RegionalWeatherRoundup::parse(EX_RWR_SECTIONS)
+            .unwrap_or_else(|e| panic!("{}", e))
+            .1;
+
+        assert_eq!(rwr.sections.len(), 3);
+        assert_eq!(rwr.sections[1].name.as_deref(), Some("MOUNTAINS"));
+        assert_eq!(rwr.sections[1].zones, rwr.sections[0].zones);
+
+        let first = &rwr.sections[0].reports;
+        assert_eq!(first[0].sky, RegionalWeatherSkyCondition::LightRain);
+        assert_eq!(
+            first[0].wind,
+            Some(RegionalWeatherWind::Directional {
+                direction: Angle::new::<degree>(315f32),
+                speed: Velocity::new::<mile_per_hour>(14f32),
+                gust: Some(Velocity::new::<mile_per_hour>(25f32)),
+            })
+        );
+        assert_eq!(first.len(), 4);
+        assert_eq!(first[1].city, "ARLINGTON");
+        assert_eq!(first[1].sky, RegionalWeatherSkyCondition::NA);
+        assert_eq!(first[1].temperature, None);
+        assert_eq!(first[1].pressure, None);
+        assert_eq!(first[2].temperature, None);
+        assert_eq!(first[2].wind, None);
+        assert_eq!(first[3].sky, RegionalWeatherSkyCondition::NA);
+        assert!(first[3].pressure.is_none());
+        assert_eq!(first[3].supplemental.len(), 3);
+
+        let columns = RegionalWeatherRoundupColumns {
+            sky: 15,
+            temperature: 25,
+        };
+        let (_, emmett) = RegionalWeatherRoundupItem::parse(columns)("EMMETT").unwrap();
+        assert_eq!(emmett.city, "EMMETT");
+        assert_eq!(emmett.sky, RegionalWeatherSkyCondition::NA);
+        assert_eq!(emmett.wind, None);
+        let (_, weiser) =
+            RegionalWeatherRoundupItem::parse(columns)("WEISER         CLOUDY    NOT AVBL")
+                .unwrap();
+        assert_eq!(weiser.sky, RegionalWeatherSkyCondition::Cloudy);
+        assert_eq!(weiser.temperature, None);
     }
 
     const EX_RWR: &str = include_str!("test/rwr.txt");
+    const EX_RWR_SECTIONS: &str = include_str!("test/rwr_sections.txt");
 }
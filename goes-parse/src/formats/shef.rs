@@ -0,0 +1,737 @@
+//! Parsing for products encoded in SHEF (Standard Hydrometeorological Exchange Format)
+//!
+//! `.A`, `.B`, and `.E` messages and their continuation lines are decoded into a flat list of
+//! values, each with the station, observation time, and parameter code that it was sent with.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use nom::{character::complete::multispace1, sequence::terminated};
+use uom::si::{
+    f32::{Length, ThermodynamicTemperature, Velocity, VolumeRate},
+    length::{centimeter, foot, inch, meter, millimeter},
+    thermodynamic_temperature::{degree_celsius, degree_fahrenheit},
+    velocity::{meter_per_second, mile_per_hour},
+    volume_rate::{cubic_foot_per_second, cubic_meter_per_second},
+};
+
+use crate::{formats::code_field, header::WMOProductIdentifier, ParseResult};
+
+/// All values decoded from the SHEF messages of a product
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ShefProduct {
+    pub header: WMOProductIdentifier,
+    pub values: Vec<ShefValue>,
+}
+
+/// A single value reported for a station
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShefValue {
+    pub station: String,
+    pub time: DateTime<Utc>,
+    pub element: ShefParameter,
+    pub value: ShefData,
+    /// Data qualifier code, i.e. `E` for estimated or `R` for questionable
+    pub qualifier: Option<char>,
+    pub units: ShefUnits,
+    /// If the value was sent in a revision (`.AR`, `.BR`, `.ER`) message
+    pub revised: bool,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShefData {
+    Value(f32),
+    Trace,
+    Missing,
+}
+
+/// A full `PEDTSEP` parameter code, with any omitted parts filled in with their defaults
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShefParameter {
+    /// Two letter physical element code, i.e. `HG` for river stage
+    pub physical_element: String,
+    /// Duration code, `I` for instantaneous values
+    pub duration: char,
+    /// Two letter type and source code, `RZ` for nonspecific reported values
+    pub type_source: String,
+    /// Extremum code, `Z` for none
+    pub extremum: char,
+    /// Probability code, `Z` for none
+    pub probability: char,
+}
+
+/// Unit system of values as set by the `DU` data element
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShefUnits {
+    #[default]
+    English,
+    Metric,
+}
+
+/// A value converted to a physical quantity according to its physical element and units
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShefQuantity {
+    Length(Length),
+    Temperature(ThermodynamicTemperature),
+    Velocity(Velocity),
+    Flow(VolumeRate),
+}
+
+impl ShefProduct {
+    /// Create a parser for a product, where dates that omit the year are placed in the year
+    /// nearest to `reference`
+    pub fn parse<'a>(reference: NaiveDate) -> impl FnMut(&'a str) -> ParseResult<&'a str, Self> {
+        move |input| Self::parse_full(input, reference)
+    }
+
+    pub fn parse_full(input: &str, reference: NaiveDate) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut messages: Vec<Message> = vec![];
+        let mut in_body = false;
+
+        for line in input.lines() {
+            let line = strip_comments(line);
+            let line = line.trim_end();
+
+            let Some(rest) = line.strip_prefix('.') else {
+                if in_body && !line.trim().is_empty() {
+                    if let Some(message) = messages.last_mut() {
+                        message.body.push(line.to_owned());
+                    }
+                }
+                continue;
+            };
+
+            let (keyword, data) = next_word(rest);
+            if keyword == "END" {
+                in_body = false;
+                continue;
+            }
+
+            let mut chars = keyword.chars();
+            let format = match chars.next() {
+                Some(f @ ('A' | 'B' | 'E')) => f,
+                _ => continue,
+            };
+            let modifiers = chars.as_str();
+            let revised = modifiers.starts_with('R');
+            let continuation = modifiers.trim_start_matches('R');
+
+            if !continuation.is_empty() {
+                match messages.last_mut() {
+                    Some(message) if message.format == format => message.extend(data),
+                    _ => log::warn!("SHEF continuation line without a message: {}", line),
+                }
+                continue;
+            }
+
+            in_body = false;
+            match Message::parse(format, revised, data, reference) {
+                Some(message) => {
+                    in_body = format == 'B';
+                    messages.push(message);
+                }
+                None => log::warn!("Invalid SHEF message header: {}", line),
+            }
+        }
+
+        let mut values = vec![];
+        for message in messages {
+            match message.format {
+                'A' => message.decode_a(reference, &mut values),
+                'B' => message.decode_b(reference, &mut values),
+                _ => message.decode_e(reference, &mut values),
+            }
+        }
+
+        Ok(("", Self { header, values }))
+    }
+}
+
+impl ShefValue {
+    /// Convert the value to a physical quantity for common physical elements with known units,
+    /// returning `None` for trace, missing, or unitless values
+    pub fn quantity(&self) -> Option<ShefQuantity> {
+        let ShefData::Value(value) = self.value else {
+            return None;
+        };
+        let metric = self.units == ShefUnits::Metric;
+
+        Some(match self.element.physical_element.as_str() {
+            pe if pe.starts_with('H') && pe != "HI" => ShefQuantity::Length(match metric {
+                true => Length::new::<meter>(value),
+                false => Length::new::<foot>(value),
+            }),
+            "PP" | "PC" | "SW" => ShefQuantity::Length(match metric {
+                true => Length::new::<millimeter>(value),
+                false => Length::new::<inch>(value),
+            }),
+            "SD" | "SF" => ShefQuantity::Length(match metric {
+                true => Length::new::<centimeter>(value),
+                false => Length::new::<inch>(value),
+            }),
+            "TA" | "TD" | "TW" => ShefQuantity::Temperature(match metric {
+                true => ThermodynamicTemperature::new::<degree_celsius>(value),
+                false => ThermodynamicTemperature::new::<degree_fahrenheit>(value),
+            }),
+            "US" | "UG" => ShefQuantity::Velocity(match metric {
+                true => Velocity::new::<meter_per_second>(value),
+                false => Velocity::new::<mile_per_hour>(value),
+            }),
+            //English discharges are reported in thousands of cubic feet per second
+            "QR" | "QT" | "QI" => ShefQuantity::Flow(match metric {
+                true => VolumeRate::new::<cubic_meter_per_second>(value),
+                false => VolumeRate::new::<cubic_foot_per_second>(value * 1000f32),
+            }),
+            _ => return None,
+        })
+    }
+}
+
+impl ShefParameter {
+    /// Parse a parameter code of between two and seven characters, expanding the common send
+    /// codes for daily extremes
+    pub fn parse(code: &str) -> Option<Self> {
+        let code = match code {
+            "TX" => "TAIRZXZ",
+            "TN" => "TAIRZNZ",
+            "HX" => "HGIRZXZ",
+            "HN" => "HGIRZNZ",
+            "QX" => "QRIRZXZ",
+            "QN" => "QRIRZNZ",
+            code => code,
+        };
+
+        if !(2..=7).contains(&code.len())
+            || !code
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+            || !code.bytes().take(2).all(|b| b.is_ascii_uppercase())
+        {
+            return None;
+        }
+
+        let part = |index: usize, default: char| code[index..].chars().next().unwrap_or(default);
+        let type_source = match code.len() {
+            ..=3 => "RZ".to_owned(),
+            4 => format!("{}Z", &code[3..4]),
+            _ => code[3..5].to_owned(),
+        };
+
+        Some(Self {
+            physical_element: code[..2].to_owned(),
+            duration: part(2, 'I'),
+            type_source,
+            extremum: if code.len() > 5 { part(5, 'Z') } else { 'Z' },
+            probability: if code.len() > 6 { part(6, 'Z') } else { 'Z' },
+        })
+    }
+}
+
+/// Time zone code of a message, with `daylight` unset for local time that follows US daylight
+/// saving time rules
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ShefTimeZone {
+    /// Offset of standard time from UTC in minutes
+    offset: i64,
+    daylight: Option<bool>,
+}
+
+impl ShefTimeZone {
+    const UTC: Self = Self {
+        offset: 0,
+        daylight: Some(false),
+    };
+
+    fn parse(code: &str) -> Option<Self> {
+        let mut chars = code.chars();
+        let offset = match chars.next()? {
+            'Z' if code.len() == 1 => return Some(Self::UTC),
+            'N' => -210,
+            'A' => -240,
+            'E' => -300,
+            'C' => -360,
+            'M' => -420,
+            'P' => -480,
+            'L' => -540,
+            'H' => -600,
+            'B' => -660,
+            _ => return None,
+        };
+
+        let daylight = match chars.as_str() {
+            "" if code == "H" => Some(false),
+            "" => None,
+            "S" => Some(false),
+            "D" => Some(true),
+            _ => return None,
+        };
+
+        Some(Self { offset, daylight })
+    }
+
+    fn to_utc(self, local: NaiveDateTime) -> DateTime<Utc> {
+        let daylight = self.daylight.unwrap_or_else(|| {
+            //Daylight time runs from the second Sunday in March to the first Sunday in November
+            let change = |month, n| {
+                NaiveDate::from_weekday_of_month_opt(local.year(), month, Weekday::Sun, n)
+                    .and_then(|d| d.and_hms_opt(2, 0, 0))
+            };
+            match (change(3, 2), change(11, 1)) {
+                (Some(start), Some(end)) => local >= start && local < end,
+                _ => false,
+            }
+        });
+
+        let offset = self.offset + if daylight { 60 } else { 0 };
+        Utc.from_utc_datetime(&(local - Duration::minutes(offset)))
+    }
+}
+
+/// Date, time, and data elements in effect for the values of a message
+#[derive(Clone, Debug)]
+struct Context {
+    zone: ShefTimeZone,
+    date: NaiveDate,
+    /// Explicit hour, minute, and second, where an hour of 24 is the end of the day
+    time: Option<(u32, u32, u32)>,
+    relative: Duration,
+    interval: Option<Duration>,
+    units: ShefUnits,
+    qualifier: Option<char>,
+}
+
+impl Context {
+    /// Time of values when no hour is given, the end of the day in local time or noon in UTC
+    fn default_time(&self) -> (u32, u32, u32) {
+        match self.zone == ShefTimeZone::UTC {
+            true => (12, 0, 0),
+            false => (24, 0, 0),
+        }
+    }
+
+    fn time(&self) -> Option<DateTime<Utc>> {
+        let (hour, minute, second) = self.time.unwrap_or_else(|| self.default_time());
+        let local = self.date.and_hms_opt(0, minute, second)?
+            + Duration::hours(hour.into())
+            + self.relative;
+        Some(self.zone.to_utc(local))
+    }
+
+    /// Apply a date or data type element such as `DH12` or `DUS`
+    fn apply(&mut self, element: &str, reference: NaiveDate) -> Option<()> {
+        let number = |range| code_field::<u32>(element, range);
+        let rest = element.get(2..)?;
+
+        match element.get(..2)? {
+            "DH" => self.time = Some(time_of_day(rest)?),
+            "DN" => {
+                let (hour, ..) = self.time.unwrap_or_else(|| self.default_time());
+                self.time = Some((hour, number(2..4)?, 0));
+            }
+            "DD" => {
+                self.date = self.date.with_day(number(2..4)?)?;
+                self.set_time(element.get(4..)?)?;
+            }
+            "DM" => {
+                self.date = nearest_date(number(2..4)?, number(4..6)?, reference)?;
+                self.set_time(element.get(6..)?)?;
+            }
+            "DY" => {
+                self.date = NaiveDate::from_ymd_opt(
+                    century(number(2..4)?, reference),
+                    number(4..6)?,
+                    number(6..8)?,
+                )?;
+                self.set_time(element.get(8..)?)?;
+            }
+            "DT" => {
+                self.date =
+                    NaiveDate::from_ymd_opt(number(2..6)? as i32, number(6..8)?, number(8..10)?)?;
+                self.set_time(element.get(10..)?)?;
+            }
+            "DR" => self.relative = time_span(rest)?,
+            "DI" => self.interval = Some(time_span(rest)?),
+            "DU" => {
+                self.units = match rest {
+                    "E" => ShefUnits::English,
+                    "S" => ShefUnits::Metric,
+                    _ => return None,
+                }
+            }
+            "DQ" => self.qualifier = Some(rest.chars().next()?),
+            //Creation dates and variable durations do not change the values
+            "DC" | "DV" => (),
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    /// Set the time of day if one follows a date element
+    fn set_time(&mut self, time: &str) -> Option<()> {
+        if !time.is_empty() {
+            self.time = Some(time_of_day(time)?);
+        }
+        Some(())
+    }
+
+    fn value(
+        &self,
+        station: &str,
+        element: ShefParameter,
+        (value, qualifier): (ShefData, Option<char>),
+        revised: bool,
+    ) -> Option<ShefValue> {
+        Some(ShefValue {
+            station: station.to_owned(),
+            time: self.time()?,
+            element,
+            value,
+            qualifier: qualifier.or(self.qualifier),
+            units: self.units,
+            revised,
+        })
+    }
+}
+
+/// A message header line with all of its continuation lines
+struct Message {
+    format: char,
+    revised: bool,
+    /// Station of `.A` and `.E` messages, or the originator of `.B` messages
+    station: String,
+    context: Context,
+    data: String,
+    /// Station lines of a `.B` message
+    body: Vec<String>,
+}
+
+impl Message {
+    fn parse(format: char, revised: bool, header: &str, reference: NaiveDate) -> Option<Self> {
+        let (station, rest) = next_word(header);
+        let (date, rest) = next_word(rest);
+        let (zone, data) = match next_word(rest) {
+            (zone, data) if ShefTimeZone::parse(zone).is_some() => {
+                (ShefTimeZone::parse(zone)?, data)
+            }
+            _ => (ShefTimeZone::UTC, rest),
+        };
+
+        let number = |range| code_field::<u32>(date, range);
+        let date = match date.len() {
+            4 => nearest_date(number(0..2)?, number(2..4)?, reference)?,
+            6 => NaiveDate::from_ymd_opt(
+                century(number(0..2)?, reference),
+                number(2..4)?,
+                number(4..6)?,
+            )?,
+            8 => NaiveDate::from_ymd_opt(number(0..4)? as i32, number(4..6)?, number(6..8)?)?,
+            _ => return None,
+        };
+
+        Some(Self {
+            format,
+            revised,
+            station: station.to_owned(),
+            context: Context {
+                zone,
+                date,
+                time: None,
+                relative: Duration::zero(),
+                interval: None,
+                units: ShefUnits::English,
+                qualifier: None,
+            },
+            data: data.trim().to_owned(),
+            body: vec![],
+        })
+    }
+
+    /// Append the data of a continuation line
+    fn extend(&mut self, data: &str) {
+        let data = data.trim();
+        if !data.starts_with('/') && !self.data.ends_with('/') {
+            self.data.push('/');
+        }
+        self.data.push_str(data);
+    }
+
+    /// Decode `PE value` pairs and date elements from the data string
+    fn decode_a(&self, reference: NaiveDate, values: &mut Vec<ShefValue>) {
+        let mut context = self.context.clone();
+        for field in self.data.split('/').map(str::trim) {
+            if is_date_element(field) {
+                if context.apply(field, reference).is_none() {
+                    log::warn!("Invalid SHEF date element {} for {}", field, self.station);
+                }
+                continue;
+            }
+
+            let (code, value) = next_word(field);
+            let Some(element) = ShefParameter::parse(code) else {
+                if !field.is_empty() {
+                    log::warn!("Invalid SHEF parameter {} for {}", code, self.station);
+                }
+                continue;
+            };
+            values.extend(
+                parse_value(value)
+                    .and_then(|value| context.value(&self.station, element, value, self.revised)),
+            );
+        }
+    }
+
+    /// Decode each station line against the parameters and date elements of the header
+    fn decode_b(&self, reference: NaiveDate, values: &mut Vec<ShefValue>) {
+        let header = self
+            .data
+            .split('/')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .collect::<Vec<_>>();
+
+        for line in self.body.iter() {
+            let (station, data) = next_word(line);
+            let mut context = self.context.clone();
+            let mut fields = header.iter();
+            //Date elements in a station line apply to the remaining values of that line only
+            let mut overrides = vec![];
+
+            for field in data.split('/').map(str::trim) {
+                if is_date_element(field) {
+                    overrides.push(field);
+                    continue;
+                }
+
+                let element = loop {
+                    match fields.next() {
+                        Some(f) if is_date_element(f) => {
+                            if context.apply(f, reference).is_none() {
+                                log::warn!("Invalid SHEF date element {} for {}", f, station);
+                            }
+                        }
+                        f => break f,
+                    }
+                };
+                let Some(element) = element else {
+                    break;
+                };
+                let Some(element) = ShefParameter::parse(element) else {
+                    continue;
+                };
+
+                let mut context = context.clone();
+                for element in overrides.iter() {
+                    if context.apply(element, reference).is_none() {
+                        log::warn!("Invalid SHEF date element {} for {}", element, station);
+                    }
+                }
+                values.extend(
+                    parse_value(field)
+                        .and_then(|value| context.value(station, element, value, self.revised)),
+                );
+            }
+        }
+    }
+
+    /// Decode a series of values for one parameter spaced by the `DI` interval
+    fn decode_e(&self, reference: NaiveDate, values: &mut Vec<ShefValue>) {
+        let mut context = self.context.clone();
+        let mut element = None;
+        let mut index = 0;
+
+        for field in self.data.split('/').map(str::trim) {
+            if is_date_element(field) {
+                if context.apply(field, reference).is_none() {
+                    log::warn!("Invalid SHEF date element {} for {}", field, self.station);
+                }
+                continue;
+            }
+
+            let Some(element) = element.as_ref() else {
+                if !field.is_empty() {
+                    element = ShefParameter::parse(field);
+                    if element.is_none() {
+                        log::warn!("Invalid SHEF parameter {} for {}", field, self.station);
+                        return;
+                    }
+                }
+                continue;
+            };
+            let Some(interval) = context.interval else {
+                log::warn!("SHEF .E message for {} has no interval", self.station);
+                return;
+            };
+
+            let mut context = context.clone();
+            context.relative += interval * index;
+            values.extend(parse_value(field).and_then(|value| {
+                context.value(&self.station, element.clone(), value, self.revised)
+            }));
+            index += 1;
+        }
+    }
+}
+
+/// Remove comments, which are enclosed between colons or run from a colon to the end of the line
+fn strip_comments(line: &str) -> String {
+    line.split(':').step_by(2).collect::<Vec<_>>().join(" ")
+}
+
+/// Split the first whitespace delimited word from a string
+fn next_word(input: &str) -> (&str, &str) {
+    let input = input.trim_start();
+    input.split_at(input.find(char::is_whitespace).unwrap_or(input.len()))
+}
+
+fn is_date_element(field: &str) -> bool {
+    field.starts_with('D')
+        && field.len() > 2
+        && field[1..2].bytes().all(|b| b.is_ascii_uppercase())
+        && !field.contains(char::is_whitespace)
+}
+
+/// Parse a value with an optional trailing qualifier, returning `None` for an empty field
+fn parse_value(field: &str) -> Option<(ShefData, Option<char>)> {
+    //Retained comments are enclosed in quotes after the value
+    let mut words = field.split('"').next()?.split_whitespace();
+    let value = words.next()?;
+    let qualifier = words
+        .next()
+        .filter(|q| q.len() == 1)
+        .and_then(|q| q.chars().next())
+        .filter(char::is_ascii_alphabetic);
+
+    Some(match value {
+        "M" | "MM" | "-9999" => (ShefData::Missing, qualifier),
+        "T" => (ShefData::Trace, qualifier),
+        value => {
+            let (number, suffix) = match value.strip_suffix(|c: char| c.is_ascii_alphabetic()) {
+                Some(number) => (number, value.chars().last()),
+                None => (value, None),
+            };
+            (
+                ShefData::Value(number.parse::<f32>().ok()?),
+                qualifier.or(suffix),
+            )
+        }
+    })
+}
+
+/// Parse a time of day as `hh`, `hhnn`, or `hhnnss`
+fn time_of_day(time: &str) -> Option<(u32, u32, u32)> {
+    let number = |range| code_field::<u32>(time, range);
+    match time.len() {
+        2 => Some((number(0..2)?, 0, 0)),
+        4 => Some((number(0..2)?, number(2..4)?, 0)),
+        6 => Some((number(0..2)?, number(2..4)?, number(4..6)?)),
+        _ => None,
+    }
+}
+
+/// Parse a signed span such as `H-6` from a relative date or interval element
+fn time_span(span: &str) -> Option<Duration> {
+    let amount = span.get(1..)?.parse::<i64>().ok()?;
+    match span.get(..1)? {
+        "S" => Some(Duration::seconds(amount)),
+        "N" => Some(Duration::minutes(amount)),
+        "H" => Some(Duration::hours(amount)),
+        "D" => Some(Duration::days(amount)),
+        _ => None,
+    }
+}
+
+/// Find the date with the given month and day that is nearest to the reference date
+fn nearest_date(month: u32, day: u32, reference: NaiveDate) -> Option<NaiveDate> {
+    (reference.year() - 1..=reference.year() + 1)
+        .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+        .min_by_key(|date| (*date - reference).num_days().abs())
+}
+
+/// Expand a two digit year to the century that does not put it far after the reference date
+fn century(year: u32, reference: NaiveDate) -> i32 {
+    let year = 2000 + year as i32;
+    match year > reference.year() + 10 {
+        true => year - 100,
+        false => year,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn utc(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    pub fn test_shef() {
+        let reference = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let shef = ShefProduct::parse(reference)(EX_SHEF)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(shef.values.len(), 22);
+
+        //Local mountain time follows daylight saving time
+        let max = &shef.values[0];
+        assert_eq!(max.station, "DENC2");
+        assert_eq!(max.time, utc(18, 12, 0));
+        assert_eq!(max.element, ShefParameter::parse("TAIRZXZ").unwrap());
+        assert_eq!(max.value, ShefData::Value(68f32));
+        assert_eq!(
+            max.quantity(),
+            Some(ShefQuantity::Temperature(ThermodynamicTemperature::new::<
+                degree_fahrenheit,
+            >(68f32)))
+        );
+        assert_eq!(shef.values[2].element.duration, 'D');
+        assert_eq!(shef.values[4].element.physical_element, "SF");
+        assert_eq!(shef.values[4].value, ShefData::Trace);
+
+        let stage = &shef.values[5];
+        assert!(stage.revised);
+        assert_eq!(stage.time, utc(18, 12, 30));
+        assert_eq!(stage.value, ShefData::Value(4.52));
+        assert_eq!(stage.qualifier, Some('E'));
+        assert_eq!(
+            shef.values[6].quantity(),
+            Some(ShefQuantity::Flow(
+                VolumeRate::new::<cubic_foot_per_second>(350f32)
+            ))
+        );
+        assert_eq!(shef.values[7].units, ShefUnits::English);
+        assert_eq!(shef.values[8].units, ShefUnits::Metric);
+
+        let b = &shef.values[9..15];
+        assert_eq!(b[0].station, "PLTC2");
+        assert_eq!(b[0].time, utc(18, 13, 0));
+        assert_eq!(b[1].element.physical_element, "PP");
+        assert_eq!(b[1].element.duration, 'H');
+        assert_eq!(b[2].qualifier, Some('E'));
+        assert_eq!(b[3].value, ShefData::Missing);
+        assert_eq!(b[4].station, "BOXC2");
+        assert_eq!(b[4].time, utc(18, 14, 0));
+        assert_eq!(b[5].value, ShefData::Trace);
+
+        let e = &shef.values[15..21];
+        assert!(e.iter().all(|v| v.station == "CCRC2"));
+        assert_eq!(e[0].time, utc(18, 12, 0));
+        assert_eq!(e[3].time, utc(18, 15, 0));
+        assert_eq!(e[3].value, ShefData::Value(2.08));
+        assert_eq!(e[4].value, ShefData::Missing);
+        assert_eq!(e[5].time, utc(18, 17, 0));
+
+        assert_eq!(shef.values[21].time, utc(18, 12, 0));
+    }
+
+    const EX_SHEF: &str = include_str!("test/shef.txt");
+}
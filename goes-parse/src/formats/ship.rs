@@ -0,0 +1,403 @@
+//! Parsing for FM 13 SHIP reports of surface observations from sea stations
+
+use nom::{
+    character::complete::{multispace0, multispace1},
+    sequence::{preceded, terminated},
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{
+    angle::degree,
+    f32::{Angle, Length, Pressure, ThermodynamicTemperature, Time, Velocity},
+    length::meter,
+    pressure::hectopascal,
+    thermodynamic_temperature::degree_celsius,
+    time::second,
+    velocity::{knot, meter_per_second},
+};
+
+use crate::{
+    formats::{
+        code_field, code_pressure, code_signed_tenths,
+        codes::{
+            sea::{IceAccretion, Waves},
+            wind::WindSummary,
+        },
+        LatitudeDir, LongitudeDir,
+    },
+    header::WMOProductIdentifier,
+    parse::time::DayHourMinute,
+    ParseResult,
+};
+
+/// A bulletin of SHIP reports
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ShipReport {
+    pub header: WMOProductIdentifier,
+    pub items: Vec<ShipReportItem>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShipReportItem {
+    /// Call sign of the ship
+    pub identifier: String,
+    pub time: DayHourMinute,
+    pub lat: Angle,
+    pub lon: Angle,
+    pub observation: MarineObservation,
+}
+
+/// Weather and sea conditions observed by a ship or buoy
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MarineObservation {
+    /// Wind observed at the station, with variable winds given a direction of 0 degrees
+    pub wind: Option<WindSummary>,
+    pub air_temperature: Option<ThermodynamicTemperature>,
+    pub dew_point: Option<ThermodynamicTemperature>,
+    /// Ranging from [0., 1.], given in place of the dew point by some stations
+    pub relative_humidity: Option<f32>,
+    pub station_pressure: Option<Pressure>,
+    pub sea_level_pressure: Option<Pressure>,
+    /// Change in pressure over the three hours preceding the observation
+    pub pressure_change: Option<Pressure>,
+    pub sea_temperature: Option<ThermodynamicTemperature>,
+    /// Waves measured by instruments
+    pub waves: Option<Waves>,
+    /// Wind waves estimated by an observer
+    pub wind_waves: Option<Waves>,
+    /// Up to two swell systems
+    pub swell: Vec<Waves>,
+    pub ice_accretion: Option<IceAccretion>,
+}
+
+impl ShipReport {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+        let (input, _) = preceded(multispace0, tag("BBXX"))(input)?;
+
+        let items = input
+            .split('=')
+            .filter_map(|report| {
+                let mut groups = report.split_whitespace().skip_while(|g| *g == "BBXX");
+                ShipReportItem::parse(&mut groups)
+            })
+            .collect();
+
+        Ok(("", Self { header, items }))
+    }
+}
+
+impl ShipReportItem {
+    /// Parse the groups of a single report, returning `None` if the report does not contain a
+    /// valid identification and position
+    fn parse<'a>(groups: &mut impl Iterator<Item = &'a str>) -> Option<Self> {
+        let identifier = groups.next()?.to_owned();
+        let date = groups.next()?;
+        let time = DayHourMinute {
+            day: code_field(date, 0..2)?,
+            hour: code_field(date, 2..4)?,
+            minute: 0,
+        };
+        let units = date.get(4..5);
+
+        let lat = groups.next()?.strip_prefix("99")?;
+        let lon = groups.next()?;
+        let (lat_dir, lon_dir) = quadrant(lon.get(..1)?)?;
+        let lat = Angle::new::<degree>(lat_dir.to_north(code_field::<f32>(lat, 0..3)? / 10f32));
+        let lon = Angle::new::<degree>(lon_dir.to_east(code_field::<f32>(lon, 1..5)? / 10f32));
+
+        //Skip the iRixhVV group
+        groups.next();
+
+        let mut observation = MarineObservation {
+            wind: groups
+                .next()
+                .and_then(|wind| wind_group(wind, 1, units, groups)),
+            ..Default::default()
+        };
+
+        let mut section = 1;
+        for group in groups {
+            match group {
+                g if g.starts_with("222") && g.len() == 5 => {
+                    section = 2;
+                    continue;
+                }
+                //Remaining sections contain regional and national data or plain language
+                "333" | "444" | "555" | "ICE" => break,
+                _ => (),
+            }
+
+            match section {
+                1 => observation.set_section1(group),
+                _ => match group.get(..1) {
+                    Some("2") => observation.wind_waves = waves(group, 0.5),
+                    _ => observation.set_section2(group),
+                },
+            }
+        }
+
+        Some(Self {
+            identifier,
+            time,
+            lat,
+            lon,
+            observation,
+        })
+    }
+}
+
+/// Get the hemispheres of a position from the quadrant of the globe Qc (code table 3333)
+pub(crate) fn quadrant(qc: &str) -> Option<(LatitudeDir, LongitudeDir)> {
+    Some(match qc {
+        "1" => (LatitudeDir::North, LongitudeDir::East),
+        "3" => (LatitudeDir::South, LongitudeDir::East),
+        "5" => (LatitudeDir::South, LongitudeDir::West),
+        "7" => (LatitudeDir::North, LongitudeDir::West),
+        _ => return None,
+    })
+}
+
+/// Parse a wind group `ddff` starting at `start` of the group with wind speed units indicator
+/// `iw`, taking the next group if the speed is given as `00fff` for speeds of 99 units or more
+pub(crate) fn wind_group<'a>(
+    group: &str,
+    start: usize,
+    iw: Option<&str>,
+    groups: &mut impl Iterator<Item = &'a str>,
+) -> Option<WindSummary> {
+    let direction = code_field::<f32>(group, start..start + 2)?;
+    let speed = match code_field::<f32>(group, start + 2..start + 4)? {
+        speed if speed >= 99f32 => code_field::<f32>(groups.next()?, 2..5)?,
+        speed => speed,
+    };
+
+    let speed = match iw? {
+        "0" | "1" => Velocity::new::<meter_per_second>(speed),
+        "3" | "4" => Velocity::new::<knot>(speed),
+        _ => return None,
+    };
+
+    Some(WindSummary {
+        direction: match direction {
+            d if d > 36f32 => Angle::new::<degree>(0f32),
+            d => Angle::new::<degree>(d * 10f32),
+        },
+        speed,
+        max_speed: None,
+    })
+}
+
+/// Parse a `PP HH` waves group where the height is given in units of `scale` meters, returning
+/// `None` if both are missing
+fn waves(group: &str, scale: f32) -> Option<Waves> {
+    let period = code_field::<f32>(group, 1..3).map(Time::new::<second>);
+    let height = code_field::<f32>(group, 3..5).map(|h| Length::new::<meter>(h * scale));
+    (period.is_some() || height.is_some()).then_some(Waves {
+        direction: None,
+        period,
+        height,
+    })
+}
+
+impl MarineObservation {
+    /// Set values from a group of section 1 following the wind group
+    pub(crate) fn set_section1(&mut self, group: &str) {
+        let temperature =
+            || code_signed_tenths(group, 1, 3).map(ThermodynamicTemperature::new::<degree_celsius>);
+
+        match group.get(..1) {
+            Some("1") => self.air_temperature = temperature(),
+            Some("2") => match group.get(1..2) {
+                Some("9") => {
+                    self.relative_humidity = code_field::<f32>(group, 2..5).map(|u| u / 100f32)
+                }
+                _ => self.dew_point = temperature(),
+            },
+            Some("3") => self.station_pressure = code_pressure(group),
+            Some("4") => self.sea_level_pressure = code_pressure(group),
+            Some("5") => {
+                let change = code_field::<f32>(group, 2..5).map(|ppp| ppp / 10f32);
+                self.pressure_change = match code_field::<u8>(group, 1..2) {
+                    Some(0..=3) => change,
+                    Some(4) => Some(0f32),
+                    Some(5..=8) => change.map(|ppp| -ppp),
+                    _ => None,
+                }
+                .map(Pressure::new::<hectopascal>);
+            }
+            _ => (),
+        }
+    }
+
+    /// Set values from a group of section 2 following the `222` group
+    pub(crate) fn set_section2(&mut self, group: &str) {
+        match group.get(..1) {
+            Some("0") => {
+                //Odd indicators of code table 3850 are negative temperatures
+                let tenths = code_field::<f32>(group, 2..5).map(|t| t / 10f32);
+                self.sea_temperature = match code_field::<u8>(group, 1..2) {
+                    Some(ss) if ss % 2 == 1 => tenths.map(|t| -t),
+                    Some(_) => tenths,
+                    None => None,
+                }
+                .map(ThermodynamicTemperature::new::<degree_celsius>);
+            }
+            Some("1") => self.waves = waves(group, 0.5),
+            Some("3") => {
+                let directions = [
+                    code_field::<f32>(group, 1..3),
+                    code_field::<f32>(group, 3..5),
+                ];
+                for (idx, direction) in directions.into_iter().enumerate() {
+                    if let Some(direction) = direction {
+                        self.swell_mut(idx).direction =
+                            Some(Angle::new::<degree>(direction * 10f32));
+                    }
+                }
+            }
+            Some(idx @ ("4" | "5")) => {
+                if let Some(Waves { period, height, .. }) = waves(group, 0.5) {
+                    let swell = self.swell_mut(if idx == "4" { 0 } else { 1 });
+                    swell.period = period;
+                    swell.height = height;
+                }
+            }
+            Some("6") => {
+                self.ice_accretion = group
+                    .get(1..)
+                    .and_then(|g| IceAccretion::parse(g).ok())
+                    .map(|(_, ice)| ice)
+                    .filter(|ice| {
+                        ice.cause.is_some() || ice.thickness.is_some() || ice.rate.is_some()
+                    })
+            }
+            Some("7") if group.starts_with("70") => {
+                if let Some(height) = code_field::<f32>(group, 2..5) {
+                    self.waves.get_or_insert_with(Default::default).height =
+                        Some(Length::new::<meter>(height / 10f32));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Get the swell system at the given index, inserting empty systems as needed
+    fn swell_mut(&mut self, idx: usize) -> &mut Waves {
+        while self.swell.len() <= idx {
+            self.swell.push(Waves::default());
+        }
+
+        &mut self.swell[idx]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::formats::codes::sea::{IceAccretionCause, IceAccretionRate, StateOfTheSea};
+
+    use super::*;
+
+    #[test]
+    pub fn test_ship() {
+        let ship = ShipReport::parse(EX_SHIP)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(ship.items.len(), 3);
+
+        let first = &ship.items[0];
+        assert_eq!(first.identifier, "WDC6925");
+        assert_eq!(
+            first.time,
+            DayHourMinute {
+                day: 18,
+                hour: 12,
+                minute: 0
+            }
+        );
+        assert_eq!(first.lat, Angle::new::<degree>(37.6));
+        assert_eq!(first.lon, Angle::new::<degree>(-74f32));
+
+        let obs = &first.observation;
+        assert_eq!(
+            obs.wind,
+            Some(WindSummary {
+                direction: Angle::new::<degree>(210f32),
+                speed: Velocity::new::<knot>(8f32),
+                max_speed: None,
+            })
+        );
+        assert_eq!(
+            obs.sea_level_pressure,
+            Some(Pressure::new::<hectopascal>(1015.4))
+        );
+        assert_eq!(obs.pressure_change, Some(Pressure::new::<hectopascal>(1.2)));
+        assert_eq!(
+            obs.sea_temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(17.8))
+        );
+        assert_eq!(
+            obs.wind_waves,
+            Some(Waves {
+                direction: None,
+                period: Some(Time::new::<second>(4f32)),
+                height: Some(Length::new::<meter>(1.5)),
+            })
+        );
+        assert_eq!(
+            obs.wind_waves.unwrap().state(),
+            Some(StateOfTheSea::Moderate)
+        );
+        assert_eq!(
+            obs.swell,
+            vec![Waves {
+                direction: Some(Angle::new::<degree>(70f32)),
+                period: Some(Time::new::<second>(8f32)),
+                height: Some(Length::new::<meter>(2.5)),
+            }]
+        );
+        assert_eq!(obs.ice_accretion, None);
+
+        let wtec = &ship.items[1];
+        assert_eq!(wtec.lon, Angle::new::<degree>(152.5));
+        let obs = &wtec.observation;
+        assert_eq!(
+            obs.air_temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(-2.1))
+        );
+        assert_eq!(obs.relative_humidity, Some(0.85));
+        assert_eq!(
+            obs.pressure_change,
+            Some(Pressure::new::<hectopascal>(-1.5))
+        );
+        assert_eq!(
+            obs.sea_temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(-1.2))
+        );
+        assert_eq!(obs.waves.unwrap().height, Some(Length::new::<meter>(4.5)));
+        assert_eq!(obs.swell.len(), 2);
+        assert_eq!(obs.swell[1].direction, Some(Angle::new::<degree>(260f32)));
+        assert_eq!(obs.swell[1].height, Some(Length::new::<meter>(2f32)));
+        assert_eq!(
+            obs.ice_accretion,
+            Some(IceAccretion {
+                cause: Some(IceAccretionCause::Spray),
+                thickness: Some(Length::new::<uom::si::length::centimeter>(12f32)),
+                rate: Some(IceAccretionRate::BuildingRapidly),
+            })
+        );
+
+        let third = &ship.items[2];
+        assert_eq!(third.lat, Angle::new::<degree>(-9.1));
+        assert_eq!(
+            third.observation.wind.unwrap().speed,
+            Velocity::new::<meter_per_second>(5f32)
+        );
+        assert_eq!(third.observation.dew_point, None);
+    }
+
+    const EX_SHIP: &str = include_str!("test/ship.txt");
+}
@@ -0,0 +1,523 @@
+//! Parsing for Storm Prediction Center convective outlook points (PTS), watch outline updates
+//! (WOU), and mesoscale discussions (MCD)
+
+use chrono::{DateTime, Utc};
+use nom::{
+    character::complete::{char, multispace1, space0},
+    sequence::{terminated, tuple},
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{angle::degree, f32::Angle};
+
+use crate::{
+    formats::{is_ugc, text_product::Vtec, LatLon},
+    header::WMOProductIdentifier,
+    parse::time::{yygggg, DayHourMinute},
+    ParseResult,
+};
+
+/// Coordinate pair used to separate disconnected lines of the same risk area
+const LINE_BREAK: &str = "99999999";
+
+/// Risk areas of a convective outlook, given as lines that enclose the area to their right
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ConvectiveOutlook {
+    pub header: WMOProductIdentifier,
+    /// Day of the outlook from the `PTSDYn` identifier
+    pub day: Option<u8>,
+    pub valid_start: Option<DayHourMinute>,
+    pub valid_end: Option<DayHourMinute>,
+    pub areas: Vec<ConvectiveOutlookArea>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvectiveOutlookArea {
+    pub hazard: ConvectiveHazard,
+    pub risk: ConvectiveRisk,
+    /// Outlines of the area, split where the outline leaves the domain of the outlook
+    pub lines: Vec<Vec<LatLon>>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConvectiveHazard {
+    Tornado,
+    Hail,
+    Wind,
+    /// Probability of any severe weather given in day 3 and later outlooks
+    AnySevere,
+    Categorical,
+    Other(String),
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConvectiveRisk {
+    Category(RiskCategory),
+    /// Probability of the hazard occurring within 25 miles of a point, ranging from [0., 1.]
+    Probability(f32),
+    /// Area where significant severe weather is possible
+    Significant,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RiskCategory {
+    /// General thunderstorms
+    Thunderstorm,
+    Marginal,
+    Slight,
+    Enhanced,
+    Moderate,
+    High,
+}
+
+/// Counties and times included in a tornado or severe thunderstorm watch
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct WatchOutline {
+    pub header: WMOProductIdentifier,
+    pub kind: WatchType,
+    pub number: u16,
+    pub valid_start: Option<DateTime<Utc>>,
+    /// End of the watch, or `None` if the watch is in effect until further notice
+    pub valid_end: Option<DateTime<Utc>>,
+    /// UGC lines of the counties and marine zones included in the watch
+    pub ugc: Vec<String>,
+    /// Outline of the watch area if given in a `LAT...LON` block
+    pub polygon: Vec<LatLon>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchType {
+    Tornado,
+    SevereThunderstorm,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct MesoscaleDiscussion {
+    pub header: WMOProductIdentifier,
+    pub number: Option<u16>,
+    pub areas_affected: String,
+    pub concerning: String,
+    pub valid_start: Option<DayHourMinute>,
+    pub valid_end: Option<DayHourMinute>,
+    /// Probability of a watch being issued, ranging from [0., 1.]
+    pub watch_probability: Option<f32>,
+    pub summary: String,
+    pub discussion: String,
+    pub polygon: Vec<LatLon>,
+}
+
+impl ConvectiveOutlook {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut day = None;
+        let mut valid = None;
+        let mut hazard = None;
+        let mut areas: Vec<ConvectiveOutlookArea> = vec![];
+
+        for line in input.lines().map(str::trim_end) {
+            if let Some(n) = line.strip_prefix("PTSDY") {
+                day = n.parse().ok();
+                continue;
+            }
+
+            if let Some(times) = line.strip_prefix("VALID TIME ") {
+                valid = valid_range(times);
+                continue;
+            }
+
+            if line.starts_with("&&") {
+                hazard = None;
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix("...").and_then(|l| l.strip_suffix("...")) {
+                hazard = Some(ConvectiveHazard::from(name.trim()));
+                continue;
+            }
+
+            let hazard = match hazard {
+                Some(ref hazard) if !line.trim().is_empty() => hazard,
+                _ => continue,
+            };
+
+            let mut words = line.split_whitespace();
+            if !line.starts_with(' ') {
+                let risk = match words.next().and_then(ConvectiveRisk::parse) {
+                    Some(risk) => risk,
+                    None => continue,
+                };
+
+                areas.push(ConvectiveOutlookArea {
+                    hazard: hazard.clone(),
+                    risk,
+                    lines: vec![vec![]],
+                });
+            }
+
+            if let Some(area) = areas.last_mut() {
+                for word in words {
+                    match word {
+                        LINE_BREAK => area.lines.push(vec![]),
+                        word => area.lines.last_mut().unwrap().extend(parse_point(word)),
+                    }
+                }
+            }
+        }
+
+        let (valid_start, valid_end) = valid.unzip();
+        Ok((
+            "",
+            Self {
+                header,
+                day,
+                valid_start,
+                valid_end,
+                areas,
+            },
+        ))
+    }
+
+    /// Get the areas of the given hazard
+    pub fn hazard<'a>(
+        &'a self,
+        hazard: &'a ConvectiveHazard,
+    ) -> impl Iterator<Item = &'a ConvectiveOutlookArea> + 'a {
+        self.areas.iter().filter(move |area| area.hazard == *hazard)
+    }
+
+    /// Get the highest categorical risk given in the outlook
+    pub fn max_category(&self) -> Option<RiskCategory> {
+        self.areas
+            .iter()
+            .filter_map(|area| match area.risk {
+                ConvectiveRisk::Category(category) => Some(category),
+                _ => None,
+            })
+            .max()
+    }
+}
+
+impl From<&str> for ConvectiveHazard {
+    fn from(value: &str) -> Self {
+        match value {
+            "TORNADO" => Self::Tornado,
+            "HAIL" => Self::Hail,
+            "WIND" => Self::Wind,
+            "ANY SEVERE" | "PROBABILISTIC" => Self::AnySevere,
+            "CATEGORICAL" => Self::Categorical,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+}
+
+impl ConvectiveRisk {
+    fn parse(label: &str) -> Option<Self> {
+        Some(match label {
+            "TSTM" => Self::Category(RiskCategory::Thunderstorm),
+            "MRGL" => Self::Category(RiskCategory::Marginal),
+            "SLGT" => Self::Category(RiskCategory::Slight),
+            "ENH" => Self::Category(RiskCategory::Enhanced),
+            "MDT" => Self::Category(RiskCategory::Moderate),
+            "HIGH" => Self::Category(RiskCategory::High),
+            "SIGN" => Self::Significant,
+            other => Self::Probability(other.parse().ok()?),
+        })
+    }
+}
+
+impl WatchOutline {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut watch = None;
+        let mut valid = None;
+        let mut ugc = vec![];
+        let mut polygon = vec![];
+        let mut in_polygon = false;
+
+        for line in input.lines().map(str::trim_end) {
+            if watch.is_none() {
+                watch = parse_watch(line);
+            }
+
+            if is_ugc(line) {
+                ugc.push(line.to_owned());
+            } else if line.starts_with("/O.") && valid.is_none() {
+                valid = Vtec::parse(line).map(|vtec| (vtec.start, vtec.end));
+            } else if let Some(points) = line.strip_prefix("LAT...LON") {
+                in_polygon = true;
+                polygon.extend(points.split_whitespace().filter_map(parse_point));
+            } else if in_polygon && line.starts_with(' ') {
+                polygon.extend(line.split_whitespace().filter_map(parse_point));
+            } else {
+                in_polygon = false;
+            }
+        }
+
+        let (kind, number) = watch.ok_or_else(|| {
+            nom::Err::Error(nom::error::ParseError::from_error_kind(
+                input,
+                nom::error::ErrorKind::Verify,
+            ))
+        })?;
+
+        let (valid_start, valid_end) = valid.unzip();
+        Ok((
+            "",
+            Self {
+                header,
+                kind,
+                number,
+                valid_start: valid_start.flatten(),
+                valid_end: valid_end.flatten(),
+                ugc,
+                polygon,
+            },
+        ))
+    }
+}
+
+/// Find the type and number of a watch in a line like `TORNADO WATCH 612 IS IN EFFECT`
+fn parse_watch(line: &str) -> Option<(WatchType, u16)> {
+    let (kind, rest) = line.split_once(" WATCH ")?;
+    let kind = match kind.trim() {
+        k if k.ends_with("SEVERE THUNDERSTORM") => WatchType::SevereThunderstorm,
+        k if k.ends_with("TORNADO") => WatchType::Tornado,
+        _ => return None,
+    };
+
+    let number = rest.split_whitespace().next()?.parse().ok()?;
+    Some((kind, number))
+}
+
+/// Parse a range of times like `181630Z - 191200Z`
+fn valid_range(input: &str) -> Option<(DayHourMinute, DayHourMinute)> {
+    tuple((
+        terminated(yygggg, char('Z')),
+        tuple((space0, tag("-"), space0)),
+        terminated(yygggg, char('Z')),
+    ))(input.trim())
+    .ok()
+    .map(|(_, (start, _, end))| (start, end))
+}
+
+/// Parse an `LLLLOOOO` coordinate pair in hundredths of degrees north and west, where the leading
+/// 1 of longitudes of 100 degrees or more is dropped
+fn parse_point(point: &str) -> Option<LatLon> {
+    if point.len() != 8 {
+        return None;
+    }
+
+    let lat = point[..4].parse::<f32>().ok()? / 100f32;
+    let lon = match point[4..].parse::<f32>().ok()? / 100f32 {
+        lon if lon < 50f32 => lon + 100f32,
+        lon => lon,
+    };
+
+    Some(LatLon {
+        lat: Angle::new::<degree>(lat),
+        lon: Angle::new::<degree>(-lon),
+    })
+}
+
+impl MesoscaleDiscussion {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut md = Self {
+            header,
+            number: None,
+            areas_affected: String::new(),
+            concerning: String::new(),
+            valid_start: None,
+            valid_end: None,
+            watch_probability: None,
+            summary: String::new(),
+            discussion: String::new(),
+            polygon: vec![],
+        };
+
+        let mut in_discussion = false;
+        let mut paragraphs = vec![];
+        let mut paragraph: Vec<&str> = vec![];
+        for line in input.lines().map(str::trim) {
+            match line.is_empty() {
+                true if !paragraph.is_empty() => paragraphs.push(std::mem::take(&mut paragraph)),
+                true => (),
+                false => paragraph.push(line),
+            }
+        }
+        paragraphs.push(paragraph);
+
+        for paragraph in paragraphs.iter().filter(|p| !p.is_empty()) {
+            let text = paragraph.join(" ");
+            if let Some(number) = paragraph[0].strip_prefix("Mesoscale Discussion ") {
+                md.number = number.trim().parse().ok();
+                continue;
+            }
+
+            if let Some(times) = text.strip_prefix("Valid ") {
+                if let Some((start, end)) = valid_range(times) {
+                    md.valid_start = Some(start);
+                    md.valid_end = Some(end);
+                }
+                continue;
+            }
+
+            //The discussion ends with the forecaster's name like `..Smith.. 10/18/2026`
+            if text.starts_with("..") {
+                in_discussion = false;
+                continue;
+            }
+
+            let (label, value) = match text.split_once("...") {
+                Some((label, value)) if !label.is_empty() => (label.to_ascii_uppercase(), value),
+                _ => {
+                    if in_discussion {
+                        md.discussion.push_str("\n\n");
+                        md.discussion.push_str(&text);
+                    }
+                    continue;
+                }
+            };
+
+            match label.as_str() {
+                "AREAS AFFECTED" => md.areas_affected = value.to_owned(),
+                "CONCERNING" => md.concerning = value.to_owned(),
+                "PROBABILITY OF WATCH ISSUANCE" => {
+                    md.watch_probability = value
+                        .split_whitespace()
+                        .next()
+                        .and_then(|p| p.parse::<f32>().ok())
+                        .map(|p| p / 100f32)
+                }
+                "SUMMARY" => md.summary = value.to_owned(),
+                "DISCUSSION" => {
+                    md.discussion = value.to_owned();
+                    in_discussion = true;
+                }
+                "LAT" => {
+                    md.polygon = value
+                        .trim_start_matches("LON")
+                        .split_whitespace()
+                        .filter_map(parse_point)
+                        .collect()
+                }
+                _ => (),
+            }
+        }
+
+        Ok(("", md))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn point(lat: f32, lon: f32) -> LatLon {
+        LatLon {
+            lat: Angle::new::<degree>(lat),
+            lon: Angle::new::<degree>(lon),
+        }
+    }
+
+    #[test]
+    pub fn test_pts() {
+        let pts = ConvectiveOutlook::parse(EX_PTS)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(pts.day, Some(1));
+        assert_eq!(
+            pts.valid_end,
+            Some(DayHourMinute {
+                day: 19,
+                hour: 12,
+                minute: 0
+            })
+        );
+        assert_eq!(pts.areas.len(), 8);
+        assert_eq!(pts.max_category(), Some(RiskCategory::Enhanced));
+
+        let tornado = pts.hazard(&ConvectiveHazard::Tornado).collect::<Vec<_>>();
+        assert_eq!(tornado.len(), 2);
+        assert_eq!(tornado[0].risk, ConvectiveRisk::Probability(0.05));
+        assert_eq!(tornado[0].lines[0].len(), 7);
+        assert_eq!(tornado[0].lines[0][0], point(36.21, -98.62));
+        assert_eq!(tornado[1].lines[0].len(), 8);
+
+        let sig = pts.hazard(&ConvectiveHazard::Hail).nth(1).unwrap();
+        assert_eq!(sig.risk, ConvectiveRisk::Significant);
+
+        let tstm = pts.areas.last().unwrap();
+        assert_eq!(
+            tstm.risk,
+            ConvectiveRisk::Category(RiskCategory::Thunderstorm)
+        );
+        assert_eq!(tstm.lines.len(), 2);
+        assert_eq!(tstm.lines[0][0], point(33.03, -102.65));
+        assert_eq!(tstm.lines[1].len(), 3);
+    }
+
+    #[test]
+    pub fn test_wou() {
+        let wou = WatchOutline::parse(EX_WOU)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(wou.kind, WatchType::Tornado);
+        assert_eq!(wou.number, 612);
+        assert_eq!(
+            wou.valid_start,
+            Some(Utc.with_ymd_and_hms(2026, 10, 18, 20, 35, 0).unwrap())
+        );
+        assert_eq!(
+            wou.valid_end,
+            Some(Utc.with_ymd_and_hms(2026, 10, 19, 3, 0, 0).unwrap())
+        );
+        assert_eq!(wou.ugc.len(), 2);
+        assert!(wou.polygon.is_empty());
+    }
+
+    #[test]
+    pub fn test_mcd() {
+        let md = MesoscaleDiscussion::parse(EX_MCD)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(md.number, Some(1987));
+        assert_eq!(
+            md.areas_affected,
+            "Portions of south-central Kansas and northern Oklahoma"
+        );
+        assert_eq!(md.concerning, "Severe potential...Tornado Watch likely");
+        assert_eq!(
+            md.valid_start,
+            Some(DayHourMinute {
+                day: 18,
+                hour: 19,
+                minute: 45
+            })
+        );
+        assert_eq!(md.watch_probability, Some(0.8));
+        assert!(md.summary.starts_with("Supercells capable"));
+        assert!(md.discussion.ends_with("strengthen through early evening."));
+        assert_eq!(md.polygon.len(), 7);
+        assert_eq!(md.polygon[0], md.polygon[6]);
+    }
+
+    const EX_PTS: &str = include_str!("test/pts.txt");
+    const EX_WOU: &str = include_str!("test/wou.txt");
+    const EX_MCD: &str = include_str!("test/mcd.txt");
+}
@@ -0,0 +1,388 @@
+//! Parsing for Space Weather Prediction Center alerts, watches, warnings, and summaries, along
+//! with the Kp index table of the 3-day forecast
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use nom::{character::complete::multispace1, sequence::terminated};
+
+use crate::{header::WMOProductIdentifier, ParseResult};
+
+/// A serial numbered space weather message
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct SpaceWeatherMessage {
+    pub header: WMOProductIdentifier,
+    /// Message code, i.e. `ALTK04` for a K-index of 4 alert
+    pub code: String,
+    pub serial: u32,
+    pub issued: DateTime<Utc>,
+    pub kind: Option<SpaceWeatherMessageType>,
+    /// Title following the message type, i.e. `Geomagnetic K-index of 5 expected`
+    pub title: Option<String>,
+    /// NOAA scale levels given in the message
+    pub scales: Vec<NoaaScale>,
+    /// All `Name: value` fields following the title, in the order given
+    pub fields: Vec<(String, String)>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpaceWeatherMessageType {
+    Alert,
+    Watch,
+    Warning,
+    ExtendedWarning,
+    Summary,
+    Cancellation,
+}
+
+/// A level of one of the NOAA space weather scales, like `G1` or `R3`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoaaScale {
+    pub kind: NoaaScaleType,
+    /// Level from 1 (minor) to 5 (extreme)
+    pub level: u8,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoaaScaleType {
+    GeomagneticStorm,
+    SolarRadiationStorm,
+    RadioBlackout,
+}
+
+/// The 3-day space weather forecast with its predicted Kp index for each 3 hour period
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct SpaceWeatherForecast {
+    pub header: WMOProductIdentifier,
+    pub issued: Option<DateTime<Utc>>,
+    /// Kp forecast ordered by time
+    pub kp: Vec<KpForecast>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KpForecast {
+    /// Beginning of the 3 hour period
+    pub start: DateTime<Utc>,
+    pub kp: f32,
+    /// Geomagnetic storm level given next to the value
+    pub scale: Option<NoaaScale>,
+}
+
+impl SpaceWeatherMessage {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut code = None;
+        let mut serial = None;
+        let mut issued = None;
+        let mut kind = None;
+        let mut title = None;
+        let mut fields: Vec<(String, String)> = vec![];
+        let mut continues = false;
+        let mut daily = None;
+        let mut scales = vec![];
+
+        for line in input.lines().map(str::trim) {
+            //Watches give the highest level for each day on the lines following the heading
+            if line.ends_with("Predicted by Day:") {
+                daily = line.strip_suffix(':').map(str::to_owned);
+                continues = false;
+                continue;
+            }
+            if let Some(name) = &daily {
+                if line.is_empty() {
+                    daily = None;
+                    continue;
+                }
+
+                scales.extend(daily_scales(line));
+                match fields.last_mut() {
+                    Some((last, text)) if last == name => {
+                        text.push(' ');
+                        text.push_str(line);
+                    }
+                    _ => fields.push((name.clone(), line.to_owned())),
+                }
+                continue;
+            }
+
+            let Some((name, value)) = line.split_once(": ").filter(|(name, _)| is_field(name))
+            else {
+                //Fields continue onto the following lines until a blank line
+                match fields.last_mut() {
+                    Some((_, text)) if continues && !line.is_empty() => {
+                        text.push(' ');
+                        text.push_str(line);
+                    }
+                    _ => continues = false,
+                }
+                continue;
+            };
+
+            let value = value.trim();
+            continues = true;
+            match name {
+                "Space Weather Message Code" => code = Some(value.to_owned()),
+                "Serial Number" => serial = value.parse().ok(),
+                "Issue Time" => issued = parse_time(value),
+                name if kind.is_none() && name.bytes().all(|b| !b.is_ascii_lowercase()) => {
+                    kind = match name {
+                        "ALERT" => Some(SpaceWeatherMessageType::Alert),
+                        "WATCH" => Some(SpaceWeatherMessageType::Watch),
+                        "WARNING" => Some(SpaceWeatherMessageType::Warning),
+                        "EXTENDED WARNING" => Some(SpaceWeatherMessageType::ExtendedWarning),
+                        "SUMMARY" => Some(SpaceWeatherMessageType::Summary),
+                        n if n.starts_with("CANCEL") => Some(SpaceWeatherMessageType::Cancellation),
+                        _ => None,
+                    };
+                    title = Some(value.to_owned());
+                    continues = false;
+                }
+                name => fields.push((name.to_owned(), value.to_owned())),
+            }
+        }
+
+        let (Some(code), Some(serial), Some(issued)) = (code, serial, issued) else {
+            return Err(nom::Err::Error(nom::error::ParseError::from_error_kind(
+                input,
+                nom::error::ErrorKind::Verify,
+            )));
+        };
+
+        //Scales are given as `NOAA Scale: G1 - Minor`, ahead of any given by day
+        let scales = fields
+            .iter()
+            .filter(|(name, _)| name.contains("Scale"))
+            .flat_map(|(_, value)| value.split_whitespace())
+            .filter_map(NoaaScale::parse)
+            .chain(scales)
+            .collect();
+
+        Ok((
+            "",
+            Self {
+                header,
+                code,
+                serial,
+                issued,
+                kind,
+                title,
+                scales,
+                fields,
+            },
+        ))
+    }
+
+    /// Get the value of a field by its name
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl NoaaScale {
+    /// Parse a scale level like `G3` or `(S1)`
+    pub fn parse(scale: &str) -> Option<Self> {
+        let scale = scale.trim_matches(|c| c == '(' || c == ')');
+        let kind = match scale.get(..1)? {
+            "G" => NoaaScaleType::GeomagneticStorm,
+            "S" => NoaaScaleType::SolarRadiationStorm,
+            "R" => NoaaScaleType::RadioBlackout,
+            _ => return None,
+        };
+        let level = scale
+            .get(1..)?
+            .parse()
+            .ok()
+            .filter(|l| (1..=5).contains(l))?;
+        Some(Self { kind, level })
+    }
+}
+
+impl SpaceWeatherForecast {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut issued = None;
+        let mut kp = vec![];
+        let mut days: Option<Vec<NaiveDate>> = None;
+        let mut lines = input.lines().map(str::trim);
+
+        while let Some(line) = lines.next() {
+            if let Some(time) = line.strip_prefix(":Issued:") {
+                issued = parse_time(time.trim());
+            } else if line.starts_with("NOAA Kp index breakdown") {
+                //The column headings are the dates of the following table, given without a year
+                let Some(reference) = issued.map(|time| time.date_naive()) else {
+                    continue;
+                };
+                days = lines.find(|line| !line.is_empty()).map(|dates| {
+                    let words = dates.split_whitespace().collect::<Vec<_>>();
+                    words
+                        .chunks(2)
+                        .filter_map(|date| nearest_date(date.join(" ").as_str(), reference))
+                        .collect()
+                });
+            } else if let Some(columns) = days.as_ref() {
+                let Some((hours, values)) = line.split_once("UT ") else {
+                    days = None;
+                    continue;
+                };
+                let Some(hour) = hours.get(..2).and_then(|h| h.parse::<i64>().ok()) else {
+                    continue;
+                };
+
+                let mut column = 0;
+                for word in values.split_whitespace() {
+                    if let Ok(value) = word.parse::<f32>() {
+                        if let Some(day) = columns.get(column) {
+                            kp.push(KpForecast {
+                                start: Utc.from_utc_datetime(
+                                    &(day.and_hms_opt(0, 0, 0).unwrap_or_default()
+                                        + Duration::hours(hour)),
+                                ),
+                                kp: value,
+                                scale: None,
+                            });
+                        }
+                        column += 1;
+                    } else if let Some(last) = kp.last_mut() {
+                        last.scale = NoaaScale::parse(word);
+                    }
+                }
+            }
+        }
+
+        kp.sort_by_key(|forecast| forecast.start);
+
+        Ok(("", Self { header, issued, kp }))
+    }
+}
+
+/// Parse the levels of a line like `Oct 19:  G1 (Minor)   Oct 20:  None (Below G1)`, skipping
+/// days without a storm
+fn daily_scales(line: &str) -> Vec<NoaaScale> {
+    line.split_whitespace()
+        .collect::<Vec<_>>()
+        .windows(2)
+        .filter(|words| words[0].ends_with(':'))
+        .filter_map(|words| NoaaScale::parse(words[1]))
+        .collect()
+}
+
+/// Check if the text before a colon is the name of a field rather than part of a sentence
+fn is_field(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 40
+        && name.starts_with(|c: char| c.is_ascii_uppercase())
+        && !name.contains('.')
+}
+
+/// Parse a time like `2026 Oct 18 1503 UTC`
+fn parse_time(time: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(time, "%Y %b %d %H%M UTC")
+        .ok()
+        .map(|time| Utc.from_utc_datetime(&time))
+}
+
+/// Find the date nearest to `reference` for a month and day like `Oct 18`
+fn nearest_date(date: &str, reference: NaiveDate) -> Option<NaiveDate> {
+    (reference.year() - 1..=reference.year() + 1)
+        .filter_map(|year| NaiveDate::parse_from_str(&format!("{year} {date}"), "%Y %b %d").ok())
+        .min_by_key(|date| (*date - reference).num_days().abs())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_swpc() {
+        let warning = SpaceWeatherMessage::parse(EX_WARNING)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(warning.code, "WARK05");
+        assert_eq!(warning.serial, 1893);
+        assert_eq!(
+            warning.issued,
+            Utc.with_ymd_and_hms(2026, 10, 18, 15, 3, 0).unwrap()
+        );
+        assert_eq!(warning.kind, Some(SpaceWeatherMessageType::Warning));
+        assert_eq!(
+            warning.title.as_deref(),
+            Some("Geomagnetic K-index of 5 expected")
+        );
+        assert_eq!(
+            warning.scales,
+            [NoaaScale {
+                kind: NoaaScaleType::GeomagneticStorm,
+                level: 1
+            }]
+        );
+        assert_eq!(warning.field("Valid To"), Some("2026 Oct 18 2359 UTC"));
+        assert_eq!(warning.field("Warning Condition"), Some("Onset"));
+        assert!(warning
+            .field("Potential Impacts")
+            .unwrap()
+            .ends_with("Aurora may be visible at high latitudes."));
+
+        let watch = SpaceWeatherMessage::parse(EX_WATCH)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(watch.kind, Some(SpaceWeatherMessageType::Watch));
+        assert_eq!(
+            watch.scales,
+            [
+                NoaaScale::parse("G1").unwrap(),
+                NoaaScale::parse("G2").unwrap()
+            ]
+        );
+        assert_eq!(
+            watch.field("Highest Storm Level Predicted by Day"),
+            Some("Oct 19:  G1 (Minor)   Oct 20:  G2 (Moderate)   Oct 21:  None (Below G1)")
+        );
+        assert_eq!(watch.field("Oct 19"), None);
+        assert!(watch
+            .field("Potential Impacts")
+            .unwrap()
+            .starts_with("Area of impact primarily poleward of 55 degrees"));
+
+        let forecast = SpaceWeatherForecast::parse(EX_3DAY)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(forecast.kp.len(), 24);
+        let time = |day, hour| Utc.with_ymd_and_hms(2026, 10, day, hour, 0, 0).unwrap();
+        assert_eq!(
+            forecast.kp[0],
+            KpForecast {
+                start: time(18, 0),
+                kp: 2.67,
+                scale: None
+            }
+        );
+        assert_eq!(forecast.kp[7].start, time(18, 21));
+        assert_eq!(
+            forecast.kp[9],
+            KpForecast {
+                start: time(19, 3),
+                kp: 5f32,
+                scale: NoaaScale::parse("G1"),
+            }
+        );
+        assert_eq!(forecast.kp[10].scale, NoaaScale::parse("G1"));
+        assert_eq!(forecast.kp[23].start, time(20, 21));
+    }
+
+    const EX_WARNING: &str = include_str!("test/swpc_warning.txt");
+    const EX_WATCH: &str = include_str!("test/swpc_watch.txt");
+    const EX_3DAY: &str = include_str!("test/swpc_3day.txt");
+}
@@ -29,6 +29,7 @@ use crate::{
 use super::codes::{clouds::CloudReport, weather::SignificantWeather, wind::WindSummary};
 
 /// Aerodome forecast report in AM 51 TAF format
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub struct TAFReport {
     pub header: WMOProductIdentifier,
@@ -37,7 +38,8 @@ pub struct TAFReport {
 }
 
 /// A single TAF forecast
-#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TAFReportItem {
     pub kind: TAFReportKind,
     pub country: CCCC,
@@ -52,14 +54,15 @@ pub struct TAFReportItem {
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TAFReportKind {
     Report,
     Amendment,
     Correction,
 }
 
-#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TAFReportItemGroup {
     pub kind: TAFReportItemGroupKind,
     pub wind: Option<WindSummary>,
@@ -68,7 +71,8 @@ pub struct TAFReportItemGroup {
     pub clouds: Vec<CloudReport>,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TAFReportItemGroupKind {
     TimeIndicator(DayHourMinute),
     Change(DayHourMinute, DayHourMinute),
@@ -333,10 +337,7 @@ impl TAFReportItemGroup {
             )),
         )(input)?;
 
-        let (input, wind) = preceded(
-            space0,
-            recover(WindSummary::parse, take_till(|c: char| c.is_whitespace())),
-        )(input)?;
+        let (input, wind) = opt(preceded(space1, WindSummary::parse))(input)?;
         let (input, (visibility, weather, clouds)) = parse_vis_weather_clouds(input)?;
 
         let (input, _) = opt(preceded(
@@ -365,7 +366,7 @@ fn parse_vis_weather_clouds(
     Ok(match cavok.is_some() {
         true => (input, (None, vec![], vec![])),
         false => {
-            let (input, visibility) = opt(vvvv)(input)?;
+            let (input, visibility) = opt(preceded(space1, vvvv))(input)?;
 
             let (input, weather) = multi_opt(preceded(
                 space1,
@@ -0,0 +1,477 @@
+//! Parsing for tropical cyclone forecast/advisory (TCM) products
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use nom::{
+    branch::alt,
+    bytes::complete::{take_until, take_while1},
+    character::complete::{char, digit1, multispace1, space0, space1},
+    combinator::{map_opt, opt, rest},
+    sequence::{preceded, terminated, tuple},
+    Parser,
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{
+    angle::degree,
+    f32::{Angle, Length, Pressure, Velocity},
+    length::nautical_mile,
+    pressure::millibar,
+    velocity::knot,
+};
+
+use crate::{
+    formats::parse_decimal_latlon,
+    header::WMOProductIdentifier,
+    parse::{
+        fromstr_n, fromstr_with,
+        time::{gggg, DayHourMinute},
+    },
+    ParseResult,
+};
+
+/// A tropical cyclone forecast/advisory issued by a hurricane center
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct TropicalCycloneAdvisory {
+    pub header: WMOProductIdentifier,
+    /// Classification of the system such as `HURRICANE` or `TROPICAL STORM`
+    pub classification: String,
+    pub name: String,
+    /// Advisory number, which may include a letter for intermediate advisories
+    pub advisory: String,
+    /// ATCF storm identifier such as `AL142024`
+    pub storm_id: Option<String>,
+    pub issued: Option<DateTime<Utc>>,
+    pub center: TropicalCyclonePosition,
+    /// Radius of the circle that the center is known to be within
+    pub position_accuracy: Option<Length>,
+    /// Movement of the center, `None` if the system is stationary
+    pub movement: Option<TropicalCycloneMovement>,
+    pub min_pressure: Option<Pressure>,
+    pub eye_diameter: Option<Length>,
+    pub max_wind: Velocity,
+    pub gusts: Velocity,
+    pub wind_radii: Vec<TropicalCycloneWindRadii>,
+    /// Radii of 12 ft seas
+    pub seas: Option<QuadrantRadii>,
+    pub forecasts: Vec<TropicalCycloneForecast>,
+}
+
+/// Position of the center of a tropical cyclone at a given time
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TropicalCyclonePosition {
+    pub time: DayHourMinute,
+    pub lat: Angle,
+    pub lon: Angle,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TropicalCycloneMovement {
+    /// Direction the center is moving towards
+    pub direction: Angle,
+    pub speed: Velocity,
+}
+
+/// Largest extent of winds of at least `threshold` in each quadrant of the storm
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TropicalCycloneWindRadii {
+    pub threshold: Velocity,
+    pub radii: QuadrantRadii,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadrantRadii {
+    pub northeast: Length,
+    pub southeast: Length,
+    pub southwest: Length,
+    pub northwest: Length,
+}
+
+/// A forecast point of the storm track from a `FORECAST VALID` or `OUTLOOK VALID` section
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TropicalCycloneForecast {
+    pub valid: DayHourMinute,
+    /// Forecast position, `None` if the system is forecast to have dissipated
+    pub position: Option<(Angle, Angle)>,
+    pub max_wind: Option<Velocity>,
+    pub gusts: Option<Velocity>,
+    pub wind_radii: Vec<TropicalCycloneWindRadii>,
+    /// If this point is part of the extended outlook, with larger track and intensity errors
+    pub outlook: bool,
+    /// Qualifier of the forecast point such as `INLAND` or `POST-TROP/EXTRATROP`
+    pub remark: Option<String>,
+}
+
+impl TropicalCycloneAdvisory {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+        let (input, _) = opt(terminated(
+            preceded(tag("TCM"), take_until("\n")),
+            multispace1,
+        ))(input)?;
+
+        let (input, (classification, name, advisory)) = terminated(
+            tuple((
+                take_until(" FORECAST/ADVISORY"),
+                preceded(
+                    tuple((tag(" FORECAST/ADVISORY NUMBER"), space1)),
+                    take_until("\n"),
+                ),
+            ))
+            .map(|(title, advisory): (&str, &str)| {
+                let (classification, name) = title.rsplit_once(' ').unwrap_or(("", title));
+                (classification, name, advisory.trim())
+            }),
+            multispace1,
+        )(input)?;
+
+        let mut storm_id = None;
+        let mut issued = None;
+        let mut center = None;
+        let mut position_accuracy = None;
+        let mut movement = None;
+        let mut min_pressure = None;
+        let mut eye_diameter = None;
+        let mut max_wind = None;
+        let mut wind_radii = vec![];
+        let mut seas = None;
+        let mut forecasts: Vec<TropicalCycloneForecast> = vec![];
+
+        for line in input.lines().map(str::trim) {
+            if line.starts_with("$$") {
+                break;
+            }
+
+            if storm_id.is_none() && line.contains("HURRICANE CENTER") {
+                storm_id = line
+                    .split_whitespace()
+                    .last()
+                    .filter(|id| id.len() == 8)
+                    .map(str::to_owned);
+            } else if issued.is_none() && line.contains(" UTC ") {
+                issued = NaiveDateTime::parse_from_str(line, "%H%M UTC %a %b %d %Y")
+                    .ok()
+                    .map(|time| Utc.from_utc_datetime(&time));
+            } else if let Ok((_, forecast)) = TropicalCycloneForecast::parse(line) {
+                forecasts.push(forecast);
+            } else if let Some(forecast) = forecasts.last_mut() {
+                if let Ok((_, (wind, gusts))) = max_wind_gusts(line) {
+                    forecast.max_wind = Some(wind);
+                    forecast.gusts = Some(gusts);
+                } else if let Ok((_, radii)) = TropicalCycloneWindRadii::parse(line) {
+                    forecast.wind_radii.push(radii);
+                }
+            } else if let Ok((_, position)) = center_position(line) {
+                center.get_or_insert(position);
+            } else if let Ok((_, accuracy)) = preceded(
+                tag("POSITION ACCURATE WITHIN"),
+                preceded(space1, nautical_miles),
+            )(line)
+            {
+                position_accuracy = Some(accuracy);
+            } else if let Ok((_, mv)) = TropicalCycloneMovement::parse(line) {
+                movement = mv;
+            } else if let Ok((_, pressure)) = preceded(
+                tuple((tag("ESTIMATED MINIMUM CENTRAL PRESSURE"), space1)),
+                fromstr_with(digit1).map(Pressure::new::<millibar>),
+            )(line)
+            {
+                min_pressure = Some(pressure);
+            } else if let Ok((_, diameter)) =
+                preceded(tuple((tag("EYE DIAMETER"), space1)), nautical_miles)(line)
+            {
+                eye_diameter = Some(diameter);
+            } else if let Ok((_, wind)) = max_wind_gusts(line) {
+                max_wind = Some(wind);
+            } else if let Ok((_, radii)) = TropicalCycloneWindRadii::parse(line) {
+                wind_radii.push(radii);
+            } else if let Ok((_, radii)) =
+                preceded(tuple((tag("12 FT SEAS"), dots)), QuadrantRadii::parse)(line)
+            {
+                seas = Some(radii);
+            }
+        }
+
+        let fail = |ctx| {
+            nom::Err::Error(nom::error::ParseError::from_error_kind(
+                ctx,
+                nom::error::ErrorKind::Verify,
+            ))
+        };
+
+        let center = center.ok_or_else(|| fail(input))?;
+        let (max_wind, gusts) = max_wind.ok_or_else(|| fail(input))?;
+
+        Ok((
+            "",
+            Self {
+                header,
+                classification: classification.trim().to_owned(),
+                name: name.to_owned(),
+                advisory: advisory.to_owned(),
+                storm_id,
+                issued,
+                center,
+                position_accuracy,
+                movement,
+                min_pressure,
+                eye_diameter,
+                max_wind,
+                gusts,
+                wind_radii,
+                seas,
+                forecasts,
+            },
+        ))
+    }
+
+    /// Iterate over the positions of the storm track, starting with the current center followed
+    /// by each forecast point that has a position
+    pub fn track(&self) -> impl Iterator<Item = TropicalCyclonePosition> + '_ {
+        std::iter::once(self.center).chain(self.forecasts.iter().filter_map(|forecast| {
+            forecast.position.map(|(lat, lon)| TropicalCyclonePosition {
+                time: forecast.valid,
+                lat,
+                lon,
+            })
+        }))
+    }
+}
+
+/// Parse a run of periods separating a label from its values like `64 KT.......`
+fn dots(input: &str) -> ParseResult<&str, &str> {
+    take_while1(|c| c == '.')(input)
+}
+
+fn nautical_miles(input: &str) -> ParseResult<&str, Length> {
+    terminated(
+        fromstr_with(digit1).map(Length::new::<nautical_mile>),
+        tag(" NM"),
+    )(input)
+}
+
+fn knots(input: &str) -> ParseResult<&str, Velocity> {
+    terminated(fromstr_with(digit1).map(Velocity::new::<knot>), tag(" KT"))(input)
+}
+
+/// Parse a time in `DD/HHMMZ` format
+fn day_time(input: &str) -> ParseResult<&str, DayHourMinute> {
+    tuple((fromstr_n(2), preceded(char('/'), gggg), char('Z')))
+        .map(|(day, time, _)| DayHourMinute {
+            day,
+            hour: time.hour,
+            minute: time.minute,
+        })
+        .parse(input)
+}
+
+/// Parse the current position of the storm like `HURRICANE CENTER LOCATED NEAR 25.6N  84.3W AT
+/// 09/1500Z`
+fn center_position(input: &str) -> ParseResult<&str, TropicalCyclonePosition> {
+    tuple((
+        preceded(
+            tuple((
+                take_until("CENTER LOCATED NEAR"),
+                tag("CENTER LOCATED NEAR"),
+                space1,
+            )),
+            parse_decimal_latlon,
+        ),
+        preceded(tuple((space1, tag("AT"), space1)), day_time),
+    ))
+    .map(|((lat, lon), time)| TropicalCyclonePosition { time, lat, lon })
+    .parse(input)
+}
+
+/// Parse maximum sustained winds and gusts in either the current or forecast format
+fn max_wind_gusts(input: &str) -> ParseResult<&str, (Velocity, Velocity)> {
+    alt((
+        tuple((
+            preceded(tuple((tag("MAX SUSTAINED WINDS"), space1)), knots),
+            preceded(tuple((tag(" WITH GUSTS TO"), space1)), knots),
+        )),
+        tuple((
+            preceded(tuple((tag("MAX WIND"), space1)), knots),
+            preceded(tuple((dots, tag("GUSTS"), space1)), knots),
+        )),
+    ))(input)
+}
+
+impl TropicalCycloneMovement {
+    /// Parse the present movement line, producing `None` for a stationary system
+    pub fn parse(input: &str) -> ParseResult<&str, Option<Self>> {
+        preceded(
+            tag("PRESENT MOVEMENT "),
+            alt((
+                tag("STATIONARY").map(|_| None),
+                tuple((
+                    preceded(
+                        tuple((take_until(" OR "), tag(" OR"), space1)),
+                        fromstr_with(digit1).map(Angle::new::<degree>),
+                    ),
+                    preceded(tuple((tag(" DEGREES AT"), space1)), knots),
+                ))
+                .map(|(direction, speed)| Some(Self { direction, speed })),
+            )),
+        )(input)
+    }
+}
+
+impl TropicalCycloneWindRadii {
+    /// Parse a radii line like `34 KT.......130NE 130SE 100SW 110NW.`
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        tuple((terminated(knots, dots), QuadrantRadii::parse))
+            .map(|(threshold, radii)| Self { threshold, radii })
+            .parse(input)
+    }
+}
+
+impl QuadrantRadii {
+    /// Parse radii in nautical miles for each quadrant like `130NE 130SE 100SW 110NW`
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        fn quadrant<'a>(q: &'static str) -> impl FnMut(&'a str) -> ParseResult<&'a str, Length> {
+            preceded(
+                space0,
+                terminated(
+                    fromstr_with(digit1).map(Length::new::<nautical_mile>),
+                    tag(q),
+                ),
+            )
+        }
+
+        tuple((
+            quadrant("NE"),
+            quadrant("SE"),
+            quadrant("SW"),
+            quadrant("NW"),
+        ))
+        .map(|(northeast, southeast, southwest, northwest)| Self {
+            northeast,
+            southeast,
+            southwest,
+            northwest,
+        })
+        .parse(input)
+    }
+}
+
+impl TropicalCycloneForecast {
+    /// Parse the first line of a forecast point like `FORECAST VALID 10/1200Z 28.0N  81.6W...INLAND`
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, (outlook, valid)) = tuple((
+            alt((
+                tag("FORECAST VALID").map(|_| false),
+                tag("OUTLOOK VALID").map(|_| true),
+            )),
+            preceded(space1, day_time),
+        ))(input)?;
+
+        let (input, position) = opt(preceded(space1, parse_decimal_latlon))(input)?;
+        let (input, remark) = opt(preceded(
+            dots,
+            map_opt(rest, |r: &str| {
+                Some(r.trim()).filter(|r| !r.is_empty()).map(str::to_owned)
+            }),
+        ))(input)?;
+
+        Ok((
+            input,
+            Self {
+                valid,
+                position,
+                max_wind: None,
+                gusts: None,
+                wind_radii: vec![],
+                outlook,
+                remark,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_tcm() {
+        let tcm = TropicalCycloneAdvisory::parse(EX_TCM)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(tcm.classification, "HURRICANE");
+        assert_eq!(tcm.name, "MILTON");
+        assert_eq!(tcm.advisory, "15");
+        assert_eq!(tcm.storm_id.as_deref(), Some("AL142024"));
+        assert_eq!(
+            tcm.issued,
+            Some(Utc.with_ymd_and_hms(2024, 10, 9, 15, 0, 0).unwrap())
+        );
+        assert_eq!(
+            tcm.center,
+            TropicalCyclonePosition {
+                time: DayHourMinute {
+                    day: 9,
+                    hour: 15,
+                    minute: 0
+                },
+                lat: Angle::new::<degree>(25.6),
+                lon: Angle::new::<degree>(-84.3),
+            }
+        );
+        assert_eq!(
+            tcm.position_accuracy,
+            Some(Length::new::<nautical_mile>(10f32))
+        );
+        assert_eq!(
+            tcm.movement,
+            Some(TropicalCycloneMovement {
+                direction: Angle::new::<degree>(45f32),
+                speed: Velocity::new::<knot>(10f32),
+            })
+        );
+        assert_eq!(tcm.min_pressure, Some(Pressure::new::<millibar>(905f32)));
+        assert_eq!(tcm.max_wind, Velocity::new::<knot>(135f32));
+        assert_eq!(tcm.gusts, Velocity::new::<knot>(165f32));
+        assert_eq!(tcm.wind_radii.len(), 3);
+        assert_eq!(
+            tcm.wind_radii[2],
+            TropicalCycloneWindRadii {
+                threshold: Velocity::new::<knot>(34f32),
+                radii: QuadrantRadii {
+                    northeast: Length::new::<nautical_mile>(130f32),
+                    southeast: Length::new::<nautical_mile>(130f32),
+                    southwest: Length::new::<nautical_mile>(100f32),
+                    northwest: Length::new::<nautical_mile>(110f32),
+                }
+            }
+        );
+        assert_eq!(
+            tcm.seas.map(|s| s.northeast),
+            Some(Length::new::<nautical_mile>(240f32))
+        );
+
+        assert_eq!(tcm.forecasts.len(), 5);
+        assert_eq!(tcm.forecasts[0].wind_radii.len(), 3);
+        assert_eq!(tcm.forecasts[1].remark.as_deref(), Some("INLAND"));
+        assert_eq!(tcm.forecasts[2].wind_radii.len(), 2);
+        assert_eq!(tcm.forecasts[2].gusts, Some(Velocity::new::<knot>(85f32)));
+
+        let outlook = &tcm.forecasts[3];
+        assert!(outlook.outlook);
+        assert_eq!(outlook.remark.as_deref(), Some("POST-TROP/EXTRATROP"));
+        assert_eq!(outlook.max_wind, Some(Velocity::new::<knot>(50f32)));
+
+        let dissipated = &tcm.forecasts[4];
+        assert_eq!(dissipated.position, None);
+        assert_eq!(dissipated.remark.as_deref(), Some("DISSIPATED"));
+
+        assert_eq!(tcm.track().count(), 5);
+    }
+
+    const EX_TCM: &str = include_str!("test/tcm.txt");
+}
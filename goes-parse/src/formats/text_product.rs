@@ -0,0 +1,445 @@
+//! Parsing for the common layout of NWS text products, split into the mass news disseminator
+//! header and the segments ending with `$$`, each with their own UGC and VTEC lines
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use nom::{character::complete::multispace1, sequence::terminated};
+
+use crate::{
+    formats::is_ugc,
+    header::WMOProductIdentifier,
+    parse::time::{issuance_time, yygggg, DayHourMinute},
+    ParseResult,
+};
+
+/// A text product split into its header lines and segments
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct TextProduct {
+    pub header: WMOProductIdentifier,
+    /// AWIPS identifier line, i.e. `AFDBOU`
+    pub awips_id: Option<String>,
+    pub mnd: Option<MassNewsDisseminator>,
+    pub segments: Vec<TextProductSegment>,
+}
+
+/// Mass news disseminator header lines giving the product name, issuing office, and issuance time
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MassNewsDisseminator {
+    /// Broadcast instruction line like `BULLETIN - IMMEDIATE BROADCAST REQUESTED`
+    pub broadcast: Option<String>,
+    /// Product name, i.e. `Area Forecast Discussion`
+    pub product: Option<String>,
+    /// Issuing office, i.e. `National Weather Service Denver/Boulder CO`
+    pub office: String,
+    /// Issuance time line as written, i.e. `415 AM MDT Sun Oct 18 2026`
+    pub issuance: String,
+}
+
+/// Issuance time of a product from the last line of its MND header
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Issuance {
+    pub time: DateTime<Utc>,
+    /// Set if the creation time of the WMO heading is more than half an hour from the issuance
+    /// time, which usually means that the local time zone of the issuance time is wrong
+    pub header_mismatch: bool,
+}
+
+/// A part of a product ending with `$$`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextProductSegment {
+    pub ugc: Option<Ugc>,
+    pub vtec: Vec<Vtec>,
+    /// Remaining text of the segment with surrounding blank lines removed
+    pub body: String,
+}
+
+/// Universal geographic code line listing the zones or counties of a segment
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ugc {
+    /// Codes with ranges expanded, i.e. `COZ040` or `KSC015`
+    pub codes: Vec<String>,
+    /// Time that the segment expires
+    pub expires: DayHourMinute,
+}
+
+/// Primary valid time event code line like `/O.NEW.KBOU.HW.W.0012.261018T1800Z-261019T0000Z/`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vtec {
+    /// Product class, `O` for operational products
+    pub class: char,
+    pub action: VtecAction,
+    pub office: String,
+    /// Two letter phenomenon code, i.e. `TO` for tornado or `HW` for high wind
+    pub phenomenon: String,
+    pub significance: VtecSignificance,
+    pub event_number: u16,
+    /// Beginning of the event, unset if the event has already begun
+    pub start: Option<DateTime<Utc>>,
+    /// End of the event, unset if the ending time is not known
+    pub end: Option<DateTime<Utc>>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VtecAction {
+    New,
+    Continued,
+    Extended,
+    /// Extended in area
+    ExtendedArea,
+    /// Extended in both time and area
+    ExtendedBoth,
+    Upgraded,
+    Cancelled,
+    Expired,
+    Corrected,
+    Routine,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VtecSignificance {
+    Warning,
+    Watch,
+    Advisory,
+    Statement,
+    Forecast,
+    Outlook,
+    Synopsis,
+}
+
+impl TextProduct {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+
+        let mut lines = input.lines().map(str::trim_end).peekable();
+        let awips_id = lines
+            .next_if(|line| {
+                (4..=6).contains(&line.len())
+                    && line
+                        .bytes()
+                        .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+            })
+            .map(str::to_owned);
+
+        while lines.next_if(|line| line.is_empty()).is_some() {}
+
+        //The header ends at the issuance time, which is only searched for before the first UGC
+        let start = lines
+            .clone()
+            .take_while(|line| !is_ugc(line) && !line.starts_with("$$"))
+            .position(is_issuance_time);
+        let mnd = start.map(|end| {
+            let mut header = lines
+                .by_ref()
+                .take(end + 1)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>();
+            let issuance = header.pop().unwrap_or_default().to_owned();
+            let office = header.pop().unwrap_or_default().to_owned();
+            let product = header.pop().map(str::to_owned);
+            let broadcast = (!header.is_empty()).then(|| header.join("\n"));
+
+            MassNewsDisseminator {
+                broadcast,
+                product,
+                office,
+                issuance,
+            }
+        });
+
+        let mut segments = vec![];
+        let mut segment: Vec<&str> = vec![];
+        for line in lines {
+            //Text before the first UGC line, like the area description of marine forecasts, is a
+            //segment of its own
+            let starts_segment = is_ugc(line) && !segment.iter().copied().any(is_ugc);
+            if line.starts_with("$$") || starts_segment {
+                segments.extend(TextProductSegment::parse(&segment));
+                segment.clear();
+            }
+
+            if !line.starts_with("$$") {
+                segment.push(line);
+            }
+        }
+        segments.extend(TextProductSegment::parse(&segment));
+
+        Ok((
+            "",
+            Self {
+                header,
+                awips_id,
+                mnd,
+                segments,
+            },
+        ))
+    }
+
+    /// Get the identifier of the issuing office from the end of the AWIPS identifier, i.e. `BOU`
+    /// from `AFDBOU`
+    pub fn office_id(&self) -> Option<&str> {
+        self.awips_id
+            .as_deref()
+            .and_then(|id| id.get(3..))
+            .filter(|id| !id.is_empty())
+    }
+
+    /// Parse the issuance time of the MND header to UTC and check it against the WMO heading
+    pub fn issuance(&self) -> Option<Issuance> {
+        let (_, time) = issuance_time(&self.mnd.as_ref()?.issuance).ok()?;
+        let header_mismatch = self
+            .header
+            .resolve(time)
+            .is_none_or(|header| (header - time).num_minutes().abs() > 30);
+        if header_mismatch {
+            log::warn!(
+                "Issuance time {} does not match the WMO heading {:?}",
+                time,
+                self.header.creation_time
+            );
+        }
+
+        Some(Issuance {
+            time,
+            header_mismatch,
+        })
+    }
+}
+
+impl TextProductSegment {
+    /// Collect the UGC and VTEC lines and body from the lines of a segment, returning `None` if
+    /// the segment is blank
+    fn parse(lines: &[&str]) -> Option<Self> {
+        let mut ugc = None;
+        let mut ugc_lines = String::new();
+        let mut vtec = vec![];
+        let mut body = vec![];
+
+        for line in lines {
+            if ugc.is_none()
+                && body.is_empty()
+                && (is_ugc(line) || (!ugc_lines.is_empty() && line.ends_with('-')))
+            {
+                //UGC lines continue until the expiration time is given
+                ugc_lines.push_str(line.trim());
+                ugc = Ugc::parse(&ugc_lines);
+            } else if let Some(v) = line.starts_with("/O.").then(|| Vtec::parse(line)).flatten() {
+                vtec.push(v);
+            } else if !line.is_empty() || !body.is_empty() {
+                body.push(*line);
+            }
+        }
+
+        while body.last().is_some_and(|line| line.is_empty()) {
+            body.pop();
+        }
+
+        (ugc.is_some() || !body.is_empty()).then(|| Self {
+            ugc,
+            vtec,
+            body: body.join("\n"),
+        })
+    }
+
+    /// Split the body into the sections separated by `&&` lines
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        self.body
+            .split("\n&&")
+            .map(|section| section.trim_matches('\n'))
+            .filter(|section| !section.trim().is_empty())
+    }
+}
+
+impl Ugc {
+    /// Parse UGC groups like `COZ040>042-045-047-190000-`, where a group beginning with a state
+    /// and `C` or `Z` sets the prefix of the numbers that follow and ranges are given with `>`
+    pub fn parse(ugc: &str) -> Option<Self> {
+        let mut codes = vec![];
+        let mut prefix = None;
+
+        for group in ugc.split('-').map(str::trim).filter(|g| !g.is_empty()) {
+            if group.len() == 6 && group.bytes().all(|b| b.is_ascii_digit()) {
+                let (_, expires) = yygggg(group).ok()?;
+                return Some(Self { codes, expires });
+            }
+
+            let numbers = match group.get(..3) {
+                Some(p) if p.bytes().take(2).all(|b| b.is_ascii_uppercase()) => {
+                    prefix = Some(p);
+                    &group[3..]
+                }
+                _ => group,
+            };
+            let prefix = prefix?;
+
+            match numbers.split_once('>') {
+                Some((first, last)) => {
+                    for number in first.parse::<u16>().ok()?..=last.parse::<u16>().ok()? {
+                        codes.push(format!("{}{:03}", prefix, number));
+                    }
+                }
+                None => codes.push(format!("{}{}", prefix, numbers)),
+            }
+        }
+
+        None
+    }
+}
+
+impl Vtec {
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.trim().trim_matches('/').split('.');
+        let class = fields.next()?.chars().next()?;
+        let action = match fields.next()? {
+            "NEW" => VtecAction::New,
+            "CON" => VtecAction::Continued,
+            "EXT" => VtecAction::Extended,
+            "EXA" => VtecAction::ExtendedArea,
+            "EXB" => VtecAction::ExtendedBoth,
+            "UPG" => VtecAction::Upgraded,
+            "CAN" => VtecAction::Cancelled,
+            "EXP" => VtecAction::Expired,
+            "COR" => VtecAction::Corrected,
+            "ROU" => VtecAction::Routine,
+            _ => return None,
+        };
+        let office = fields.next()?.to_owned();
+        let phenomenon = fields.next()?.to_owned();
+        let significance = match fields.next()? {
+            "W" => VtecSignificance::Warning,
+            "A" => VtecSignificance::Watch,
+            "Y" => VtecSignificance::Advisory,
+            "S" => VtecSignificance::Statement,
+            "F" => VtecSignificance::Forecast,
+            "O" => VtecSignificance::Outlook,
+            "N" => VtecSignificance::Synopsis,
+            _ => return None,
+        };
+        let event_number = fields.next()?.parse().ok()?;
+
+        //Times of all zeroes are used when the time is not given
+        let (start, end) = fields.next()?.split_once('-')?;
+        let time = |s: &str| {
+            NaiveDateTime::parse_from_str(s, "%y%m%dT%H%MZ")
+                .ok()
+                .map(|t| Utc.from_utc_datetime(&t))
+        };
+
+        Some(Self {
+            class,
+            action,
+            office,
+            phenomenon,
+            significance,
+            event_number,
+            start: time(start),
+            end: time(end),
+        })
+    }
+}
+
+/// Split a heading line like `.TODAY...Sunny` or `.SHORT TERM /Today through Monday/...` into
+/// its name and the text following the dots
+pub(crate) fn dot_heading(line: &str) -> Option<(&str, &str)> {
+    let (name, text) = line.strip_prefix('.')?.split_once("...")?;
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        .then(|| (name.trim(), text))
+}
+
+/// Check if a line gives an issuance time like `415 AM MDT Sun Oct 18 2026`
+pub(crate) fn is_issuance_time(line: &str) -> bool {
+    let mut words = line.split_whitespace();
+    let first = words.next().unwrap_or_default();
+    let last = words.last().unwrap_or_default();
+
+    (3..=4).contains(&first.len())
+        && first.bytes().all(|b| b.is_ascii_digit())
+        && last.len() == 4
+        && last.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_text_product() {
+        let product = TextProduct::parse(EX_NPW)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(product.awips_id.as_deref(), Some("NPWBOU"));
+        assert_eq!(
+            product.mnd,
+            Some(MassNewsDisseminator {
+                broadcast: None,
+                product: Some("URGENT - WEATHER MESSAGE".to_owned()),
+                office: "National Weather Service Denver/Boulder CO".to_owned(),
+                issuance: "415 AM MDT Sun Oct 18 2026".to_owned(),
+            })
+        );
+
+        assert_eq!(
+            product.issuance(),
+            Some(Issuance {
+                time: Utc.with_ymd_and_hms(2026, 10, 18, 10, 15, 0).unwrap(),
+                header_mismatch: false,
+            })
+        );
+        let late = TextProduct::parse(&EX_NPW.replace("KBOU 181015", "KBOU 181115"))
+            .unwrap()
+            .1;
+        assert!(late.issuance().unwrap().header_mismatch);
+
+        assert_eq!(product.segments.len(), 3);
+        let warning = &product.segments[0];
+        let ugc = warning.ugc.as_ref().unwrap();
+        assert_eq!(
+            ugc.codes,
+            ["COZ033", "COZ034", "COZ036", "COZ037", "COZ039"]
+        );
+        assert_eq!(
+            ugc.expires,
+            DayHourMinute {
+                day: 19,
+                hour: 0,
+                minute: 0
+            }
+        );
+        assert_eq!(
+            warning.vtec,
+            [Vtec {
+                class: 'O',
+                action: VtecAction::New,
+                office: "KBOU".to_owned(),
+                phenomenon: "HW".to_owned(),
+                significance: VtecSignificance::Warning,
+                event_number: 12,
+                start: Some(Utc.with_ymd_and_hms(2026, 10, 18, 18, 0, 0).unwrap()),
+                end: Some(Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap()),
+            }]
+        );
+        assert!(warning.body.starts_with("Grand Mesa-Gore"));
+        assert_eq!(warning.sections().count(), 1);
+
+        let advisory = &product.segments[1];
+        assert_eq!(
+            advisory.ugc.as_ref().unwrap().codes,
+            ["COZ040", "COZ041", "COZ042", "COZ045", "COZ047"]
+        );
+        assert_eq!(advisory.vtec.len(), 2);
+        assert_eq!(advisory.vtec[1].action, VtecAction::Cancelled);
+        assert_eq!(advisory.vtec[1].start, None);
+
+        assert_eq!(product.segments[2].ugc, None);
+        assert_eq!(product.segments[2].body, "SCHLATTER");
+    }
+
+    const EX_NPW: &str = include_str!("test/npw.txt");
+}
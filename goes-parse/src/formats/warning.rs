@@ -0,0 +1,160 @@
+//! Extraction of the headlines, bullets, and call to action from watch, warning, and advisory
+//! products
+
+use crate::{
+    formats::text_product::{TextProduct, TextProductSegment},
+    ParseResult,
+};
+
+/// A watch, warning, or advisory product with the structured text of each segment
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct WarningProduct {
+    pub product: TextProduct,
+    /// Text of each segment of the product, in the same order as the product's segments
+    pub segments: Vec<WarningText>,
+}
+
+/// Headlines and bullets of a single segment
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WarningText {
+    /// Headlines like `...HIGH WIND WARNING IN EFFECT...` with the surrounding dots removed
+    pub headlines: Vec<String>,
+    /// Bullet labels like `WHAT` or `IMPACTS` mapped to their text, in the order given
+    pub bullets: Vec<(String, String)>,
+    /// Text of the `PRECAUTIONARY/PREPAREDNESS ACTIONS` section
+    pub call_to_action: Option<String>,
+}
+
+impl WarningProduct {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, product) = TextProduct::parse(input)?;
+        let segments = product
+            .segments
+            .iter()
+            .map(WarningText::from_segment)
+            .collect();
+
+        Ok((input, Self { product, segments }))
+    }
+}
+
+impl WarningText {
+    pub fn from_segment(segment: &TextProductSegment) -> Self {
+        let mut text = Self::default();
+        let mut headline: Option<String> = None;
+        let mut bullet: Option<(String, String)> = None;
+        let mut call_to_action: Option<Vec<&str>> = None;
+
+        for line in segment.body.lines() {
+            if let Some(cta) = call_to_action.as_mut() {
+                if line.starts_with("&&") {
+                    break;
+                }
+                cta.push(line);
+                continue;
+            }
+
+            //Headlines may wrap, and end with the line that closes the dots
+            if let Some(mut head) = headline.take().or_else(|| {
+                (text.bullets.is_empty() && bullet.is_none() && line.starts_with("..."))
+                    .then(String::new)
+            }) {
+                if !head.is_empty() {
+                    head.push(' ');
+                }
+                head.push_str(line.trim());
+                match head.len() > 3 && head.ends_with("...") {
+                    true => text
+                        .headlines
+                        .push(head.trim_matches('.').trim().to_owned()),
+                    false => headline = Some(head),
+                }
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                text.bullets.extend(bullet.take());
+            } else if let Some(item) = line.strip_prefix("* ") {
+                text.bullets.extend(bullet.take());
+                let (label, content) = item.split_once("...").unwrap_or((item, ""));
+                bullet = Some((label.trim().to_owned(), content.trim().to_owned()));
+            } else if line
+                .trim_start()
+                .starts_with("PRECAUTIONARY/PREPAREDNESS ACTIONS")
+            {
+                text.bullets.extend(bullet.take());
+                call_to_action = Some(vec![]);
+            } else if let Some((_, content)) = bullet.as_mut() {
+                content.push(' ');
+                content.push_str(line.trim());
+            }
+        }
+
+        text.bullets.extend(bullet);
+        text.call_to_action = call_to_action
+            .map(|lines| lines.join("\n").trim().to_owned())
+            .filter(|cta| !cta.is_empty());
+        text
+    }
+
+    /// Get the text of a bullet by its label
+    pub fn bullet(&self, label: &str) -> Option<&str> {
+        self.bullets
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_warning() {
+        let warning = WarningProduct::parse(EX_NPW)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(warning.segments.len(), 3);
+
+        let high_wind = &warning.segments[0];
+        assert_eq!(
+            high_wind.headlines,
+            ["HIGH WIND WARNING IN EFFECT FROM NOON TODAY TO 6 PM MDT THIS EVENING"]
+        );
+        assert_eq!(high_wind.bullets.len(), 4);
+        assert_eq!(
+            high_wind.bullet("WHAT"),
+            Some("West winds 30 to 40 mph with gusts up to 75 mph.")
+        );
+        assert_eq!(
+            high_wind.bullet("IMPACTS"),
+            Some(
+                "Damaging winds could blow down trees and power lines. Travel could be \
+                difficult, especially for high profile vehicles."
+            )
+        );
+        assert_eq!(
+            high_wind.call_to_action.as_deref(),
+            Some(
+                "People should avoid being outside in forested areas and around\n\
+                trees and branches. Use caution if you must drive."
+            )
+        );
+
+        let advisory = &warning.segments[1];
+        assert_eq!(advisory.headlines.len(), 2);
+        assert_eq!(advisory.headlines[1], "HIGH WIND WATCH IS CANCELLED");
+        assert_eq!(
+            advisory.bullet("WHEN"),
+            Some("From 9 AM this morning to 7 PM MDT this evening.")
+        );
+
+        assert_eq!(warning.segments[2], WarningText::default());
+    }
+
+    const EX_NPW: &str = include_str!("test/npw.txt");
+}
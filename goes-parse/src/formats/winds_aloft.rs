@@ -0,0 +1,301 @@
+//! Parsing for winds and temperatures aloft forecasts (FD/FB)
+
+use nom::{
+    branch::alt,
+    bytes::complete::take_till,
+    character::complete::{char, digit1, multispace1, space1},
+    combinator::{all_consuming, not, opt},
+    error::context,
+    sequence::{preceded, terminated, tuple},
+    Parser,
+};
+use nom_supreme::tag::complete::tag;
+use uom::si::{
+    angle::degree,
+    f32::{Angle, Length, ThermodynamicTemperature, Velocity},
+    length::foot,
+    thermodynamic_temperature::degree_celsius,
+    velocity::knot,
+};
+
+use crate::{
+    formats::column_cells,
+    header::WMOProductIdentifier,
+    parse::{
+        fromstr_n, fromstr_with,
+        time::{gggg, yygggg, DayHourMinute, HourMinute},
+    },
+    ParseResult,
+};
+
+/// Altitude above which temperatures are negative when the product does not specify one
+const DEFAULT_NEGATIVE_ABOVE_FT: f32 = 24000f32;
+
+/// A winds and temperatures aloft bulletin containing forecast winds for a set of stations at
+/// fixed altitudes
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct WindsAloftForecast {
+    pub header: WMOProductIdentifier,
+    /// Time of the model run that the forecast is based on
+    pub based_on: DayHourMinute,
+    pub valid: DayHourMinute,
+    /// Start of the period that the forecast is intended to be used in
+    pub for_use_start: HourMinute,
+    /// End of the period that the forecast is intended to be used in
+    pub for_use_end: HourMinute,
+    /// Altitude above which all temperatures are negative and reported without a sign
+    pub negative_above: Length,
+    /// Altitudes of each column in the forecast tables
+    pub altitudes: Vec<Length>,
+    pub stations: Vec<WindsAloftStation>,
+}
+
+/// All forecast levels given for a single station
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct WindsAloftStation {
+    pub station: String,
+    pub levels: Vec<WindsAloftLevel>,
+}
+
+/// Forecast wind and temperature at a single altitude
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindsAloftLevel {
+    pub altitude: Length,
+    pub wind: WindsAloftWind,
+    /// Temperature at the given altitude, not forecast for levels near the surface
+    pub temperature: Option<ThermodynamicTemperature>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WindsAloftWind {
+    /// Light and variable winds of less than 5 knots, coded as `9900`
+    LightAndVariable,
+    Wind {
+        /// True direction that the wind is blowing from
+        direction: Angle,
+        speed: Velocity,
+    },
+}
+
+impl WindsAloftForecast {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, header) = terminated(WMOProductIdentifier::parse, multispace1)(input)?;
+        let (input, _) = opt(terminated(
+            preceded(not(tag("DATA")), take_till(|c| c == '\n')),
+            multispace1,
+        ))(input)?;
+
+        let (input, based_on) = context(
+            "data based on time",
+            terminated(
+                preceded(tag("DATA BASED ON "), yygggg),
+                tuple((char('Z'), multispace1)),
+            ),
+        )(input)?;
+
+        let (input, (valid, for_use_start, for_use_end, negative_above)) = context(
+            "valid time",
+            tuple((
+                preceded(tag("VALID "), terminated(yygggg, char('Z'))),
+                preceded(tuple((space1, tag("FOR USE "))), gggg),
+                preceded(char('-'), terminated(gggg, opt(char('Z')))),
+                opt(preceded(
+                    tuple((opt(char('.')), space1, tag("TEMPS NEG ABV "))),
+                    fromstr_with(digit1).map(Length::new::<foot>),
+                )),
+            )),
+        )(input)?;
+
+        let negative_above =
+            negative_above.unwrap_or_else(|| Length::new::<foot>(DEFAULT_NEGATIVE_ABOVE_FT));
+
+        let mut altitudes = vec![];
+        let mut columns: Vec<(usize, Length)> = vec![];
+        let mut stations = vec![];
+
+        for line in input.lines().map(str::trim_end) {
+            if let Some(labels) = line.strip_prefix("FT") {
+                columns = column_cells(labels)
+                    .filter_map(|(end, label)| {
+                        label
+                            .parse::<f32>()
+                            .ok()
+                            .map(|ft| (end + 2, Length::new::<foot>(ft)))
+                    })
+                    .collect();
+
+                for (_, altitude) in columns.iter() {
+                    if !altitudes.contains(altitude) {
+                        altitudes.push(*altitude);
+                    }
+                }
+
+                continue;
+            }
+
+            if columns.is_empty() || line.starts_with("$$") {
+                continue;
+            }
+
+            let mut cells = column_cells(line);
+            let station = match cells.next() {
+                Some((_, station)) if station.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                    station.to_owned()
+                }
+                _ => continue,
+            };
+
+            let mut levels = vec![];
+            for (end, cell) in cells {
+                let altitude = match columns.iter().min_by_key(|(col, _)| col.abs_diff(end)) {
+                    Some((_, altitude)) => *altitude,
+                    None => continue,
+                };
+
+                match WindsAloftLevel::parse(altitude, negative_above)(cell) {
+                    Ok((_, level)) => levels.push(level),
+                    Err(e) => log::info!(
+                        "Failed to parse winds aloft cell {} for {}: {}",
+                        cell,
+                        station,
+                        e
+                    ),
+                }
+            }
+
+            stations.push(WindsAloftStation { station, levels });
+        }
+
+        Ok((
+            "",
+            Self {
+                header,
+                based_on,
+                valid,
+                for_use_start,
+                for_use_end,
+                negative_above,
+                altitudes,
+                stations,
+            },
+        ))
+    }
+}
+
+impl WindsAloftLevel {
+    /// Parse a single `DDff±TT` cell of the forecast table for the given altitude
+    pub fn parse(
+        altitude: Length,
+        negative_above: Length,
+    ) -> impl FnMut(&str) -> ParseResult<&str, Self> {
+        move |input| {
+            let (input, (dd, ff, temperature)) = all_consuming(tuple((
+                fromstr_n::<u16>(2),
+                fromstr_n::<f32>(2),
+                opt(alt((
+                    preceded(char('+'), fromstr_n::<f32>(2)),
+                    preceded(char('-'), fromstr_n::<f32>(2)).map(|t| -t),
+                    fromstr_n::<f32>(2).map(|t| match altitude > negative_above {
+                        true => -t,
+                        false => t,
+                    }),
+                ))),
+            )))(input)?;
+
+            let wind = match (dd, ff) {
+                (99, _) => WindsAloftWind::LightAndVariable,
+                (51.., ff) => WindsAloftWind::Wind {
+                    direction: Angle::new::<degree>((dd - 50) as f32 * 10f32),
+                    speed: Velocity::new::<knot>(ff + 100f32),
+                },
+                (dd, ff) => WindsAloftWind::Wind {
+                    direction: Angle::new::<degree>(dd as f32 * 10f32),
+                    speed: Velocity::new::<knot>(ff),
+                },
+            };
+
+            Ok((
+                input,
+                Self {
+                    altitude,
+                    wind,
+                    temperature: temperature.map(ThermodynamicTemperature::new::<degree_celsius>),
+                },
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_winds_aloft() {
+        let fd = WindsAloftForecast::parse(EX_FD)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(
+            fd.based_on,
+            DayHourMinute {
+                day: 18,
+                hour: 12,
+                minute: 0
+            }
+        );
+        assert_eq!(
+            fd.for_use_start,
+            HourMinute {
+                hour: 14,
+                minute: 0
+            }
+        );
+        assert_eq!(
+            fd.for_use_end,
+            HourMinute {
+                hour: 21,
+                minute: 0
+            }
+        );
+        assert_eq!(fd.altitudes.len(), 9);
+        assert_eq!(fd.stations.len(), 5);
+
+        let abq = &fd.stations[1];
+        assert_eq!(abq.levels.len(), 7);
+        assert_eq!(abq.levels[0].altitude, Length::new::<foot>(9000f32));
+
+        let bhm = &fd.stations[2];
+        assert_eq!(bhm.levels[0].wind, WindsAloftWind::LightAndVariable);
+        assert_eq!(bhm.levels[0].temperature, None);
+        assert_eq!(
+            bhm.levels[8],
+            WindsAloftLevel {
+                altitude: Length::new::<foot>(39000f32),
+                wind: WindsAloftWind::Wind {
+                    direction: Angle::new::<degree>(250f32),
+                    speed: Velocity::new::<knot>(102f32),
+                },
+                temperature: Some(ThermodynamicTemperature::new::<degree_celsius>(-57f32)),
+            }
+        );
+        assert_eq!(
+            bhm.levels[6].temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(-36f32))
+        );
+        assert_eq!(
+            fd.stations[3].levels[1].temperature,
+            Some(ThermodynamicTemperature::new::<degree_celsius>(-2f32))
+        );
+
+        let den = &fd.stations[4];
+        assert_eq!(den.levels[0].altitude, Length::new::<foot>(12000f32));
+        assert_eq!(den.levels[0].wind, WindsAloftWind::LightAndVariable);
+    }
+
+    const EX_FD: &str = include_str!("test/winds_aloft.txt");
+}
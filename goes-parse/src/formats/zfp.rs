@@ -0,0 +1,262 @@
+//! Parsing for the forecast periods of Zone Forecast Products
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDateTime, TimeZone, Utc, Weekday};
+
+use crate::{
+    formats::text_product::{dot_heading, is_issuance_time, TextProduct, TextProductSegment},
+    parse::time::issuance_time_local,
+    ParseResult,
+};
+
+/// A Zone Forecast Product with the forecast periods for each group of zones
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ZoneForecastProduct {
+    pub product: TextProduct,
+    /// Identifier of the issuing forecast office, i.e. `BOU`
+    pub office: Option<String>,
+    pub forecasts: Vec<ZoneForecast>,
+}
+
+/// The forecast for a single segment of the product
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZoneForecast {
+    /// UGC codes of the zones, i.e. `COZ039`
+    pub zones: Vec<String>,
+    /// Zone names and included cities listed after the UGC line
+    pub names: Vec<String>,
+    /// Issuance time of the segment, or of the product if the segment does not give one
+    pub issued: Option<DateTime<Utc>>,
+    /// Synopsis given in place of or before the forecast periods, as in marine forecasts
+    pub synopsis: Option<String>,
+    pub periods: Vec<ForecastPeriod>,
+}
+
+/// A forecast period like `.TONIGHT...Mostly clear.`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForecastPeriod {
+    /// Name of the period as given, i.e. `MONDAY NIGHT`
+    pub name: String,
+    /// Times covered by the period, unset if the name or issuance time could not be resolved
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub text: String,
+}
+
+impl ZoneForecastProduct {
+    pub fn parse(input: &str) -> ParseResult<&str, Self> {
+        let (input, product) = TextProduct::parse(input)?;
+        let office = product.office_id().map(str::to_owned);
+        let issued = product_issuance(&product);
+        let forecasts = product
+            .segments
+            .iter()
+            .filter_map(|segment| ZoneForecast::from_segment(segment, issued))
+            .collect();
+
+        Ok((
+            input,
+            Self {
+                product,
+                office,
+                forecasts,
+            },
+        ))
+    }
+}
+
+impl ZoneForecast {
+    /// Collect the forecast periods of a segment with UGC codes, resolving the periods from the
+    /// issuance time of the segment or else the issuance time of the product
+    pub(crate) fn from_segment(
+        segment: &TextProductSegment,
+        product_issued: Option<DateTime<FixedOffset>>,
+    ) -> Option<Self> {
+        let ugc = segment.ugc.as_ref()?;
+
+        let mut names = vec![];
+        let mut issued = None;
+        let mut synopsis: Option<String> = None;
+        let mut periods: Vec<ForecastPeriod> = vec![];
+        let mut heading: Option<String> = None;
+        let mut in_period = false;
+
+        for line in segment.body.lines() {
+            //Long headings may wrap before the dots that end them
+            let line = match heading.take() {
+                Some(mut head) if !line.trim().is_empty() => {
+                    head.push(' ');
+                    head.push_str(line.trim());
+                    head
+                }
+                _ => line.to_owned(),
+            };
+            let is_heading = line
+                .strip_prefix('.')
+                .is_some_and(|name| name.starts_with(|c: char| c.is_ascii_uppercase()));
+            if is_heading && dot_heading(&line).is_none() {
+                heading = Some(line);
+                continue;
+            }
+
+            if let Some((name, text)) = dot_heading(&line) {
+                in_period = true;
+                let text = text.trim().to_owned();
+                match name.starts_with("SYNOPSIS") {
+                    true => synopsis = Some(text),
+                    false => periods.push(ForecastPeriod {
+                        name: name.to_owned(),
+                        start: None,
+                        end: None,
+                        text,
+                    }),
+                }
+            } else if line.trim().is_empty() || line.starts_with("&&") {
+                in_period = false;
+            } else if in_period {
+                let text = match periods.last_mut() {
+                    Some(period) => &mut period.text,
+                    None => synopsis.get_or_insert_with(String::new),
+                };
+                text.push(' ');
+                text.push_str(line.trim());
+            } else if periods.is_empty() && is_issuance_time(&line) {
+                issued = issuance_time_local(&line).ok().map(|(_, time)| time);
+            } else if periods.is_empty() && synopsis.is_none() && issued.is_none() {
+                names.push(line.trim().trim_end_matches('-').to_owned());
+            }
+        }
+
+        //Periods follow each other from the time of issuance in local time
+        let issued = issued.or(product_issued);
+        if let Some(issued) = issued {
+            let mut cursor = issued.naive_local();
+            for period in periods.iter_mut() {
+                let Some((start, end)) = resolve_period(&period.name, cursor) else {
+                    log::warn!("Unrecognized forecast period {}", period.name);
+                    break;
+                };
+                let utc = |time| {
+                    issued
+                        .timezone()
+                        .from_local_datetime(&time)
+                        .single()
+                        .map(|time| time.with_timezone(&Utc))
+                };
+                period.start = utc(start);
+                period.end = utc(end);
+                cursor = end;
+            }
+        }
+
+        Some(Self {
+            zones: ugc.codes.clone(),
+            names,
+            issued: issued.map(|time| time.with_timezone(&Utc)),
+            synopsis,
+            periods,
+        })
+    }
+}
+
+/// Get the issuance time of a product in the local time zone it was given in
+pub(crate) fn product_issuance(product: &TextProduct) -> Option<DateTime<FixedOffset>> {
+    product
+        .mnd
+        .as_ref()
+        .and_then(|mnd| issuance_time_local(&mnd.issuance).ok())
+        .map(|(_, time)| time)
+}
+
+/// Resolve a period name to a local time range beginning no earlier than `after`, where daytime
+/// periods run from 6 AM to 6 PM and nighttime periods from 6 PM to 6 AM. Combined periods like
+/// `TUESDAY THROUGH WEDNESDAY NIGHT` run from the start of the first to the end of the last.
+fn resolve_period(name: &str, after: NaiveDateTime) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let (first, last) = name
+        .split_once(" THROUGH ")
+        .or_else(|| name.split_once(" AND "))
+        .unwrap_or((name, name));
+
+    let first_end = period_end(first, after)?;
+    let end = period_end(last, after)?;
+    let start = after.max(first_end - Duration::hours(12));
+    (start < end).then_some((start, end))
+}
+
+/// Find the end of the first period with the given name that ends after `after`
+fn period_end(name: &str, after: NaiveDateTime) -> Option<NaiveDateTime> {
+    let night = name.contains("NIGHT") || name.contains("EVENING");
+    let weekday = name
+        .split_whitespace()
+        .next()
+        .and_then(|day| day.parse::<Weekday>().ok());
+
+    (-1..8)
+        .filter_map(|offset| after.date().checked_add_signed(Duration::days(offset)))
+        .filter(|day| weekday.is_none_or(|weekday| day.weekday() == weekday))
+        .filter_map(|day| match night {
+            true => day.succ_opt()?.and_hms_opt(6, 0, 0),
+            false => day.and_hms_opt(18, 0, 0),
+        })
+        .find(|end| *end > after)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn utc(day: u32, hour: u32) -> Option<DateTime<Utc>> {
+        Utc.with_ymd_and_hms(2026, 10, day, hour, 0, 0).single()
+    }
+
+    #[test]
+    pub fn test_zfp() {
+        let zfp = ZoneForecastProduct::parse(EX_ZFP)
+            .unwrap_or_else(|e| panic!("{}", crate::display_error(e)))
+            .1;
+
+        assert_eq!(zfp.office.as_deref(), Some("BOU"));
+        assert_eq!(zfp.forecasts.len(), 2);
+
+        let denver = &zfp.forecasts[0];
+        assert_eq!(denver.zones, ["COZ039"]);
+        assert_eq!(
+            denver.names,
+            ["City and County of Denver", "Including the city of Denver"]
+        );
+        assert_eq!(
+            denver.issued,
+            Utc.with_ymd_and_hms(2026, 10, 18, 9, 45, 0).single()
+        );
+
+        let periods = denver
+            .periods
+            .iter()
+            .map(|p| (p.name.as_str(), p.start, p.end))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            periods,
+            [
+                ("TODAY", utc(18, 12), utc(19, 0)),
+                ("TONIGHT", utc(19, 0), utc(19, 12)),
+                ("MONDAY", utc(19, 12), utc(20, 0)),
+                ("MONDAY NIGHT", utc(20, 0), utc(20, 12)),
+                ("TUESDAY THROUGH WEDNESDAY NIGHT", utc(20, 12), utc(22, 12)),
+                ("THURSDAY", utc(22, 12), utc(23, 0)),
+            ]
+        );
+        assert_eq!(
+            denver.periods[0].text,
+            "Sunny. Highs in the lower 60s. West winds 10 to 15 mph with gusts to 30 mph."
+        );
+
+        let foothills = &zfp.forecasts[1];
+        assert_eq!(foothills.zones, ["COZ040", "COZ041", "COZ042"]);
+        assert_eq!(foothills.periods.len(), 2);
+        assert_eq!(foothills.periods[1].end, utc(19, 12));
+    }
+
+    const EX_ZFP: &str = include_str!("test/zfp.txt");
+}
@@ -12,6 +12,7 @@ use nom_supreme::tag::complete::tag;
 use crate::{parse::fromstr_n, ParseResult};
 
 /// A channel number between 01 and 16
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Channel {
     Blue,
@@ -39,6 +40,7 @@ pub enum Channel {
 
 /// Instrument that an image was taken with, represented as an enum with one variant in case more
 /// satellites with more instruments are ever put into orbit
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Instrument {
@@ -46,6 +48,7 @@ pub enum Instrument {
 }
 
 /// Either level 1b or 2+ processing level for [DataShortName] with product acronym
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ProductAcronym {
     /// Always radiance data
@@ -54,6 +57,7 @@ pub enum ProductAcronym {
 }
 
 /// Product acronyms for [level 2+](ProductAcronym::L2)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum L2Acronym {
     CloudTopHeight,
@@ -81,6 +85,7 @@ pub enum L2Acronym {
     TotalPrecipitableWater,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ABISector {
     FullDisk,
@@ -89,6 +94,7 @@ pub enum ABISector {
     Mesoscale2,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ABIMode {
     Mode3,
@@ -97,6 +103,7 @@ pub enum ABIMode {
 }
 
 /// Structure representing a full DSN part of a GOES-R series filename
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct DataShortName {
     pub instrument: Instrument,
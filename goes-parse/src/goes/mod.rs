@@ -19,6 +19,7 @@ pub mod dsn;
 
 /// Two-letter system environment code specifying if a GOES image was received from a test or
 /// real-time data transmission
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SystemEnvironment {
     OperationalRealTime,
@@ -30,6 +31,7 @@ pub enum SystemEnvironment {
 }
 
 /// Enumeration representing all GOES-R series satellites
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Satellite {
     Goes16,
@@ -39,6 +41,7 @@ pub enum Satellite {
 }
 
 /// GOES-R series file name
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug)]
 pub struct GoesFileName {
     pub env: SystemEnvironment,
@@ -1,6 +1,6 @@
-use std::{num::ParseIntError, str::FromStr};
+use std::{fmt, num::ParseIntError, str::FromStr};
 
-use chrono::{NaiveDateTime, NaiveTime};
+use chrono::{DateTime, Datelike, Months, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use nom::{
     bytes::complete::take,
     character::{complete::space1, streaming::char},
@@ -15,6 +15,7 @@ use crate::{
 };
 
 /// A full WMO product identifier with data type designator, country code, and time
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct WMOProductIdentifier {
     pub datatype: DataTypeDesignator,
@@ -23,18 +24,25 @@ pub struct WMOProductIdentifier {
 }
 
 /// A full AWIPS product identifier containing a WMO abbreviated heading and AFOS PIL
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct AWIPSProductIdentifer {
     pub wmo_abbreviated_heading: DataTypeDesignator,
 }
 
 /// Four-letter country code
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct CCCC {
     pub code: [char; 4],
 }
 
 /// A parsed EMWIN filename from a GOES-R series satellite
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GoesEmwinFileName {
     pub wmo_product_id: DataTypeDesignator,
@@ -43,6 +51,8 @@ pub struct GoesEmwinFileName {
     pub creation_timestamp: NaiveDateTime,
     pub sequence: u32,
     pub priority: u8,
+    /// AWIPS product identifier and state following the priority, i.e. `AFDABQNM`
+    pub pil: Option<String>,
 }
 
 fn expect<I: Iterator<Item = (usize, char)>>(
@@ -142,6 +152,15 @@ impl FromStr for GoesEmwinFileName {
             .ok_or_else(|| GoesFileNameParseError::Priority(priority))?
             as u8;
 
+        let pil = match chars.next() {
+            Some((idx, '-')) => s[idx + 1..]
+                .split('.')
+                .next()
+                .filter(|pil| !pil.is_empty())
+                .map(str::to_owned),
+            _ => None,
+        };
+
         Ok(Self {
             wmo_product_id,
             country: CCCC { code: country },
@@ -149,6 +168,7 @@ impl FromStr for GoesEmwinFileName {
             creation_timestamp,
             sequence,
             priority,
+            pil,
         })
     }
 }
@@ -176,6 +196,25 @@ impl WMOProductIdentifier {
             },
         ))
     }
+
+    /// Resolve the day, hour, and minute of the header to a full UTC timestamp, choosing the
+    /// month nearest to `reference` so that products issued across a month boundary resolve
+    /// correctly
+    pub fn resolve(&self, reference: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let month = Utc
+            .with_ymd_and_hms(reference.year(), reference.month(), 1, 0, 0, 0)
+            .single()?;
+
+        [
+            month.checked_sub_months(Months::new(1)),
+            Some(month),
+            month.checked_add_months(Months::new(1)),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|month| self.creation_time.offset(month))
+        .min_by_key(|time| (*time - reference).num_seconds().abs())
+    }
 }
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -212,6 +251,25 @@ impl FromStr for CCCC {
     }
 }
 
+impl fmt::Display for CCCC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.code.iter().try_for_each(|c| write!(f, "{}", c))
+    }
+}
+
+impl From<CCCC> for String {
+    fn from(cccc: CCCC) -> Self {
+        cccc.to_string()
+    }
+}
+
+impl TryFrom<String> for CCCC {
+    type Error = CCCCParseError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -224,9 +282,28 @@ mod test {
                 .unwrap();
         assert!(filename.priority == 2);
         assert!(filename.sequence == 8996);
+        assert_eq!(filename.pil.as_deref(), Some("AFDABQNM"));
         assert!(matches!(
             filename.wmo_product_id,
             DataTypeDesignator::Forecast(_)
         ));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_filename() {
+        let filename: GoesEmwinFileName =
+            "A_FXUS65KABQ121804AAB_C_KWIN_20160112180901_008996-2-AFDABQNM.TXT"
+                .parse()
+                .unwrap();
+
+        let json = serde_json::to_value(&filename).unwrap();
+        assert_eq!(json["country"], "KABQ");
+        assert_eq!(json["wmo_product_id"]["Forecast"]["area"], "US");
+
+        let decoded: GoesEmwinFileName = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, filename);
+
+        assert!(serde_json::from_str::<CCCC>("\"KW\"").is_err());
+    }
 }
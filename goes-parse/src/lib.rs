@@ -5,10 +5,12 @@ pub type ParseError<I> = ErrorTree<I>;
 pub type ParseResult<I, O> = IResult<I, O, ParseError<I>>;
 
 pub mod dt;
+pub mod filter;
 pub mod formats;
 pub mod goes;
 pub mod header;
 mod parse;
+pub mod stations;
 
 pub fn display_error(e: nom::Err<ParseError<&str>>) -> String {
     match e {
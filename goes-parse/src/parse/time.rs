@@ -1,10 +1,20 @@
-use nom::{bytes::complete::take, combinator::map_res, error::context, sequence::tuple, Parser};
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag_no_case, take, take_while_m_n},
+    character::complete::{alpha1, digit1, space1},
+    combinator::{map_opt, map_res, opt},
+    error::context,
+    sequence::{terminated, tuple},
+    Parser,
+};
 
 use crate::ParseResult;
 
 use super::fromstr_n;
 
 /// A structure holding naive days, hours, and minutes into the month
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct DayHourMinute {
     pub day: u8,
@@ -51,3 +61,126 @@ pub fn yygg(input: &str) -> ParseResult<&str, DayHourMinute> {
         },
     ))
 }
+
+/// A naive time of day in hours and minutes
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HourMinute {
+    pub hour: u8,
+    pub minute: u8,
+}
+
+/// Parse a time of day in GGgg (hours-minutes) format
+pub fn gggg(input: &str) -> ParseResult<&str, HourMinute> {
+    context(
+        "time in GGgg format",
+        tuple((context("GG", fromstr_n(2)), context("gg", fromstr_n(2))))
+            .map(|(hour, minute)| HourMinute { hour, minute }),
+    )(input)
+}
+
+/// Get the offset from UTC of a time zone abbreviation used by NWS offices, from Atlantic time in
+/// Puerto Rico to Chamorro time in Guam
+pub fn us_time_zone_offset(zone: &str) -> Option<FixedOffset> {
+    let hours = match zone.to_ascii_uppercase().as_str() {
+        "UTC" | "GMT" | "Z" => 0,
+        "AST" => -4,
+        "ADT" => -3,
+        "EST" => -5,
+        "EDT" => -4,
+        "CST" => -6,
+        "CDT" => -5,
+        "MST" => -7,
+        "MDT" => -6,
+        "PST" => -8,
+        "PDT" => -7,
+        "AKST" => -9,
+        "AKDT" => -8,
+        "HST" => -10,
+        "HDT" => -9,
+        "SST" => -11,
+        "CHST" => 10,
+        _ => return None,
+    };
+
+    FixedOffset::east_opt(hours * 3600)
+}
+
+/// Parse an NWS issuance time line like `1015 AM CDT TUE OCT 17 2026` or
+/// `1800 UTC Sat Oct 17 2026` to UTC
+pub fn issuance_time(input: &str) -> ParseResult<&str, DateTime<Utc>> {
+    issuance_time_local
+        .map(|time| time.with_timezone(&Utc))
+        .parse(input)
+}
+
+/// Parse an NWS issuance time line, keeping the offset of the time zone it was given in
+pub fn issuance_time_local(input: &str) -> ParseResult<&str, DateTime<FixedOffset>> {
+    context(
+        "issuance time",
+        map_opt(
+            tuple((
+                terminated(digit1, space1),
+                opt(terminated(
+                    alt((tag_no_case("AM"), tag_no_case("PM"))),
+                    space1,
+                )),
+                terminated(alpha1, space1),
+                terminated(alpha1, space1),
+                terminated(alpha1, space1),
+                terminated(digit1, space1),
+                take_while_m_n(4, 4, |c: char| c.is_ascii_digit()),
+            )),
+            |(time, meridiem, zone, _weekday, month, day, year): (
+                &str,
+                Option<&str>,
+                &str,
+                &str,
+                &str,
+                &str,
+                &str,
+            )| {
+                let time = time.parse::<u32>().ok()?;
+                let (hour, minute) = (time / 100, time % 100);
+                let hour = match meridiem.map(|m| m.eq_ignore_ascii_case("PM")) {
+                    Some(pm) if (1..=12).contains(&hour) => hour % 12 + if pm { 12 } else { 0 },
+                    Some(_) => return None,
+                    None => hour,
+                };
+
+                let date =
+                    NaiveDate::parse_from_str(&format!("{year} {month} {day}"), "%Y %b %d").ok()?;
+                us_time_zone_offset(zone)?
+                    .from_local_datetime(&date.and_hms_opt(hour, minute, 0)?)
+                    .single()
+            },
+        ),
+    )(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_issuance_time() {
+        let time = |line| issuance_time(line).unwrap().1;
+        assert_eq!(
+            time("1015 AM CDT TUE OCT 17 2026"),
+            Utc.with_ymd_and_hms(2026, 10, 17, 15, 15, 0).unwrap()
+        );
+        assert_eq!(
+            time("1200 AM HST Sun Oct 18 2026"),
+            Utc.with_ymd_and_hms(2026, 10, 18, 10, 0, 0).unwrap()
+        );
+        assert_eq!(
+            time("730 PM ChST Sun Oct 18 2026"),
+            Utc.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap()
+        );
+        assert_eq!(
+            time("1800 UTC Sat Oct 17 2026"),
+            Utc.with_ymd_and_hms(2026, 10, 17, 18, 0, 0).unwrap()
+        );
+        assert!(issuance_time("1015 AM XYZ TUE OCT 17 2026").is_err());
+    }
+}
@@ -0,0 +1,370 @@
+//! Station metadata catalog giving the location of stations by ICAO location indicator or WMO
+//! index number, read from the semicolon delimited format of the NWS `nsd_cccc.txt` station list
+
+use std::{collections::HashMap, fmt, path::Path, str::FromStr, sync::OnceLock};
+
+use uom::si::{
+    angle::{degree, radian},
+    f32::{Angle, Length},
+    length::{kilometer, meter},
+};
+
+use crate::{
+    formats::LatLon,
+    header::{CCCCParseError, CCCC},
+};
+
+/// Station list embedded in the crate from `nsd_cccc.txt` in this directory, replaceable at runtime
+/// with [StationTable::load]. Set `GOES_PARSE_NSD_CCCC` to the path of a newer copy of the NWS list
+/// when building to embed it instead
+const EMBEDDED: &str = include_str!(concat!(env!("OUT_DIR"), "/nsd_cccc.txt"));
+
+/// Mean radius of the earth used for distances between stations
+const EARTH_RADIUS_KM: f32 = 6371.0;
+
+/// A single station of the catalog
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Station {
+    pub icao: CCCC,
+    pub wmo: Option<WmoIndex>,
+    pub name: String,
+    /// Two letter state or territory code for US stations
+    pub state: Option<String>,
+    pub country: String,
+    pub location: LatLon,
+    /// Elevation of the station above mean sea level
+    pub elevation: Option<Length>,
+}
+
+/// WMO block and station number `IIiii`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WmoIndex {
+    pub block: u8,
+    pub station: u16,
+}
+
+/// A table of stations indexed by ICAO location indicator and WMO index number
+#[derive(Clone, Debug, Default)]
+pub struct StationTable {
+    stations: Vec<Station>,
+    icao: HashMap<CCCC, usize>,
+    wmo: HashMap<WmoIndex, usize>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StationTableError {
+    #[error("Failed to read station table: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid ICAO location indicator on line {0}: {1}")]
+    Icao(usize, CCCCParseError),
+    #[error("Invalid {1} on line {0}")]
+    Field(usize, &'static str),
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("Invalid WMO index number {0}, expecting 5 digits IIiii")]
+pub struct WmoIndexParseError(String);
+
+impl StationTable {
+    /// Get the station table embedded in the crate
+    pub fn embedded() -> &'static Self {
+        static TABLE: OnceLock<StationTable> = OnceLock::new();
+        TABLE.get_or_init(|| Self::parse(EMBEDDED))
+    }
+
+    /// Read a station table from a file in `nsd_cccc.txt` format, skipping malformed lines
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StationTableError> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Parse a station table with one station per line, given as
+    /// `ICAO;block;station;name;state;country;WMO region;latitude;longitude;upper air latitude;
+    /// upper air longitude;elevation;upper air elevation;RBSN`. Later entries for the same station
+    /// replace earlier ones, and malformed lines are skipped with a warning.
+    pub fn parse(input: &str) -> Self {
+        let mut table = Self::default();
+
+        for (line, text) in input.lines().enumerate().map(|(i, l)| (i + 1, l.trim())) {
+            if text.is_empty() {
+                continue;
+            }
+
+            match Station::parse_line(line, text) {
+                Ok(station) => table.insert(station),
+                Err(e) => log::warn!("Skipping station: {}", e),
+            }
+        }
+
+        table
+    }
+
+    /// Add a station to the table, replacing any station with the same ICAO location indicator
+    pub fn insert(&mut self, station: Station) {
+        let idx = match self.icao.get(&station.icao) {
+            Some(&idx) => {
+                if let Some(wmo) = self.stations[idx].wmo {
+                    self.wmo.remove(&wmo);
+                }
+                self.stations[idx] = station;
+                idx
+            }
+            None => {
+                self.stations.push(station);
+                self.stations.len() - 1
+            }
+        };
+
+        let station = &self.stations[idx];
+        self.icao.insert(station.icao, idx);
+        if let Some(wmo) = station.wmo {
+            self.wmo.insert(wmo, idx);
+        }
+    }
+
+    /// Look up a station by its ICAO location indicator
+    pub fn by_icao(&self, icao: &CCCC) -> Option<&Station> {
+        self.icao.get(icao).map(|&idx| &self.stations[idx])
+    }
+
+    /// Look up a station by its WMO index number
+    pub fn by_wmo(&self, wmo: WmoIndex) -> Option<&Station> {
+        self.wmo.get(&wmo).map(|&idx| &self.stations[idx])
+    }
+
+    /// Get up to `n` stations nearest to a point along with their distance from it, ordered from
+    /// nearest to farthest
+    pub fn nearest(&self, point: LatLon, n: usize) -> Vec<(&Station, Length)> {
+        let mut stations = self
+            .stations
+            .iter()
+            .map(|station| (station, distance(point, station.location)))
+            .collect::<Vec<_>>();
+        stations.sort_by(|(_, a), (_, b)| a.value.total_cmp(&b.value));
+        stations.truncate(n);
+        stations
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Station> {
+        self.stations.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.stations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stations.is_empty()
+    }
+}
+
+impl Station {
+    /// Parse a single line of a station table in `nsd_cccc.txt` format
+    fn parse_line(line: usize, text: &str) -> Result<Self, StationTableError> {
+        let fields = text.split(';').map(str::trim).collect::<Vec<_>>();
+        let field = |i: usize| fields.get(i).copied().filter(|f| !f.is_empty());
+        let required = |i: usize, name| field(i).ok_or(StationTableError::Field(line, name));
+
+        let icao = required(0, "ICAO location indicator")?;
+        if icao.len() != 4 {
+            return Err(StationTableError::Field(line, "ICAO location indicator"));
+        }
+        let icao = icao
+            .parse::<CCCC>()
+            .map_err(|e| StationTableError::Icao(line, e))?;
+
+        //Stations without a WMO index leave the block and station number blank or dashed
+        let wmo = match (
+            field(1).and_then(|b| b.parse().ok()),
+            field(2).and_then(|s| s.parse().ok()),
+        ) {
+            (Some(block), Some(station)) => Some(WmoIndex { block, station }),
+            _ => None,
+        };
+
+        let lat = parse_coordinate(required(7, "latitude")?, 'N', 'S')
+            .ok_or(StationTableError::Field(line, "latitude"))?;
+        let lon = parse_coordinate(required(8, "longitude")?, 'E', 'W')
+            .ok_or(StationTableError::Field(line, "longitude"))?;
+        let elevation = field(11)
+            .map(|e| {
+                e.parse::<f32>()
+                    .map(Length::new::<meter>)
+                    .map_err(|_| StationTableError::Field(line, "elevation"))
+            })
+            .transpose()?;
+
+        Ok(Station {
+            icao,
+            wmo,
+            name: required(3, "name")?.to_owned(),
+            state: field(4).map(str::to_owned),
+            country: field(5).unwrap_or_default().to_owned(),
+            location: LatLon { lat, lon },
+            elevation,
+        })
+    }
+}
+
+/// Parse a coordinate in `DD-MMH` or `DD-MM-SSH` format
+fn parse_coordinate(coord: &str, positive: char, negative: char) -> Option<Angle> {
+    let sign = match coord.chars().last()? {
+        c if c == positive => 1f32,
+        c if c == negative => -1f32,
+        _ => return None,
+    };
+
+    let degrees = coord[..coord.len() - 1]
+        .split('-')
+        .zip([1f32, 60f32, 3600f32])
+        .try_fold(0f32, |sum, (part, scale)| {
+            part.parse::<f32>().ok().map(|v| sum + v / scale)
+        })?;
+
+    Some(Angle::new::<degree>(sign * degrees))
+}
+
+/// Great circle distance between two points
+pub fn distance(a: LatLon, b: LatLon) -> Length {
+    let (lat1, lat2) = (a.lat.get::<radian>(), b.lat.get::<radian>());
+    let dlat = lat2 - lat1;
+    let dlon = (b.lon - a.lon).get::<radian>();
+
+    let h = (dlat / 2f32).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2f32).sin().powi(2);
+    Length::new::<kilometer>(2f32 * EARTH_RADIUS_KM * h.sqrt().min(1f32).asin())
+}
+
+impl FromStr for WmoIndex {
+    type Err = WmoIndexParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || WmoIndexParseError(s.to_owned());
+        if s.len() != 5 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+
+        Ok(Self {
+            block: s[..2].parse().map_err(|_| err())?,
+            station: s[2..].parse().map_err(|_| err())?,
+        })
+    }
+}
+
+impl fmt::Display for WmoIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:03}", self.block, self.station)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_stations() {
+        let table = StationTable::embedded();
+
+        let kden = table.by_icao(&"KDEN".parse().unwrap()).unwrap();
+        assert_eq!(kden.wmo, "72565".parse().ok());
+        assert_eq!(kden.state.as_deref(), Some("CO"));
+        assert_eq!(kden.elevation, Some(Length::new::<meter>(1640f32)));
+        assert!((kden.location.lat.get::<degree>() - 39.85).abs() < 0.01);
+        assert!((kden.location.lon.get::<degree>() + 104.65).abs() < 0.01);
+
+        let yssy = table.by_wmo("94767".parse().unwrap()).unwrap();
+        assert_eq!(yssy.icao.to_string(), "YSSY");
+        assert_eq!(yssy.state, None);
+        assert!(yssy.location.lat.get::<degree>() < 0f32);
+        assert!(table
+            .by_icao(&"KAPA".parse().unwrap())
+            .unwrap()
+            .wmo
+            .is_none());
+
+        //Stations near Denver are the same whether the full NWS list is embedded or not
+        let nearest = table.nearest(kden.location, 3);
+        assert_eq!(nearest[0].0.icao, kden.icao);
+        assert_eq!(nearest[0].1, Length::new::<kilometer>(0f32));
+        assert!(nearest.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(nearest[2].1 < Length::new::<kilometer>(50f32));
+
+        //London to Paris is about 340 km
+        let london = table.by_icao(&"EGLL".parse().unwrap()).unwrap().location;
+        let paris = table.by_icao(&"LFPG".parse().unwrap()).unwrap().location;
+        assert!((330f32..360f32).contains(&distance(london, paris).get::<kilometer>()));
+
+        let nearest = StationTable::parse(
+            "KAPA;;;Denver/Centennial Airport;CO;United States;4;39-34N;104-51W;;;1793;;\n\
+            KBJC;;;Broomfield/Rocky Mountain Metropolitan Airport;CO;United States;4;39-55N;\
+            105-07W;;;1724;;\n\
+            KDEN;72;565;Denver International Airport;CO;United States;4;39-51N;104-39W;;;1640;;\n\
+            KCOS;72;466;Colorado Springs Municipal Airport;CO;United States;4;38-49N;104-43W;\
+            ;;1881;;",
+        )
+        .nearest(
+            LatLon {
+                lat: Angle::new::<degree>(39.74),
+                lon: Angle::new::<degree>(-104.99),
+            },
+            3,
+        )
+        .into_iter()
+        .map(|(station, _)| station.icao.to_string())
+        .collect::<Vec<_>>();
+        assert_eq!(nearest, ["KBJC", "KAPA", "KDEN"]);
+
+        let mut table = StationTable::parse(
+            "KXYZ;--;---;Test Station;CO;United States;4;40-00-30N;105-30W;;;;;",
+        );
+        let xyz = table.by_icao(&"KXYZ".parse().unwrap()).unwrap();
+        assert_eq!(xyz.wmo, None);
+        assert_eq!(xyz.elevation, None);
+        table.insert(Station {
+            wmo: Some(WmoIndex {
+                block: 72,
+                station: 1,
+            }),
+            ..xyz.clone()
+        });
+        assert_eq!(table.len(), 1);
+        assert_eq!(
+            table.by_wmo("72001".parse().unwrap()).unwrap().name,
+            "Test Station"
+        );
+
+        assert!(matches!(
+            Station::parse_line(1, "KXYZ;72;001;Test;;;;40-00X;105-30W"),
+            Err(StationTableError::Field(1, "latitude"))
+        ));
+    }
+
+    #[test]
+    pub fn test_station_lines() {
+        //Lines in the layout of the NWS list, with upper air positions, RBSN flags, and a line
+        //missing its coordinates
+        let table = StationTable::parse(
+            "PANC;70;273;Anchorage, Anchorage International Airport;AK;United States;4;61-10N;\
+            150-01W;61-09N;150-01W;40;45;P\n\
+            KOUN;72;357;Norman / Max Westheimer;OK;United States;4;35-14-44N;097-28-19W;\
+            35-11N;097-26W;357;362;P\n\
+            KBAD;;;Shreveport, Barksdale Air Force Base;LA;United States;4;;;;;;;\n\
+            NZSP;89;009;Amundsen-Scott South Pole Station;;Antarctica;7;90-00S;000-00E;;;2835;;",
+        );
+
+        assert_eq!(table.len(), 3);
+        assert!(table.by_icao(&"KBAD".parse().unwrap()).is_none());
+
+        let panc = table.by_wmo("70273".parse().unwrap()).unwrap();
+        assert_eq!(panc.name, "Anchorage, Anchorage International Airport");
+        assert_eq!(panc.elevation, Some(Length::new::<meter>(40f32)));
+        assert!((panc.location.lon.get::<degree>() + 150.017).abs() < 0.01);
+
+        let koun = table.by_icao(&"KOUN".parse().unwrap()).unwrap();
+        assert!((koun.location.lat.get::<degree>() - 35.2456).abs() < 0.001);
+        assert!((koun.location.lon.get::<degree>() + 97.4719).abs() < 0.001);
+
+        let nzsp = table.by_icao(&"NZSP".parse().unwrap()).unwrap();
+        assert_eq!(nzsp.location.lat, Angle::new::<degree>(-90f32));
+        assert_eq!(nzsp.state, None);
+    }
+}
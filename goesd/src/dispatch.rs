@@ -10,7 +10,10 @@ use goes_parse::{
         AircraftReportCodeForm, AnalysisSubType, DataTypeDesignator, ForecastSubType,
         SurfaceSubType, UpperAirDataSubType,
     },
-    formats::{metar::EmwinMetarReport, rwr::RegionalWeatherRoundup, taf::TAFReport},
+    formats::{
+        metar::EmwinMetarReport, rtp::StateTemperaturePrecipitation, rwr::RegionalWeatherRoundup,
+        taf::TAFReport,
+    },
     goes::GoesFileName,
     header::GoesEmwinFileName,
 };
@@ -53,13 +56,28 @@ pub async fn emwin_dispatch(filename: GoesEmwinFileName, src: &str, ctx: Arc<Goe
             subtype: AnalysisSubType::Surface,
             ..
         }) => {
-            let _ = match RegionalWeatherRoundup::parse(&src) {
-                Ok((_, rwr)) => rwr,
-                Err(e) => {
-                    log::error!("Failed to parse regional weather roundup: {}", e);
-                    return;
-                }
-            };
+            //Roundups and temperature/precipitation tables share a heading but not an AWIPS PIL
+            let rtp = filename
+                .pil
+                .as_deref()
+                .is_some_and(|pil| pil.starts_with("RTP"));
+            if rtp {
+                let _ = match StateTemperaturePrecipitation::parse(&src) {
+                    Ok((_, rtp)) => rtp,
+                    Err(e) => {
+                        log::error!("Failed to parse temperature and precipitation table: {}", e);
+                        return;
+                    }
+                };
+            } else {
+                let _ = match RegionalWeatherRoundup::parse(&src) {
+                    Ok((_, rwr)) => rwr,
+                    Err(e) => {
+                        log::error!("Failed to parse regional weather roundup: {}", e);
+                        return;
+                    }
+                };
+            }
         }
         DataTypeDesignator::UpperAirData(UpperAirData {
             subtype: UpperAirDataSubType::AircraftReport(AircraftReportCodeForm::AMDAR),
@@ -120,7 +138,11 @@ pub async fn emwin_dispatch(filename: GoesEmwinFileName, src: &str, ctx: Arc<Goe
             }
         }
         _ => {
-            log::trace!("Unknown EMWIN product: {:?}", filename.wmo_product_id);
+            log::trace!(
+                "Unknown EMWIN product: {} ({})",
+                filename.wmo_product_id,
+                filename.wmo_product_id.description()
+            );
         }
     }
 }